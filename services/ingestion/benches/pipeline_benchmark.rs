@@ -5,7 +5,6 @@
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::runtime::Runtime;
@@ -35,7 +34,7 @@ fn bench_channel_throughput(c: &mut Criterion) {
                         
                         let consumer = tokio::spawn(async move {
                             let mut count = 0u64;
-                            while let Some(_) = rx.recv().await {
+                            while rx.recv().await.is_some() {
                                 count += 1;
                             }
                             count
@@ -187,7 +186,7 @@ fn bench_worker_scaling(c: &mut Criterion) {
                         
                         // Collect
                         let mut count = 0;
-                        while let Some(_) = out_rx.recv().await {
+                        while out_rx.recv().await.is_some() {
                             count += 1;
                         }
                         
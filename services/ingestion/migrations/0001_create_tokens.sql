@@ -0,0 +1,19 @@
+-- Tokens upserted by Storage::store_trending_tokens / store_new_tokens
+-- (matches scripts/init-db.sql, so databases it created are left as is)
+CREATE TABLE IF NOT EXISTS tokens (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    address VARCHAR(42) NOT NULL UNIQUE,
+    name VARCHAR(255) NOT NULL,
+    symbol VARCHAR(32) NOT NULL,
+    decimals SMALLINT DEFAULT 18,
+    total_supply NUMERIC(78, 0),
+    creator_address VARCHAR(42),
+    nadfun_url VARCHAR(512),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    metadata JSONB DEFAULT '{}'::jsonb
+);
+
+CREATE INDEX IF NOT EXISTS idx_tokens_symbol ON tokens(symbol);
+CREATE INDEX IF NOT EXISTS idx_tokens_creator ON tokens(creator_address);
+CREATE INDEX IF NOT EXISTS idx_tokens_created_at ON tokens(created_at DESC);
@@ -0,0 +1,30 @@
+-- News articles (schemas::NewsItem)
+CREATE TABLE IF NOT EXISTS news_items (
+    id UUID PRIMARY KEY,
+    title TEXT NOT NULL,
+    content TEXT NOT NULL,
+    summary TEXT,
+    source VARCHAR(32) NOT NULL,
+    source_url TEXT,
+    source_id VARCHAR(255),
+    author VARCHAR(255),
+    category VARCHAR(32) NOT NULL,
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    sentiment VARCHAR(16),
+    sentiment_score DOUBLE PRECISION,
+    relevance_score DOUBLE PRECISION,
+    importance VARCHAR(16) NOT NULL DEFAULT 'low',
+    mentioned_tokens TEXT[] NOT NULL DEFAULT '{}',
+    mentioned_addresses TEXT[] NOT NULL DEFAULT '{}',
+    language VARCHAR(8) NOT NULL DEFAULT 'en',
+    published_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    fetched_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    processed BOOLEAN NOT NULL DEFAULT FALSE,
+    embedding_id UUID,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_news_items_source_url ON news_items(source_url);
+CREATE INDEX IF NOT EXISTS idx_news_items_published_at ON news_items(published_at DESC);
+CREATE INDEX IF NOT EXISTS idx_news_items_unprocessed ON news_items(fetched_at) WHERE NOT processed;
@@ -0,0 +1,37 @@
+-- Social media signals (schemas::SocialSignal)
+CREATE TABLE IF NOT EXISTS social_signals (
+    id UUID PRIMARY KEY,
+    platform VARCHAR(32) NOT NULL,
+    signal_type VARCHAR(32) NOT NULL,
+    content TEXT,
+    content_url TEXT,
+    author_id VARCHAR(255) NOT NULL,
+    author_username VARCHAR(255),
+    author_followers BIGINT,
+    author_verified BOOLEAN NOT NULL DEFAULT FALSE,
+    is_influencer BOOLEAN NOT NULL DEFAULT FALSE,
+    influencer_tier VARCHAR(16),
+    likes BIGINT NOT NULL DEFAULT 0,
+    retweets BIGINT NOT NULL DEFAULT 0,
+    replies BIGINT NOT NULL DEFAULT 0,
+    views BIGINT,
+    engagement_rate DOUBLE PRECISION,
+    token_address VARCHAR(42),
+    token_symbol VARCHAR(32),
+    mentioned_addresses TEXT[] NOT NULL DEFAULT '{}',
+    sentiment VARCHAR(16),
+    sentiment_score DOUBLE PRECISION,
+    relevance_score DOUBLE PRECISION,
+    signal_strength DOUBLE PRECISION NOT NULL DEFAULT 0,
+    confidence DOUBLE PRECISION NOT NULL DEFAULT 0,
+    posted_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    fetched_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    processed BOOLEAN NOT NULL DEFAULT FALSE,
+    embedding_id UUID,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX IF NOT EXISTS idx_social_signals_token ON social_signals(token_address);
+CREATE INDEX IF NOT EXISTS idx_social_signals_author ON social_signals(platform, author_id);
+CREATE INDEX IF NOT EXISTS idx_social_signals_posted_at ON social_signals(posted_at DESC);
@@ -0,0 +1,32 @@
+-- Embedding records (schemas::EmbeddingRecord); vectors live in the vector
+-- store, this table keeps them alongside their lineage
+CREATE TABLE IF NOT EXISTS embeddings (
+    id UUID PRIMARY KEY,
+    source_type VARCHAR(32) NOT NULL,
+    source_id UUID NOT NULL,
+    content TEXT NOT NULL,
+    content_hash VARCHAR(128) NOT NULL,
+    content_length BIGINT NOT NULL,
+    embedding DOUBLE PRECISION[] NOT NULL,
+    embedding_dimension INTEGER NOT NULL,
+    embedding_model VARCHAR(64) NOT NULL,
+    embedding_model_version VARCHAR(64),
+    vector_store_id VARCHAR(255),
+    collection_name VARCHAR(255) NOT NULL,
+    metadata JSONB NOT NULL DEFAULT '{}'::jsonb,
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    is_chunked BOOLEAN NOT NULL DEFAULT FALSE,
+    chunk_index INTEGER,
+    total_chunks INTEGER,
+    parent_id UUID,
+    token_count INTEGER,
+    quality_score DOUBLE PRECISION,
+    expires_at TIMESTAMP WITH TIME ZONE,
+    generated_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    processing_time_ms BIGINT,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX IF NOT EXISTS idx_embeddings_source ON embeddings(source_type, source_id);
+CREATE INDEX IF NOT EXISTS idx_embeddings_content_hash ON embeddings(content_hash);
@@ -0,0 +1,293 @@
+//! Admin Endpoints
+//!
+//! Served alongside `/metrics` so operators can pause noisy sources at
+//! runtime without a restart:
+//! - `POST /admin/source/{id}/pause`
+//! - `POST /admin/source/{id}/resume`
+//! - `GET /admin/sources`
+//! - `GET /admin/dedup`
+//! - `GET /admin/health`
+//!
+//! All admin endpoints require `Authorization: Bearer <metrics_auth_token>`
+//! and are disabled when no token is configured. `GET /readyz` reports the
+//! same source health without auth, returning 503 while any source is
+//! unhealthy, or (with `READYZ_MAX_EVENT_AGE_SECS`) while any enabled polled
+//! source has gone longer than that without returning events.
+
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::{Method, Response, StatusCode};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::audit::{self, AuditLogger};
+use crate::dedup::DedupStore;
+use crate::metrics;
+use crate::sources::{SourceHealth, SourceId, SourceSwitches};
+
+/// Shared state for admin endpoints
+#[derive(Clone)]
+pub struct AdminState {
+    /// Bearer token required for admin requests (admin disabled when unset)
+    pub auth_token: Option<String>,
+    /// Runtime enable/disable flags consulted by the harvester
+    pub switches: SourceSwitches,
+    /// Dedup store whose statistics are reported (endpoint 404s when unset)
+    pub dedup: Option<Arc<DedupStore>>,
+    /// Latest source health sweep results
+    pub health: SourceHealth,
+    /// Longest an enabled source may go without events and stay ready
+    /// (unchecked when unset)
+    pub max_event_age: Option<Duration>,
+    /// Records source pause/resume as audit events (not audited when unset)
+    pub audit: Option<AuditLogger>,
+}
+
+impl AdminState {
+    /// Gets the enabled sources without events for longer than `max_event_age`
+    pub fn stale_sources(&self) -> Vec<SourceId> {
+        let Some(max_age) = self.max_event_age else {
+            return Vec::new();
+        };
+        self.health
+            .stale_sources(max_age)
+            .into_iter()
+            .filter(|source_id| self.switches.is_enabled(*source_id))
+            .collect()
+    }
+
+    /// Checks that every source is healthy and none is stale
+    pub fn is_ready(&self) -> bool {
+        self.health.is_ready() && self.stale_sources().is_empty()
+    }
+
+    /// Updates `ingestion_source_last_event_age_seconds` for tracked sources
+    pub fn record_event_ages(&self) {
+        for (source_id, age) in self.health.event_ages() {
+            metrics::update_source_last_event_age(source_id.as_str(), age.as_secs_f64());
+        }
+    }
+}
+
+/// Handles `GET /readyz` (no auth, for orchestrator probes)
+pub fn handle_readyz(state: &AdminState) -> Response<Full<Bytes>> {
+    state.record_event_ages();
+    let status = if state.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    json_response(status, health_json(state))
+}
+
+/// Handles an admin request
+///
+/// `authorization` is the raw `Authorization` header value, if any.
+pub fn handle_admin(
+    method: &Method,
+    path: &str,
+    authorization: Option<&str>,
+    state: &AdminState,
+) -> Response<Full<Bytes>> {
+    let Some(token) = state.auth_token.as_deref().filter(|t| !t.is_empty()) else {
+        return json_response(StatusCode::FORBIDDEN, serde_json::json!({
+            "error": "admin endpoints disabled (no metrics auth token configured)",
+        }));
+    };
+
+    let expected = format!("Bearer {}", token);
+    if !authorization.is_some_and(|given| token_matches(given, &expected)) {
+        warn!(path, "Rejected unauthorized admin request");
+        return json_response(StatusCode::UNAUTHORIZED, serde_json::json!({
+            "error": "unauthorized",
+        }));
+    }
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match (method, segments.as_slice()) {
+        (&Method::GET, ["admin", "sources"]) => {
+            json_response(StatusCode::OK, sources_json(&state.switches))
+        }
+        (&Method::GET, ["admin", "health"]) => {
+            json_response(StatusCode::OK, health_json(state))
+        }
+        (&Method::GET, ["admin", "dedup"]) => match state.dedup {
+            Some(ref dedup) => json_response(StatusCode::OK, serde_json::json!(dedup.stats())),
+            None => not_found(),
+        },
+        (&Method::POST, ["admin", "source", id, action]) => {
+            let source_id: SourceId = match id.parse() {
+                Ok(source_id) => source_id,
+                Err(e) => {
+                    return json_response(StatusCode::NOT_FOUND, serde_json::json!({
+                        "error": e.to_string(),
+                    }));
+                }
+            };
+
+            match *action {
+                "pause" => state.switches.pause(source_id),
+                "resume" => state.switches.resume(source_id),
+                _ => return not_found(),
+            }
+            info!(source = %source_id, action, "Source toggled via admin endpoint");
+
+            let enabled = state.switches.is_enabled(source_id);
+            if let Some(ref logger) = state.audit {
+                logger.record_in_background(audit::source_toggled(source_id.as_str(), enabled));
+            }
+
+            json_response(StatusCode::OK, serde_json::json!({
+                "source": source_id.as_str(),
+                "enabled": enabled,
+            }))
+        }
+        _ => not_found(),
+    }
+}
+
+/// Compares an `Authorization` header with the expected one in constant time
+///
+/// Both sides are hashed first, so neither how much of the token matched nor
+/// its length shows in the timing.
+fn token_matches(given: &str, expected: &str) -> bool {
+    use sha2::{Digest, Sha256};
+
+    let given = Sha256::digest(given.as_bytes());
+    let expected = Sha256::digest(expected.as_bytes());
+    given.iter().zip(expected.iter()).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// Serializes the enabled flag of every source
+fn sources_json(switches: &SourceSwitches) -> serde_json::Value {
+    let states: serde_json::Map<String, serde_json::Value> = SourceId::ALL
+        .into_iter()
+        .map(|id| (id.as_str().to_string(), serde_json::json!(switches.is_enabled(id))))
+        .collect();
+    serde_json::Value::Object(states)
+}
+
+/// Serializes readiness, the last health result of every checked source and
+/// the sources gone stale
+fn health_json(state: &AdminState) -> serde_json::Value {
+    let sources: serde_json::Map<String, serde_json::Value> = state
+        .health
+        .snapshot()
+        .into_iter()
+        .map(|(id, healthy)| (id.as_str().to_string(), serde_json::json!(healthy)))
+        .collect();
+    let stale: Vec<&str> = state.stale_sources().into_iter().map(|id| id.as_str()).collect();
+    serde_json::json!({
+        "ready": state.is_ready(),
+        "sources": sources,
+        "stale": stale,
+    })
+}
+
+pub(crate) fn not_found() -> Response<Full<Bytes>> {
+    json_response(StatusCode::NOT_FOUND, serde_json::json!({ "error": "not found" }))
+}
+
+pub(crate) fn json_response(status: StatusCode, body: serde_json::Value) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(token: Option<&str>) -> AdminState {
+        AdminState {
+            auth_token: token.map(String::from),
+            switches: SourceSwitches::default(),
+            dedup: None,
+            health: SourceHealth::default(),
+            max_event_age: None,
+            audit: None,
+        }
+    }
+
+    #[test]
+    fn test_admin_requires_token() {
+        let disabled = state(None);
+        let response = handle_admin(&Method::POST, "/admin/source/x_api/pause", None, &disabled);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let empty = state(Some(""));
+        let response = handle_admin(&Method::POST, "/admin/source/x_api/pause", Some("Bearer "), &empty);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let admin = state(Some("secret"));
+        let response = handle_admin(&Method::POST, "/admin/source/x_api/pause", Some("Bearer wrong"), &admin);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(admin.switches.is_enabled(SourceId::XApi));
+
+        for wrong in ["Bearer secret2", "Bearer secre", "bearer secret"] {
+            let response = handle_admin(&Method::POST, "/admin/source/x_api/pause", Some(wrong), &admin);
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[test]
+    fn test_admin_pause_and_resume() {
+        let admin = state(Some("secret"));
+        let auth = Some("Bearer secret");
+
+        let response = handle_admin(&Method::POST, "/admin/source/x_api/pause", auth, &admin);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!admin.switches.is_enabled(SourceId::XApi));
+        assert!(admin.switches.is_enabled(SourceId::NewsApi));
+
+        let response = handle_admin(&Method::POST, "/admin/source/x_api/resume", auth, &admin);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(admin.switches.is_enabled(SourceId::XApi));
+
+        let response = handle_admin(&Method::POST, "/admin/source/unknown/pause", auth, &admin);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let response = handle_admin(&Method::POST, "/admin/source/x_api/stop", auth, &admin);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_readyz_reflects_source_health() {
+        let admin = state(None);
+        assert_eq!(handle_readyz(&admin).status(), StatusCode::OK);
+
+        admin.health.set(SourceId::NewsApi, true);
+        admin.health.set(SourceId::XApi, false);
+        assert_eq!(handle_readyz(&admin).status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        admin.health.set(SourceId::XApi, true);
+        assert_eq!(handle_readyz(&admin).status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_readyz_fails_when_source_stops_producing_events() {
+        let mut admin = state(None);
+        admin.max_event_age = Some(Duration::from_millis(100));
+        admin.health.set(SourceId::NewsApi, true);
+        admin.health.watch_events(SourceId::NewsApi);
+        assert_eq!(handle_readyz(&admin).status(), StatusCode::OK);
+
+        // Healthy by ping, but no events within the max age
+        std::thread::sleep(Duration::from_millis(150));
+        assert_eq!(handle_readyz(&admin).status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(health_json(&admin)["stale"], serde_json::json!(["newsapi"]));
+        assert!(metrics::source_last_event_age("newsapi") >= 0.15);
+
+        // Paused sources aren't expected to produce events
+        admin.switches.pause(SourceId::NewsApi);
+        assert_eq!(handle_readyz(&admin).status(), StatusCode::OK);
+        admin.switches.resume(SourceId::NewsApi);
+
+        admin.health.record_events(SourceId::NewsApi);
+        assert_eq!(handle_readyz(&admin).status(), StatusCode::OK);
+    }
+}
@@ -5,14 +5,19 @@
 //! - Local filesystem (development)
 //! - S3-compatible storage (production)
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
 use std::path::{Path, PathBuf};
 use tokio::fs::{self, OpenOptions};
-use tokio::io::AsyncWriteExt;
-use tracing::{debug, info, warn, error};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tracing::{debug, info, warn};
 
+use crate::circuit_breaker::CircuitStateChange;
 use crate::error::{IngestionError, Result};
+use crate::metrics;
+use crate::schemas::AuditLogEvent;
 
 /// Entry in the append-only log
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +43,99 @@ pub struct LogEntry {
     pub content_hash: String,
 }
 
+impl LogEntry {
+    /// Creates a `RawResponse` entry for an unmodified API payload
+    pub fn raw_response(
+        source_id: &str,
+        correlation_id: &str,
+        session_id: &str,
+        payload: serde_json::Value,
+    ) -> Self {
+        let raw = payload.to_string();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            timestamp: Utc::now(),
+            source_id: source_id.to_string(),
+            correlation_id: correlation_id.to_string(),
+            session_id: session_id.to_string(),
+            entry_type: LogEntryType::RawResponse,
+            payload_size: raw.len() as u64,
+            content_hash: crate::dedup::compute_hash(&raw),
+            payload,
+        }
+    }
+
+    /// Creates an `Audit` entry for a lifecycle event, filed under the
+    /// `audit` source so all audit entries share one log
+    pub fn audit(correlation_id: &str, event: &AuditLogEvent) -> Self {
+        let payload = serde_json::to_value(event).unwrap_or_default();
+        Self {
+            entry_type: LogEntryType::Audit,
+            ..Self::raw_response(AUDIT_SOURCE_ID, correlation_id, correlation_id, payload)
+        }
+    }
+
+    /// Creates a `CircuitEvent` entry for a breaker state change, filed
+    /// under the circuit's source
+    pub fn circuit_event(correlation_id: &str, session_id: &str, change: &CircuitStateChange) -> Self {
+        let payload = serde_json::to_value(change).unwrap_or_default();
+        Self {
+            timestamp: change.changed_at,
+            entry_type: LogEntryType::CircuitEvent,
+            ..Self::raw_response(&change.circuit, correlation_id, session_id, payload)
+        }
+    }
+}
+
+/// Reads one append log line, upgrading entries written before fields were
+/// added to `LogEntry`
+///
+/// Lines that match no known shape are counted in
+/// `ingestion_unreadable_log_entries_total` and skipped (`None`).
+fn read_log_line(source_id: &str, line: &[u8]) -> Option<LogEntry> {
+    if line.trim_ascii().is_empty() {
+        return None;
+    }
+    if let Ok(entry) = serde_json::from_slice::<LogEntry>(line) {
+        return Some(entry);
+    }
+
+    let entry = serde_json::from_slice::<serde_json::Value>(line)
+        .ok()
+        .and_then(|mut value| {
+            upgrade_log_entry(&mut value)?;
+            serde_json::from_value::<LogEntry>(value).ok()
+        });
+    if entry.is_none() {
+        metrics::record_unreadable_log_entry(source_id);
+        warn!(source = source_id, "Skipping unreadable append log line");
+    }
+    entry
+}
+
+/// Fills in fields missing from older log entry shapes:
+/// - `sessionId` (added after `correlationId`; older entries reuse it)
+/// - `payloadSize` and `contentHash` (computed from the payload)
+fn upgrade_log_entry(value: &mut serde_json::Value) -> Option<()> {
+    let entry = value.as_object_mut()?;
+    let payload = entry.get("payload")?.to_string();
+
+    if !entry.contains_key("sessionId") {
+        let correlation_id = entry.get("correlationId")?.clone();
+        entry.insert("sessionId".to_string(), correlation_id);
+    }
+    if !entry.contains_key("payloadSize") {
+        entry.insert("payloadSize".to_string(), serde_json::json!(payload.len()));
+    }
+    if !entry.contains_key("contentHash") {
+        entry.insert("contentHash".to_string(), serde_json::json!(crate::dedup::compute_hash(&payload)));
+    }
+    Some(())
+}
+
+/// Source ID under which audit entries are stored
+pub const AUDIT_SOURCE_ID: &str = "audit";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum LogEntryType {
@@ -45,6 +143,74 @@ pub enum LogEntryType {
     NormalizedEvent,
     Error,
     Checkpoint,
+    Audit,
+    /// A source's circuit breaker changed state
+    CircuitEvent,
+}
+
+/// How often the filesystem log rolls over to a new file per source
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogGranularity {
+    /// `YYYY-MM-DD.jsonl`
+    #[default]
+    Daily,
+    /// `YYYY-MM-DD-HH.jsonl` (for high-volume sources)
+    Hourly,
+}
+
+impl LogGranularity {
+    /// Gets the log file name (without extension) for an entry timestamp
+    fn file_stem(&self, timestamp: &DateTime<Utc>) -> String {
+        match self {
+            Self::Daily => timestamp.format("%Y-%m-%d").to_string(),
+            Self::Hourly => timestamp.format("%Y-%m-%d-%H").to_string(),
+        }
+    }
+}
+
+/// Listed entry ordered by timestamp, then by the order it was read in
+struct ListedEntry {
+    seq: usize,
+    entry: LogEntry,
+}
+
+impl ListedEntry {
+    fn key(&self) -> (DateTime<Utc>, usize) {
+        (self.entry.timestamp, self.seq)
+    }
+}
+
+impl PartialEq for ListedEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for ListedEntry {}
+
+impl PartialOrd for ListedEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ListedEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// Gets the end of the period covered by a log file from its name
+/// (either granularity; `None` if the name isn't recognized)
+fn file_period_end(stem: &str) -> Option<DateTime<Utc>> {
+    if let Ok(hour) = NaiveDateTime::parse_from_str(&format!("{}:00", stem), "%Y-%m-%d-%H:%M") {
+        return Some(hour.and_utc() + Duration::hours(1));
+    }
+    NaiveDate::parse_from_str(stem, "%Y-%m-%d")
+        .ok()
+        .and_then(|day| day.and_hms_opt(0, 0, 0))
+        .map(|day| day.and_utc() + Duration::days(1))
 }
 
 /// Trait for append-only log storage backends
@@ -53,6 +219,17 @@ pub trait AppendLogStorage: Send + Sync {
     /// Appends an entry to the log
     async fn append(&self, entry: &LogEntry) -> Result<()>;
 
+    /// Appends several entries at once
+    ///
+    /// The default implementation appends them one by one; backends with a
+    /// per-write cost (S3) override this to write the batch together.
+    async fn append_batch(&self, entries: &[LogEntry]) -> Result<()> {
+        for entry in entries {
+            self.append(entry).await?;
+        }
+        Ok(())
+    }
+
     /// Lists entries (for replay)
     async fn list_entries(
         &self,
@@ -61,6 +238,26 @@ pub trait AppendLogStorage: Send + Sync {
         limit: usize,
     ) -> Result<Vec<LogEntry>>;
 
+    /// Lists the newest `limit` entries accepted by `matches`, oldest first
+    /// (for inspection)
+    ///
+    /// The default implementation lists every entry before cutting; the
+    /// filesystem backend only holds `limit` entries at a time.
+    async fn tail_entries(
+        &self,
+        source_id: Option<&str>,
+        since: Option<DateTime<Utc>>,
+        limit: usize,
+        matches: &(dyn for<'e> Fn(&'e LogEntry) -> bool + Send + Sync),
+    ) -> Result<Vec<LogEntry>> {
+        let mut entries = self.list_entries(source_id, since, usize::MAX).await?;
+        entries.retain(|entry| matches(entry));
+        entries.sort_by_key(|entry| entry.timestamp);
+        let excess = entries.len().saturating_sub(limit);
+        entries.drain(..excess);
+        Ok(entries)
+    }
+
     /// Gets storage statistics
     async fn stats(&self) -> Result<StorageStats>;
 }
@@ -78,7 +275,10 @@ pub struct StorageStats {
 pub struct FileSystemAppendLog {
     base_path: PathBuf,
     /// Current log file for today
+    #[allow(dead_code)]
     current_date: parking_lot::RwLock<String>,
+    /// File rollover period
+    granularity: LogGranularity,
 }
 
 impl FileSystemAppendLog {
@@ -95,13 +295,20 @@ impl FileSystemAppendLog {
         Ok(Self {
             base_path: base_path.to_path_buf(),
             current_date: parking_lot::RwLock::new(today),
+            granularity: LogGranularity::Daily,
         })
     }
 
-    /// Gets the log file path for a given date and source
-    fn get_log_path(&self, date: &str, source_id: &str) -> PathBuf {
+    /// Sets how often log files roll over
+    pub fn with_granularity(mut self, granularity: LogGranularity) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    /// Gets the log file path for a given period (date or date-hour) and source
+    fn get_log_path(&self, period: &str, source_id: &str) -> PathBuf {
         let source_dir = self.base_path.join(source_id);
-        source_dir.join(format!("{}.jsonl", date))
+        source_dir.join(format!("{}.jsonl", period))
     }
 
     /// Ensures the directory exists for a log file
@@ -111,21 +318,79 @@ impl FileSystemAppendLog {
             .map_err(|e| IngestionError::StorageError(format!("Failed to create source dir: {}", e)))?;
         Ok(())
     }
+
+    /// Gets each source's log files (every source unless `source_id` is
+    /// set), oldest first, skipping periods that end before `since`
+    async fn log_files(
+        &self,
+        source_id: Option<&str>,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<(String, Vec<PathBuf>)>> {
+        // List source directories
+        let sources: Vec<String> = if let Some(source) = source_id {
+            vec![source.to_string()]
+        } else {
+            let mut sources = Vec::new();
+            let mut dir = fs::read_dir(&self.base_path).await
+                .map_err(|e| IngestionError::StorageError(format!("Failed to read log dir: {}", e)))?;
+            
+            while let Some(entry) = dir.next_entry().await
+                .map_err(|e| IngestionError::StorageError(format!("Failed to read dir entry: {}", e)))? {
+                if entry.file_type().await.map(|t| t.is_dir()).unwrap_or(false) {
+                    if let Some(name) = entry.file_name().to_str() {
+                        sources.push(name.to_string());
+                    }
+                }
+            }
+            sources
+        };
+
+        let mut listed = Vec::with_capacity(sources.len());
+        for source in sources {
+            let source_dir = self.base_path.join(&source);
+            if !source_dir.exists() {
+                continue;
+            }
+
+            let mut files: Vec<_> = Vec::new();
+            let mut dir = fs::read_dir(&source_dir).await
+                .map_err(|e| IngestionError::StorageError(format!("Failed to read source dir: {}", e)))?;
+
+            while let Some(entry) = dir.next_entry().await
+                .map_err(|e| IngestionError::StorageError(format!("Failed to read dir entry: {}", e)))? {
+                if let Some(stem) = entry.file_name().to_str().and_then(|n| n.strip_suffix(".jsonl")) {
+                    // Daily and hourly files; skip periods that end before `since`
+                    let expired = match (since, file_period_end(stem)) {
+                        (Some(since_time), Some(end)) => end <= since_time,
+                        _ => false,
+                    };
+                    if !expired {
+                        files.push(entry.path());
+                    }
+                }
+            }
+
+            // Sort by filename (date)
+            files.sort();
+            listed.push((source, files));
+        }
+        Ok(listed)
+    }
 }
 
 #[async_trait::async_trait]
 impl AppendLogStorage for FileSystemAppendLog {
     async fn append(&self, entry: &LogEntry) -> Result<()> {
-        let date = entry.timestamp.format("%Y-%m-%d").to_string();
+        let period = self.granularity.file_stem(&entry.timestamp);
         
         // Ensure directory exists
         self.ensure_dir(&entry.source_id).await?;
 
-        let log_path = self.get_log_path(&date, &entry.source_id);
+        let log_path = self.get_log_path(&period, &entry.source_id);
 
         // Serialize entry to JSON line
         let json = serde_json::to_string(entry)
-            .map_err(|e| IngestionError::JsonError(e))?;
+            .map_err(IngestionError::JsonError)?;
         let line = format!("{}\n", json);
 
         // Append to file
@@ -158,79 +423,99 @@ impl AppendLogStorage for FileSystemAppendLog {
         since: Option<DateTime<Utc>>,
         limit: usize,
     ) -> Result<Vec<LogEntry>> {
-        let mut entries = Vec::new();
-
-        // List source directories
-        let sources: Vec<String> = if let Some(source) = source_id {
-            vec![source.to_string()]
-        } else {
-            let mut sources = Vec::new();
-            let mut dir = fs::read_dir(&self.base_path).await
-                .map_err(|e| IngestionError::StorageError(format!("Failed to read log dir: {}", e)))?;
-            
-            while let Some(entry) = dir.next_entry().await
-                .map_err(|e| IngestionError::StorageError(format!("Failed to read dir entry: {}", e)))? {
-                if entry.file_type().await.map(|t| t.is_dir()).unwrap_or(false) {
-                    if let Some(name) = entry.file_name().to_str() {
-                        sources.push(name.to_string());
+        // Files are streamed line by line, and only the oldest `limit`
+        // entries are held (the newest is evicted first), so memory stays
+        // bounded by `limit` rather than by file size
+        let mut oldest: BinaryHeap<ListedEntry> = BinaryHeap::new();
+        let mut seq = 0;
+
+        for (source, files) in self.log_files(source_id, since).await? {
+            // Files are chronological, so a source stops after `limit` entries
+            let mut listed = 0;
+            'files: for file_path in files {
+                let file = fs::File::open(&file_path).await
+                    .map_err(|e| IngestionError::StorageError(format!("Failed to open log file: {}", e)))?;
+                let mut reader = BufReader::new(file);
+                let mut line = Vec::new();
+
+                loop {
+                    if listed >= limit {
+                        break 'files;
                     }
-                }
-            }
-            sources
-        };
 
-        for source in sources {
-            let source_dir = self.base_path.join(&source);
-            if !source_dir.exists() {
-                continue;
-            }
-
-            let mut files: Vec<_> = Vec::new();
-            let mut dir = fs::read_dir(&source_dir).await
-                .map_err(|e| IngestionError::StorageError(format!("Failed to read source dir: {}", e)))?;
-
-            while let Some(entry) = dir.next_entry().await
-                .map_err(|e| IngestionError::StorageError(format!("Failed to read dir entry: {}", e)))? {
-                if let Some(name) = entry.file_name().to_str() {
-                    if name.ends_with(".jsonl") {
-                        files.push(entry.path());
-                    }
-                }
-            }
-
-            // Sort by filename (date)
-            files.sort();
-
-            for file_path in files {
-                if entries.len() >= limit {
-                    break;
-                }
-
-                let content = fs::read_to_string(&file_path).await
-                    .map_err(|e| IngestionError::StorageError(format!("Failed to read log file: {}", e)))?;
-
-                for line in content.lines() {
-                    if entries.len() >= limit {
+                    line.clear();
+                    let read = reader.read_until(b'\n', &mut line).await
+                        .map_err(|e| IngestionError::StorageError(format!("Failed to read log file: {}", e)))?;
+                    if read == 0 {
                         break;
                     }
 
-                    if let Ok(entry) = serde_json::from_str::<LogEntry>(line) {
+                    if let Some(entry) = read_log_line(&source, &line) {
                         // Filter by since
                         if let Some(since_time) = since {
                             if entry.timestamp < since_time {
                                 continue;
                             }
                         }
-                        entries.push(entry);
+                        oldest.push(ListedEntry { seq, entry });
+                        if oldest.len() > limit {
+                            oldest.pop();
+                        }
+                        seq += 1;
+                        listed += 1;
                     }
                 }
             }
         }
 
         // Sort by timestamp
-        entries.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+        Ok(oldest.into_sorted_vec().into_iter().map(|listed| listed.entry).collect())
+    }
 
-        Ok(entries)
+    async fn tail_entries(
+        &self,
+        source_id: Option<&str>,
+        since: Option<DateTime<Utc>>,
+        limit: usize,
+        matches: &(dyn for<'e> Fn(&'e LogEntry) -> bool + Send + Sync),
+    ) -> Result<Vec<LogEntry>> {
+        // Every file is streamed, and only the newest `limit` matches are
+        // held (the oldest is evicted first)
+        let mut newest: BinaryHeap<Reverse<ListedEntry>> = BinaryHeap::new();
+        let mut seq = 0;
+
+        for (source, files) in self.log_files(source_id, since).await? {
+            for file_path in files {
+                let file = fs::File::open(&file_path).await
+                    .map_err(|e| IngestionError::StorageError(format!("Failed to open log file: {}", e)))?;
+                let mut reader = BufReader::new(file);
+                let mut line = Vec::new();
+
+                loop {
+                    line.clear();
+                    let read = reader.read_until(b'\n', &mut line).await
+                        .map_err(|e| IngestionError::StorageError(format!("Failed to read log file: {}", e)))?;
+                    if read == 0 {
+                        break;
+                    }
+
+                    let Some(entry) = read_log_line(&source, &line) else {
+                        continue;
+                    };
+                    if since.is_some_and(|since_time| entry.timestamp < since_time) || !matches(&entry) {
+                        continue;
+                    }
+                    newest.push(Reverse(ListedEntry { seq, entry }));
+                    if newest.len() > limit {
+                        newest.pop();
+                    }
+                    seq += 1;
+                }
+            }
+        }
+
+        // Descending by `Reverse`, so oldest first once reversed
+        Ok(newest.into_sorted_vec().into_iter().rev().map(|Reverse(listed)| listed.entry).collect())
     }
 
     async fn stats(&self) -> Result<StorageStats> {
@@ -275,7 +560,7 @@ pub struct S3AppendLog {
 impl S3AppendLog {
     /// Creates a new S3 append log
     pub async fn new(bucket: &str, prefix: &str, endpoint_url: Option<&str>) -> Result<Self> {
-        let config_loader = aws_config::from_env();
+        let config_loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
         
         let config = if let Some(endpoint) = endpoint_url {
             // Custom endpoint for S3-compatible services (MinIO, etc.)
@@ -313,24 +598,44 @@ impl S3AppendLog {
             entry.id
         )
     }
-}
 
-#[async_trait::async_trait]
-impl AppendLogStorage for S3AppendLog {
-    async fn append(&self, entry: &LogEntry) -> Result<()> {
-        let key = self.get_key(entry);
-        let body = serde_json::to_vec(entry)
-            .map_err(|e| IngestionError::JsonError(e))?;
+    /// Gets the S3 key for a batch of entries from one source
+    fn get_batch_key(&self, first: &LogEntry) -> String {
+        let date = first.timestamp.format("%Y/%m/%d").to_string();
+        let hour = first.timestamp.format("%H").to_string();
+        format!(
+            "{}/{}/{}/{}-batch-{}.jsonl",
+            self.prefix,
+            first.source_id,
+            date,
+            hour,
+            uuid::Uuid::new_v4()
+        )
+    }
 
+    /// Writes an object to the bucket
+    async fn put(&self, key: &str, body: Vec<u8>, content_type: &str) -> Result<()> {
         self.client
             .put_object()
             .bucket(&self.bucket)
-            .key(&key)
+            .key(key)
             .body(body.into())
-            .content_type("application/json")
+            .content_type(content_type)
             .send()
             .await
             .map_err(|e| IngestionError::StorageError(format!("S3 put failed: {}", e)))?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AppendLogStorage for S3AppendLog {
+    async fn append(&self, entry: &LogEntry) -> Result<()> {
+        let key = self.get_key(entry);
+        let body = serde_json::to_vec(entry)
+            .map_err(IngestionError::JsonError)?;
+
+        self.put(&key, body, "application/json").await?;
 
         debug!(
             bucket = %self.bucket,
@@ -341,6 +646,38 @@ impl AppendLogStorage for S3AppendLog {
         Ok(())
     }
 
+    async fn append_batch(&self, entries: &[LogEntry]) -> Result<()> {
+        // One JSON-lines object per source so prefix listing stays per-source
+        let mut by_source: Vec<(&str, Vec<&LogEntry>)> = Vec::new();
+        for entry in entries {
+            match by_source.iter_mut().find(|(source, _)| *source == entry.source_id) {
+                Some((_, group)) => group.push(entry),
+                None => by_source.push((&entry.source_id, vec![entry])),
+            }
+        }
+
+        for (_, group) in by_source {
+            let key = self.get_batch_key(group[0]);
+            let mut body = Vec::new();
+            for entry in &group {
+                serde_json::to_writer(&mut body, entry)
+                    .map_err(IngestionError::JsonError)?;
+                body.push(b'\n');
+            }
+
+            self.put(&key, body, "application/x-ndjson").await?;
+
+            debug!(
+                bucket = %self.bucket,
+                key = %key,
+                entries = group.len(),
+                "Appended batch to S3"
+            );
+        }
+
+        Ok(())
+    }
+
     async fn list_entries(
         &self,
         source_id: Option<&str>,
@@ -384,15 +721,27 @@ impl AppendLogStorage for S3AppendLog {
                         .map_err(|e| IngestionError::StorageError(format!("S3 get failed: {}", e)))?;
 
                     let body = get_response.body.collect().await
-                        .map_err(|e| IngestionError::StorageError(format!("S3 read body failed: {}", e)))?;
+                        .map_err(|e| IngestionError::StorageError(format!("S3 read body failed: {}", e)))?
+                        .into_bytes();
+
+                    // Objects hold one entry, or one per line for batches
+                    let source = key
+                        .strip_prefix(&format!("{}/", self.prefix))
+                        .and_then(|rest| rest.split('/').next())
+                        .unwrap_or("unknown");
+                    for line in body.split(|b| *b == b'\n') {
+                        if entries.len() >= limit {
+                            return Ok(entries);
+                        }
 
-                    if let Ok(entry) = serde_json::from_slice::<LogEntry>(&body.into_bytes()) {
-                        if let Some(since_time) = since {
-                            if entry.timestamp < since_time {
-                                continue;
+                        if let Some(entry) = read_log_line(source, line) {
+                            if let Some(since_time) = since {
+                                if entry.timestamp < since_time {
+                                    continue;
+                                }
                             }
+                            entries.push(entry);
                         }
-                        entries.push(entry);
                     }
                 }
             }
@@ -444,6 +793,47 @@ impl AppendLogStorage for S3AppendLog {
     }
 }
 
+/// Maximum payload characters shown per entry by `write_entry_summaries`
+const SUMMARY_PREVIEW_CHARS: usize = 80;
+
+/// Whether `entry`'s payload contains `term` (always true without one)
+pub fn payload_contains(entry: &LogEntry, term: Option<&str>) -> bool {
+    term.is_none_or(|term| entry.payload.to_string().contains(term))
+}
+
+/// Writes a one-line summary per entry, keeping only entries whose payload
+/// contains `grep` when set, and returns the number of entries written
+pub fn write_entry_summaries<W: std::io::Write>(
+    entries: &[LogEntry],
+    grep: Option<&str>,
+    mut writer: W,
+) -> Result<usize> {
+    let mut count = 0;
+    for entry in entries {
+        if !payload_contains(entry, grep) {
+            continue;
+        }
+        let payload = entry.payload.to_string();
+
+        let mut preview: String = payload.chars().take(SUMMARY_PREVIEW_CHARS).collect();
+        if preview.len() < payload.len() {
+            preview.push('…');
+        }
+        writeln!(
+            writer,
+            "{} {:<12} {:<16} {} {}B {}",
+            entry.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            entry.source_id,
+            format!("{:?}", entry.entry_type),
+            entry.id.get(..8).unwrap_or(&entry.id),
+            entry.payload_size,
+            preview
+        )?;
+        count += 1;
+    }
+    Ok(count)
+}
+
 /// Factory function to create appropriate storage backend
 pub async fn create_append_log(
     storage_type: &str,
@@ -451,11 +841,12 @@ pub async fn create_append_log(
     s3_bucket: Option<&str>,
     s3_prefix: Option<&str>,
     s3_endpoint: Option<&str>,
+    granularity: LogGranularity,
 ) -> Result<Box<dyn AppendLogStorage>> {
     match storage_type {
         "filesystem" | "local" => {
             let path = local_path.unwrap_or(Path::new("./data/append_log"));
-            Ok(Box::new(FileSystemAppendLog::new(path).await?))
+            Ok(Box::new(FileSystemAppendLog::new(path).await?.with_granularity(granularity)))
         }
         "s3" => {
             let bucket = s3_bucket
@@ -497,4 +888,208 @@ mod tests {
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].id, "test-123");
     }
+
+    #[tokio::test]
+    async fn test_filesystem_append_batch() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+
+        let entries: Vec<LogEntry> = ["newsapi", "newsapi", "cryptopanic"]
+            .iter()
+            .enumerate()
+            .map(|(i, source)| LogEntry::raw_response(
+                source,
+                "corr-456",
+                "sess-789",
+                serde_json::json!({"n": i}),
+            ))
+            .collect();
+
+        log.append_batch(&entries).await.unwrap();
+
+        let listed = log.list_entries(None, None, 100).await.unwrap();
+        assert_eq!(listed.len(), 3);
+        for entry in &entries {
+            assert!(listed.iter().any(|e| e.id == entry.id && e.payload == entry.payload));
+        }
+        let newsapi = log.list_entries(Some("newsapi"), None, 100).await.unwrap();
+        assert_eq!(newsapi.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_hourly_granularity_splits_files() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path())
+            .await
+            .unwrap()
+            .with_granularity(LogGranularity::Hourly);
+
+        let first_hour = DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z").unwrap().with_timezone(&Utc);
+        for (id, timestamp) in [("first", first_hour), ("second", first_hour + Duration::hours(1))] {
+            let mut entry = LogEntry::raw_response("newsapi", "corr-456", "sess-789", serde_json::json!({}));
+            entry.id = id.to_string();
+            entry.timestamp = timestamp;
+            log.append(&entry).await.unwrap();
+        }
+
+        let source_dir = temp_dir.path().join("newsapi");
+        assert!(source_dir.join("2024-01-15-10.jsonl").exists());
+        assert!(source_dir.join("2024-01-15-11.jsonl").exists());
+
+        let entries = log.list_entries(Some("newsapi"), None, 100).await.unwrap();
+        let ids: Vec<_> = entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["first", "second"]);
+
+        let since = first_hour + Duration::minutes(45);
+        let entries = log.list_entries(Some("newsapi"), Some(since), 100).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, "second");
+    }
+
+    #[tokio::test]
+    async fn test_list_entries_reads_older_entry_shapes() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+
+        let current = LogEntry::raw_response("legacy-test", "corr-1", "sess-1", serde_json::json!({"n": 1}));
+        // Written before sessionId, payloadSize and contentHash existed
+        let older = serde_json::json!({
+            "id": "older-1",
+            "timestamp": "2024-01-15T10:00:00Z",
+            "sourceId": "legacy-test",
+            "correlationId": "corr-0",
+            "entryType": "raw_response",
+            "payload": {"n": 0},
+        });
+        let lines = [
+            serde_json::to_string(&current).unwrap(),
+            older.to_string(),
+            "not json".to_string(),
+            r#"{"id":"no-payload","timestamp":"2024-01-15T10:00:00Z"}"#.to_string(),
+        ];
+        let source_dir = temp_dir.path().join("legacy-test");
+        fs::create_dir_all(&source_dir).await.unwrap();
+        fs::write(source_dir.join("2024-01-15.jsonl"), lines.join("\n") + "\n").await.unwrap();
+
+        let before = metrics::unreadable_log_entries_total("legacy-test");
+        let entries = log.list_entries(Some("legacy-test"), None, 100).await.unwrap();
+
+        let ids: Vec<_> = entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["older-1", current.id.as_str()]);
+        let older = &entries[0];
+        assert_eq!(older.session_id, "corr-0");
+        assert_eq!(older.payload_size, r#"{"n":0}"#.len() as u64);
+        assert_eq!(older.content_hash, crate::dedup::compute_hash(r#"{"n":0}"#));
+        assert_eq!(metrics::unreadable_log_entries_total("legacy-test"), before + 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_entries_streams_large_files() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+
+        let start = DateTime::parse_from_rfc3339("2024-01-15T00:00:00Z").unwrap().with_timezone(&Utc);
+        let mut content = String::new();
+        for n in 0..50_000 {
+            let mut entry = LogEntry::raw_response("stream-test", "corr-1", "sess-1", serde_json::json!({"n": n}));
+            entry.id = format!("entry-{}", n);
+            entry.timestamp = start + Duration::milliseconds(n);
+            content.push_str(&serde_json::to_string(&entry).unwrap());
+            content.push('\n');
+        }
+        // Only counted if the reader gets this far
+        content.push_str("not json\n");
+        let source_dir = temp_dir.path().join("stream-test");
+        fs::create_dir_all(&source_dir).await.unwrap();
+        fs::write(source_dir.join("2024-01-15.jsonl"), content).await.unwrap();
+
+        let before = metrics::unreadable_log_entries_total("stream-test");
+        let entries = log.list_entries(Some("stream-test"), None, 100).await.unwrap();
+        assert_eq!(entries.len(), 100);
+        assert_eq!(entries[0].id, "entry-0");
+        assert_eq!(entries[99].id, "entry-99");
+        // Stopped reading once `limit` entries were found
+        assert_eq!(metrics::unreadable_log_entries_total("stream-test"), before);
+
+        let since = start + Duration::milliseconds(49_990);
+        let entries = log.list_entries(Some("stream-test"), Some(since), 100).await.unwrap();
+        let ids: Vec<_> = entries.iter().map(|e| e.id.clone()).collect();
+        let expected: Vec<_> = (49_990..50_000).map(|n| format!("entry-{}", n)).collect();
+        assert_eq!(ids, expected);
+        assert_eq!(metrics::unreadable_log_entries_total("stream-test"), before + 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_entries_keeps_oldest_across_sources() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+
+        let start = DateTime::parse_from_rfc3339("2024-01-15T10:00:00Z").unwrap().with_timezone(&Utc);
+        for (source, offsets) in [("newsapi", [1, 3, 5]), ("x_api", [0, 2, 4])] {
+            for offset in offsets {
+                let mut entry = LogEntry::raw_response(source, "corr-1", "sess-1", serde_json::json!({}));
+                entry.id = format!("{}-{}", source, offset);
+                entry.timestamp = start + Duration::minutes(offset);
+                log.append(&entry).await.unwrap();
+            }
+        }
+
+        let entries = log.list_entries(None, None, 3).await.unwrap();
+        let ids: Vec<_> = entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["x_api-0", "newsapi-1", "x_api-2"]);
+    }
+
+    #[tokio::test]
+    async fn test_entry_summaries_filter_by_grep() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+
+        for (id, title) in [("btc-1", "Bitcoin ETF approved"), ("eth-1", "Ether upgrade"), ("btc-2", "Bitcoin hits high")] {
+            let mut entry = LogEntry::raw_response("newsapi", "corr-1", "sess-1", serde_json::json!({ "title": title }));
+            entry.id = id.to_string();
+            log.append(&entry).await.unwrap();
+        }
+
+        let entries = log.list_entries(Some("newsapi"), None, 10).await.unwrap();
+        let mut out = Vec::new();
+        let written = write_entry_summaries(&entries, Some("Bitcoin"), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(written, 2);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("btc-1") && lines[0].contains("Bitcoin ETF approved"));
+        assert!(lines[1].contains("btc-2"));
+        assert!(!out.contains("eth-1"));
+
+        let mut out = Vec::new();
+        assert_eq!(write_entry_summaries(&entries, None, &mut out).unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_tail_entries_keeps_newest_matches() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+
+        let start = DateTime::parse_from_rfc3339("2024-01-15T10:00:00Z").unwrap().with_timezone(&Utc);
+        let titles = ["Bitcoin ETF", "Ether upgrade", "Bitcoin high", "Bitcoin dip", "Ether fees", "Ether burn"];
+        for (offset, title) in titles.iter().enumerate() {
+            let mut entry = LogEntry::raw_response("newsapi", "corr-1", "sess-1", serde_json::json!({ "title": title }));
+            entry.id = format!("entry-{}", offset);
+            entry.timestamp = start + Duration::minutes(offset as i64);
+            log.append(&entry).await.unwrap();
+        }
+
+        // More matches than the limit: the newest ones, even though the
+        // newest entries overall don't match
+        let ids = |entries: Vec<LogEntry>| entries.into_iter().map(|e| e.id).collect::<Vec<_>>();
+        let bitcoin = log
+            .tail_entries(None, None, 2, &|entry| payload_contains(entry, Some("Bitcoin")))
+            .await
+            .unwrap();
+        assert_eq!(ids(bitcoin), vec!["entry-2", "entry-3"]);
+
+        let all = log.tail_entries(Some("newsapi"), None, 3, &|_| true).await.unwrap();
+        assert_eq!(ids(all), vec!["entry-3", "entry-4", "entry-5"]);
+    }
 }
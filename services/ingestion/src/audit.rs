@@ -0,0 +1,280 @@
+//! Audit Logging
+//!
+//! Records significant lifecycle events as `AuditLogEvent`s:
+//! - service start/stop
+//! - sources enabled/disabled via the admin endpoint
+//! - circuit breaker trips
+//! - checkpoint resets
+//! - config reloads
+//!
+//! Every event is written to the append log as an `Audit` entry (under the
+//! `audit` source) and, when a bus is attached, published to the audit
+//! stream. Failures are logged and never surface to the caller.
+//!
+//! Circuit breaker state changes (trips and recoveries) are also kept, as
+//! `CircuitEvent` entries in each source's own log, for post-incident review.
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::append_log::{create_append_log, AppendLogStorage, LogEntry};
+use crate::circuit_breaker::CircuitStateChange;
+use crate::config::Config;
+use crate::message_bus::MessageBus;
+use crate::schemas::{AuditAction, AuditCategory, AuditLogEvent, Severity};
+
+/// Writes audit events to the append log and, optionally, a message bus
+#[derive(Clone)]
+pub struct AuditLogger {
+    append_log: Arc<dyn AppendLogStorage>,
+    correlation_id: String,
+    /// Harvest session circuit state changes are logged under
+    session_id: String,
+    bus: Arc<RwLock<Option<Arc<dyn MessageBus>>>>,
+}
+
+impl AuditLogger {
+    pub fn new(append_log: Arc<dyn AppendLogStorage>, correlation_id: impl Into<String>) -> Self {
+        Self {
+            append_log,
+            correlation_id: correlation_id.into(),
+            session_id: String::new(),
+            bus: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Sets the harvest session (the checkpoint's) that circuit state
+    /// changes are logged under
+    pub fn with_session_id(mut self, session_id: impl Into<String>) -> Self {
+        self.session_id = session_id.into();
+        self
+    }
+
+    /// Creates a logger writing to the append log described by `config`
+    pub async fn from_config(config: &Config, correlation_id: impl Into<String>) -> crate::error::Result<Self> {
+        let append_log = create_append_log(
+            &config.storage_type,
+            Some(&config.data_dir),
+            config.s3_bucket.as_deref(),
+            config.s3_prefix.as_deref(),
+            config.s3_endpoint_url.as_deref(),
+            config.append_log_granularity,
+        )
+        .await?;
+        Ok(Self::new(Arc::from(append_log), correlation_id))
+    }
+
+    /// Also publishes every later event to `bus` (shared by all clones)
+    pub fn set_bus(&self, bus: Arc<dyn MessageBus>) {
+        *self.bus.write() = Some(bus);
+    }
+
+    /// Records an event
+    pub async fn record(&self, event: AuditLogEvent) {
+        let entry = LogEntry::audit(&self.correlation_id, &event);
+        if let Err(e) = self.append_log.append(&entry).await {
+            warn!(error = %e, action = ?event.action, "Failed to write audit entry");
+        }
+
+        let bus = self.bus.read().clone();
+        if let Some(bus) = bus {
+            if let Err(e) = bus.publish_audit(&event).await {
+                warn!(error = %e, action = ?event.action, "Failed to publish audit event");
+            }
+        }
+
+        debug!(action = ?event.action, target = ?event.target_id, "Audit event recorded");
+    }
+
+    /// Records an event on a background task, for synchronous callers such
+    /// as circuit breaker hooks and admin handlers
+    pub fn record_in_background(&self, event: AuditLogEvent) {
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                let logger = self.clone();
+                runtime.spawn(async move { logger.record(event).await });
+            }
+            Err(_) => warn!(action = ?event.action, "No async runtime; audit event dropped"),
+        }
+    }
+
+    /// Writes a circuit breaker state change to the append log without
+    /// waiting (safe to call from breaker hooks)
+    pub fn record_circuit_change(&self, change: &CircuitStateChange) {
+        let entry = LogEntry::circuit_event(&self.correlation_id, &self.session_id, change);
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                let append_log = self.append_log.clone();
+                runtime.spawn(async move {
+                    if let Err(e) = append_log.append(&entry).await {
+                        warn!(error = %e, circuit = %entry.source_id, "Failed to write circuit state change");
+                    }
+                });
+            }
+            Err(_) => warn!(circuit = %change.circuit, "No async runtime; circuit state change dropped"),
+        }
+    }
+}
+
+/// The ingestion service started in `mode` (`daemon`, `once`, `pipeline`)
+pub fn service_started(mode: &str) -> AuditLogEvent {
+    let mut event = AuditLogEvent::system_event(
+        AuditAction::SystemStart,
+        format!("Ingestion service started ({})", mode),
+    );
+    event.details.insert("mode".to_string(), serde_json::json!(mode));
+    event
+}
+
+/// The ingestion service shut down
+pub fn service_stopped() -> AuditLogEvent {
+    AuditLogEvent::system_event(AuditAction::SystemStop, "Ingestion service stopped".to_string())
+}
+
+/// A source was enabled or disabled at runtime
+pub fn source_toggled(source_id: &str, enabled: bool) -> AuditLogEvent {
+    let state = if enabled { "enabled" } else { "disabled" };
+    let mut event = source_event(
+        AuditAction::ConfigChange,
+        source_id,
+        format!("Source {} {}", source_id, state),
+    );
+    event.details.insert("enabled".to_string(), serde_json::json!(enabled));
+    event
+}
+
+/// A source's circuit breaker tripped to Open
+pub fn circuit_tripped(circuit: &str) -> AuditLogEvent {
+    let mut event = source_event(
+        AuditAction::Custom,
+        circuit,
+        format!("Circuit breaker tripped for {}", circuit),
+    );
+    event.category = AuditCategory::Error;
+    event.severity = Severity::High;
+    event.success = false;
+    event.details.insert("event".to_string(), serde_json::json!("circuit_tripped"));
+    event.tags.push("circuit_breaker".to_string());
+    event
+}
+
+/// Checkpoints were reset for a source (`None` for all sources)
+pub fn checkpoint_reset(source_id: Option<&str>, with_dedup: bool) -> AuditLogEvent {
+    let subject = source_id.unwrap_or("all");
+    let mut event = source_event(
+        AuditAction::DataDeleted,
+        subject,
+        format!("Checkpoint reset for {}", subject),
+    );
+    event.category = AuditCategory::Data;
+    event.severity = Severity::Medium;
+    event.details.insert("with_dedup".to_string(), serde_json::json!(with_dedup));
+    event
+}
+
+/// The config was reloaded (SIGHUP), applying `applied` settings
+pub fn config_reloaded(applied: &[&str]) -> AuditLogEvent {
+    let mut event = AuditLogEvent::system_event(
+        AuditAction::ConfigChange,
+        "Configuration reloaded".to_string(),
+    );
+    event.target_type = Some("config".to_string());
+    event.details.insert("applied".to_string(), serde_json::json!(applied));
+    event
+}
+
+/// System event targeting a source
+fn source_event(action: AuditAction, source_id: &str, description: String) -> AuditLogEvent {
+    let mut event = AuditLogEvent::system_event(action, description);
+    event.target_type = Some("source".to_string());
+    event.target_id = Some(source_id.to_string());
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::append_log::{FileSystemAppendLog, LogEntryType, AUDIT_SOURCE_ID};
+    use crate::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_circuit_trip_writes_audit_entry() {
+        let temp_dir = TempDir::new().unwrap();
+        let append_log = Arc::new(FileSystemAppendLog::new(temp_dir.path()).await.unwrap());
+        let audit = AuditLogger::new(append_log.clone(), "corr-1");
+
+        let hook_audit = audit.clone();
+        let cb = CircuitBreaker::new(
+            "newsapi",
+            CircuitBreakerConfig {
+                failure_threshold: 2,
+                ..Default::default()
+            },
+        )
+        .with_on_trip(Arc::new(move |name| hook_audit.record_in_background(circuit_tripped(name))));
+
+        cb.record_failure();
+        cb.record_failure();
+
+        let mut entries = Vec::new();
+        for _ in 0..50 {
+            entries = append_log.list_entries(Some(AUDIT_SOURCE_ID), None, 10).await.unwrap();
+            if !entries.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        assert_eq!(entries.len(), 1);
+        assert!(matches!(entries[0].entry_type, LogEntryType::Audit));
+        let event: AuditLogEvent = serde_json::from_value(entries[0].payload.clone()).unwrap();
+        assert_eq!(event.action, AuditAction::Custom);
+        assert_eq!(event.details["event"], "circuit_tripped");
+        assert_eq!(event.target_type.as_deref(), Some("source"));
+        assert_eq!(event.target_id.as_deref(), Some("newsapi"));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_state_changes_write_source_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let append_log = Arc::new(FileSystemAppendLog::new(temp_dir.path()).await.unwrap());
+        let audit = AuditLogger::new(append_log.clone(), "corr-1").with_session_id("session-1");
+
+        let hook_audit = audit.clone();
+        let cb = CircuitBreaker::new(
+            "newsapi",
+            CircuitBreakerConfig {
+                failure_threshold: 2,
+                ..Default::default()
+            },
+        )
+        .with_on_state_change(Arc::new(move |change| hook_audit.record_circuit_change(change)));
+
+        cb.record_failure();
+        cb.record_failure();
+
+        let mut entries = Vec::new();
+        for _ in 0..50 {
+            entries = append_log.list_entries(Some("newsapi"), None, 10).await.unwrap();
+            if !entries.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        assert_eq!(entries.len(), 1);
+        assert!(matches!(entries[0].entry_type, LogEntryType::CircuitEvent));
+        let change: CircuitStateChange = serde_json::from_value(entries[0].payload.clone()).unwrap();
+        assert_eq!(change.circuit, "newsapi");
+        assert_eq!(change.from, CircuitState::Closed);
+        assert_eq!(change.to, CircuitState::Open);
+        assert_eq!(change.failure_count, 2);
+        assert_eq!(change.trips, 1);
+        assert_eq!(entries[0].timestamp, change.changed_at);
+        assert_eq!(entries[0].correlation_id, "corr-1");
+        assert_eq!(entries[0].session_id, "session-1");
+    }
+}
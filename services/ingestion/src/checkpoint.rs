@@ -14,6 +14,12 @@ use tokio::fs;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tracing::{debug, info, warn, error};
 
+/// Maximum attempts for a checkpoint save before giving up
+const SAVE_MAX_ATTEMPTS: u32 = 4;
+
+/// Initial delay between checkpoint save attempts (doubled per retry)
+const SAVE_INITIAL_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(100);
+
 /// Checkpoint data for a single source
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -24,6 +30,9 @@ pub struct SourceCheckpoint {
     pub last_fetch_at: DateTime<Utc>,
     /// Last cursor/page token (for paginated APIs)
     pub cursor: Option<String>,
+    /// Cursor of the page an interrupted paginated fetch failed on
+    #[serde(default)]
+    pub resume_cursor: Option<String>,
     /// Number of items fetched in last batch
     pub last_batch_count: u32,
     /// Total items fetched since checkpoint start
@@ -43,6 +52,7 @@ impl SourceCheckpoint {
             source_id: source_id.to_string(),
             last_fetch_at: Utc::now(),
             cursor: None,
+            resume_cursor: None,
             last_batch_count: 0,
             total_items_fetched: 0,
             last_error: None,
@@ -57,10 +67,22 @@ impl SourceCheckpoint {
         self.last_batch_count = batch_count;
         self.total_items_fetched += batch_count as u64;
         self.cursor = cursor;
+        self.resume_cursor = None;
         self.last_error = None;
         self.error_count = 0;
     }
 
+    /// Records a paginated fetch that failed after some pages succeeded
+    ///
+    /// `last_fetch_at` is left alone so the resumed fetch covers the same
+    /// window.
+    pub fn record_partial(&mut self, batch_count: u32, resume_cursor: Option<String>, error: &str) {
+        self.last_batch_count = batch_count;
+        self.total_items_fetched += batch_count as u64;
+        self.resume_cursor = resume_cursor;
+        self.record_error(error);
+    }
+
     /// Records a failed fetch
     pub fn record_error(&mut self, error: &str) {
         self.last_error = Some(error.to_string());
@@ -105,6 +127,7 @@ impl CheckpointState {
     }
 
     /// Updates the checkpoint for a source
+    #[allow(dead_code)]
     pub fn update(&mut self, checkpoint: SourceCheckpoint) {
         self.updated_at = Utc::now();
         self.sources.insert(checkpoint.source_id.clone(), checkpoint);
@@ -193,6 +216,44 @@ impl CheckpointManager {
         Ok(())
     }
 
+    /// Saves checkpoint to file, retrying transient IO errors with backoff
+    async fn save_to_file_with_retry(&self) -> anyhow::Result<()> {
+        let mut attempt = 0u32;
+        let mut delay = SAVE_INITIAL_RETRY_DELAY;
+
+        loop {
+            attempt += 1;
+
+            match self.save_to_file().await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt < SAVE_MAX_ATTEMPTS => {
+                    warn!(
+                        error = %e,
+                        attempt = attempt,
+                        max_attempts = SAVE_MAX_ATTEMPTS,
+                        "Checkpoint save failed, will retry"
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Gets the fallback path used when the checkpoint file cannot be written
+    pub fn fallback_path(&self) -> PathBuf {
+        std::env::temp_dir().join(format!("neuro-checkpoint-{}.json", self.state.session_id))
+    }
+
+    /// Writes a fallback copy of the checkpoint so state isn't lost
+    async fn save_fallback(&self) -> anyhow::Result<PathBuf> {
+        let json = serde_json::to_string_pretty(&self.state)?;
+        let path = self.fallback_path();
+        fs::write(&path, json.as_bytes()).await?;
+        Ok(path)
+    }
+
     /// Gets the current session ID
     pub fn session_id(&self) -> &str {
         &self.state.session_id
@@ -205,6 +266,27 @@ impl CheckpointManager {
             .unwrap_or_else(|| Utc::now() - default_since)
     }
 
+    /// Resolves a CLI `--since` value to a fetch start time
+    ///
+    /// `checkpoint` resumes from the last fetch of `source_ids` (the oldest
+    /// one when harvesting several), falling back to `default_since` ago for
+    /// sources without a checkpoint; anything else is parsed as a duration ago.
+    pub fn resolve_since(
+        &self,
+        since: &str,
+        source_ids: &[&str],
+        default_since: Duration,
+    ) -> anyhow::Result<DateTime<Utc>> {
+        if since.trim().eq_ignore_ascii_case(SINCE_CHECKPOINT) {
+            return Ok(source_ids
+                .iter()
+                .map(|source_id| self.get_since(source_id, default_since))
+                .min()
+                .unwrap_or_else(|| Utc::now() - default_since));
+        }
+        Ok(Utc::now() - parse_since(since)?)
+    }
+
     /// Records a successful fetch for a source
     pub fn record_success(&mut self, source_id: &str, batch_count: u32, cursor: Option<String>) {
         let checkpoint = self.state.get_or_create(source_id);
@@ -221,7 +303,21 @@ impl CheckpointManager {
         self.dirty = true;
     }
 
+    /// Records a paginated fetch for a source that failed partway through
+    pub fn record_partial(&mut self, source_id: &str, batch_count: u32, resume_cursor: Option<String>, error: &str) {
+        let checkpoint = self.state.get_or_create(source_id);
+        checkpoint.record_partial(batch_count, resume_cursor, error);
+        self.state.updated_at = Utc::now();
+        self.dirty = true;
+    }
+
+    /// Gets the cursor to resume a source's interrupted paginated fetch from
+    pub fn resume_cursor(&self, source_id: &str) -> Option<String> {
+        self.state.sources.get(source_id).and_then(|cp| cp.resume_cursor.clone())
+    }
+
     /// Gets checkpoint for a source
+    #[allow(dead_code)]
     pub fn get_checkpoint(&self, source_id: &str) -> Option<&SourceCheckpoint> {
         self.state.sources.get(source_id)
     }
@@ -235,9 +331,16 @@ impl CheckpointManager {
     }
 
     /// Forces a save
+    ///
+    /// Retries transient failures; if every attempt fails, a fallback copy is
+    /// written to the temp directory before the error is returned.
     pub async fn save(&mut self) -> anyhow::Result<()> {
-        if let Err(e) = self.save_to_file().await {
+        if let Err(e) = self.save_to_file_with_retry().await {
             error!(error = %e, "Failed to save checkpoint");
+            match self.save_fallback().await {
+                Ok(path) => warn!(path = %path.display(), "Wrote fallback checkpoint copy"),
+                Err(fallback_err) => error!(error = %fallback_err, "Failed to write fallback checkpoint"),
+            }
             return Err(e);
         }
         self.last_save = Utc::now();
@@ -273,6 +376,9 @@ impl CheckpointManager {
     }
 }
 
+/// `--since` value that resumes from each source's last checkpointed fetch
+pub const SINCE_CHECKPOINT: &str = "checkpoint";
+
 /// Parses a human-readable duration string (e.g., "1h", "30m", "2d")
 pub fn parse_since(since_str: &str) -> anyhow::Result<Duration> {
     let since_str = since_str.trim().to_lowercase();
@@ -352,4 +458,69 @@ mod tests {
         assert_eq!(loaded.get_checkpoint("newsapi").unwrap().total_items_fetched, 50);
         assert_eq!(loaded.get_checkpoint("cryptopanic").unwrap().cursor, Some("page2".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_resolve_since_uses_checkpoint() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut manager = CheckpointManager::new(temp_dir.path()).await.unwrap();
+        manager.record_success("newsapi", 10, None);
+        manager.save().await.unwrap();
+
+        let loaded = CheckpointManager::new(temp_dir.path()).await.unwrap();
+        let last_fetch = loaded.get_checkpoint("newsapi").unwrap().last_fetch_at;
+        let default_since = Duration::hours(1);
+
+        let since = loaded.resolve_since("checkpoint", &["newsapi"], default_since).unwrap();
+        assert_eq!(since, last_fetch);
+
+        // Sources without a checkpoint fall back to the default window
+        let before = Utc::now() - default_since;
+        let since = loaded.resolve_since("checkpoint", &["x_api"], default_since).unwrap();
+        assert!(since >= before && since < last_fetch);
+
+        // Harvesting several sources starts from the oldest of them
+        let oldest = loaded.resolve_since("checkpoint", &["newsapi", "x_api"], default_since).unwrap();
+        assert!(oldest < last_fetch);
+
+        let since = loaded.resolve_since("2h", &["newsapi"], default_since).unwrap();
+        assert!(since < Utc::now() - Duration::minutes(119));
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_save_retries_transient_failure() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut manager = CheckpointManager::new(temp_dir.path()).await.unwrap();
+        manager.record_success("newsapi", 10, None);
+
+        // A directory at the temp path makes the first write attempts fail
+        let blocker = temp_dir.path().join("checkpoint.json.tmp");
+        std::fs::create_dir(&blocker).unwrap();
+        let unblock = tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+            std::fs::remove_dir(&blocker).unwrap();
+        });
+
+        manager.save().await.unwrap();
+        unblock.await.unwrap();
+
+        let loaded = CheckpointManager::new(temp_dir.path()).await.unwrap();
+        assert_eq!(loaded.get_checkpoint("newsapi").unwrap().total_items_fetched, 10);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_save_writes_fallback_on_persistent_failure() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut manager = CheckpointManager::new(temp_dir.path()).await.unwrap();
+        manager.record_success("newsapi", 10, None);
+
+        std::fs::create_dir(temp_dir.path().join("checkpoint.json.tmp")).unwrap();
+
+        assert!(manager.save().await.is_err());
+
+        let fallback = manager.fallback_path();
+        let state: CheckpointState =
+            serde_json::from_str(&std::fs::read_to_string(&fallback).unwrap()).unwrap();
+        assert_eq!(state.sources["newsapi"].total_items_fetched, 10);
+        std::fs::remove_file(fallback).unwrap();
+    }
 }
@@ -6,13 +6,77 @@
 //! Turkish: "Eğer bir kaynak sürekli hata veriyorsa, sistemi yormamak için
 //! o kaynağı geçici olarak devre dışı bırakan bir Circuit Breaker mantığı"
 
+use std::fmt;
+use std::future::Future;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
-use parking_lot::RwLock;
+use chrono::{DateTime, Utc};
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
 use tracing::{info, warn, debug};
 
+/// Longest a probe task sleeps before re-checking a non-open circuit
+const PROBE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Shortest pause between consecutive probes of a HalfOpen circuit
+const PROBE_MIN_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Source of the current time for a circuit breaker
+///
+/// Breakers use `SystemClock`; tests swap in `MockClock` to move time
+/// forward without sleeping.
+pub trait Clock: Send + Sync + fmt::Debug {
+    fn now(&self) -> Instant;
+}
+
+/// Wall clock (`Instant::now`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Clock that only moves when advanced (clones share the same time)
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+#[allow(dead_code)]
+impl MockClock {
+    pub fn new() -> Self {
+        Self {
+            now: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Moves the clock forward
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock() += duration;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.now.lock()
+    }
+}
+
 /// Circuit breaker states
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CircuitState {
     /// Normal operation - requests pass through
     Closed,
@@ -58,6 +122,30 @@ pub struct CircuitBreaker {
     total_failures: AtomicU64,
     total_successes: AtomicU64,
     trips: AtomicU64,
+    clock: Arc<dyn Clock>,
+    on_trip: Option<TripHook>,
+    on_state_change: Option<StateChangeHook>,
+}
+
+/// Callback invoked with the circuit name whenever a circuit trips
+pub type TripHook = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Callback invoked whenever a circuit changes state
+pub type StateChangeHook = Arc<dyn Fn(&CircuitStateChange) + Send + Sync>;
+
+/// A circuit's transition from one state to another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitStateChange {
+    /// Circuit name (the source ID for source breakers)
+    pub circuit: String,
+    pub from: CircuitState,
+    pub to: CircuitState,
+    /// Consecutive failures counted when the state changed
+    pub failure_count: u32,
+    pub total_failures: u64,
+    pub trips: u64,
+    pub changed_at: DateTime<Utc>,
 }
 
 impl CircuitBreaker {
@@ -74,9 +162,44 @@ impl CircuitBreaker {
             total_failures: AtomicU64::new(0),
             total_successes: AtomicU64::new(0),
             trips: AtomicU64::new(0),
+            on_trip: None,
+            on_state_change: None,
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Sets the clock used for open-duration timing
+    #[allow(dead_code)]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Sets a hook run whenever the circuit trips to Open
+    ///
+    /// The hook runs while the state lock is held, so it must not block or
+    /// call back into the breaker.
+    pub fn with_on_trip(mut self, hook: TripHook) -> Self {
+        self.on_trip = Some(hook);
+        self
+    }
+
+    /// Sets a hook run whenever the circuit changes state (after the trip
+    /// hook on trips)
+    ///
+    /// Like the trip hook, it runs while the state lock is held.
+    pub fn with_on_state_change(mut self, hook: StateChangeHook) -> Self {
+        self.on_state_change = Some(hook);
+        self
+    }
+
+    /// Time since the last recorded failure (or trip)
+    fn since_last_failure(&self) -> Option<Duration> {
+        self.last_failure_time
+            .read()
+            .map(|last_failure| self.clock.now().saturating_duration_since(last_failure))
+    }
+
     /// Creates a circuit breaker with default config
     pub fn with_defaults(name: impl Into<String>) -> Self {
         Self::new(name, CircuitBreakerConfig::default())
@@ -88,11 +211,13 @@ impl CircuitBreaker {
     }
 
     /// Gets the name of this circuit breaker
+    #[allow(dead_code)]
     pub fn name(&self) -> &str {
         &self.name
     }
 
     /// Gets circuit breaker statistics
+    #[allow(dead_code)]
     pub fn stats(&self) -> CircuitBreakerStats {
         CircuitBreakerStats {
             state: self.state(),
@@ -113,17 +238,9 @@ impl CircuitBreaker {
             CircuitState::Closed => true,
             CircuitState::Open => {
                 // Check if we should transition to half-open
-                if let Some(last_failure) = *self.last_failure_time.read() {
-                    if last_failure.elapsed() >= self.config.open_duration {
-                        info!(
-                            circuit = %self.name,
-                            "Circuit transitioning from Open to HalfOpen"
-                        );
-                        *state = CircuitState::HalfOpen;
-                        self.half_open_requests.store(0, Ordering::Relaxed);
-                        self.success_count.store(0, Ordering::Relaxed);
-                        return self.try_half_open_request();
-                    }
+                if self.open_elapsed() {
+                    self.enter_half_open(&mut state);
+                    return self.try_half_open_request();
                 }
                 debug!(
                     circuit = %self.name,
@@ -137,6 +254,43 @@ impl CircuitBreaker {
         }
     }
 
+    /// Whether an open circuit has waited out `open_duration`
+    fn open_elapsed(&self) -> bool {
+        self.since_last_failure()
+            .is_some_and(|elapsed| elapsed >= self.config.open_duration)
+    }
+
+    /// Moves an open circuit to HalfOpen (caller holds the state lock)
+    fn enter_half_open(&self, state: &mut CircuitState) {
+        info!(
+            circuit = %self.name,
+            "Circuit transitioning from Open to HalfOpen"
+        );
+        *state = CircuitState::HalfOpen;
+        self.half_open_requests.store(0, Ordering::Relaxed);
+        self.success_count.store(0, Ordering::Relaxed);
+        self.notify_state_change(CircuitState::Open, CircuitState::HalfOpen);
+    }
+
+    /// Moves a due open circuit to HalfOpen without taking a half-open
+    /// request slot; returns whether the circuit is now HalfOpen
+    fn begin_probe(&self) -> bool {
+        let mut state = self.state.write();
+        match *state {
+            CircuitState::HalfOpen => true,
+            CircuitState::Open if self.open_elapsed() => {
+                self.enter_half_open(&mut state);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Number of outcomes recorded so far
+    fn recorded_outcomes(&self) -> u64 {
+        self.total_successes.load(Ordering::Relaxed) + self.total_failures.load(Ordering::Relaxed)
+    }
+
     /// Try to allow a request in half-open state
     fn try_half_open_request(&self) -> bool {
         let current = self.half_open_requests.fetch_add(1, Ordering::Relaxed);
@@ -181,6 +335,7 @@ impl CircuitBreaker {
                     *state = CircuitState::Closed;
                     self.failure_count.store(0, Ordering::Relaxed);
                     self.success_count.store(0, Ordering::Relaxed);
+                    self.notify_state_change(CircuitState::HalfOpen, CircuitState::Closed);
                 } else {
                     debug!(
                         circuit = %self.name,
@@ -194,6 +349,7 @@ impl CircuitBreaker {
                 // Shouldn't happen, but reset to closed
                 *state = CircuitState::Closed;
                 self.failure_count.store(0, Ordering::Relaxed);
+                self.notify_state_change(CircuitState::Open, CircuitState::Closed);
             }
         }
     }
@@ -201,7 +357,7 @@ impl CircuitBreaker {
     /// Records a failed request
     pub fn record_failure(&self) {
         self.total_failures.fetch_add(1, Ordering::Relaxed);
-        *self.last_failure_time.write() = Some(Instant::now());
+        *self.last_failure_time.write() = Some(self.clock.now());
         
         let mut state = self.state.write();
         
@@ -219,6 +375,8 @@ impl CircuitBreaker {
                     );
                     *state = CircuitState::Open;
                     self.trips.fetch_add(1, Ordering::Relaxed);
+                    self.notify_trip();
+                    self.notify_state_change(CircuitState::Closed, CircuitState::Open);
                 } else {
                     debug!(
                         circuit = %self.name,
@@ -236,6 +394,8 @@ impl CircuitBreaker {
                 *state = CircuitState::Open;
                 self.trips.fetch_add(1, Ordering::Relaxed);
                 self.success_count.store(0, Ordering::Relaxed);
+                self.notify_trip();
+                self.notify_state_change(CircuitState::HalfOpen, CircuitState::Open);
             }
             CircuitState::Open => {
                 // Already open, just record the failure time
@@ -243,29 +403,118 @@ impl CircuitBreaker {
         }
     }
 
+    /// Spawns a background task that probes the circuit once `open_duration`
+    /// has elapsed, so recovery doesn't wait for foreground traffic.
+    ///
+    /// The task moves the circuit to HalfOpen itself before probing. A probe
+    /// going through this breaker (e.g. a `SourceHttpClient` health check)
+    /// records its own outcome; for any other probe the task records the
+    /// returned result. Probes repeat at least `PROBE_MIN_INTERVAL` apart
+    /// while HalfOpen and stop on the first failure. The task exits once the
+    /// breaker is dropped.
+    pub fn spawn_probe<F, Fut>(self: &Arc<Self>, probe: F) -> JoinHandle<()>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = bool> + Send,
+    {
+        let breaker = Arc::downgrade(self);
+        
+        tokio::spawn(async move {
+            while let Some(wait) = breaker.upgrade().map(|cb| cb.time_until_probe()) {
+                tokio::time::sleep(wait.max(PROBE_MIN_INTERVAL)).await;
+                
+                let Some(cb) = breaker.upgrade() else { break };
+                if cb.state() != CircuitState::Open || !cb.begin_probe() {
+                    continue;
+                }
+                
+                info!(circuit = %cb.name, "Probing open circuit");
+                while cb.state() == CircuitState::HalfOpen {
+                    let recorded = cb.recorded_outcomes();
+                    let healthy = probe().await;
+                    if cb.recorded_outcomes() == recorded {
+                        if healthy {
+                            cb.record_success();
+                        } else {
+                            cb.record_failure();
+                        }
+                    }
+                    if !healthy {
+                        debug!(circuit = %cb.name, "Circuit probe failed");
+                        break;
+                    }
+                    tokio::time::sleep(PROBE_MIN_INTERVAL).await;
+                }
+            }
+        })
+    }
+
+    /// Time until an open circuit is due for a HalfOpen probe
+    fn time_until_probe(&self) -> Duration {
+        if self.state() == CircuitState::Open {
+            if let Some(elapsed) = self.since_last_failure() {
+                return self.config.open_duration.saturating_sub(elapsed);
+            }
+        }
+        PROBE_CHECK_INTERVAL.min(self.config.open_duration)
+    }
+
     /// Manually trips the circuit (for testing or manual intervention)
+    #[allow(dead_code)]
     pub fn trip(&self) {
         let mut state = self.state.write();
         if *state != CircuitState::Open {
             warn!(circuit = %self.name, "Circuit manually tripped");
+            let from = *state;
             *state = CircuitState::Open;
-            *self.last_failure_time.write() = Some(Instant::now());
+            *self.last_failure_time.write() = Some(self.clock.now());
             self.trips.fetch_add(1, Ordering::Relaxed);
+            self.notify_trip();
+            self.notify_state_change(from, CircuitState::Open);
+        }
+    }
+
+    /// Runs the trip hook, if any
+    fn notify_trip(&self) {
+        if let Some(hook) = &self.on_trip {
+            hook(&self.name);
+        }
+    }
+
+    /// Runs the state change hook, if any
+    ///
+    /// Counters are read directly since the caller holds the state lock.
+    fn notify_state_change(&self, from: CircuitState, to: CircuitState) {
+        if let Some(hook) = &self.on_state_change {
+            hook(&CircuitStateChange {
+                circuit: self.name.clone(),
+                from,
+                to,
+                failure_count: self.failure_count.load(Ordering::Relaxed),
+                total_failures: self.total_failures.load(Ordering::Relaxed),
+                trips: self.trips.load(Ordering::Relaxed),
+                changed_at: Utc::now(),
+            });
         }
     }
 
     /// Manually resets the circuit (for testing or manual intervention)
+    #[allow(dead_code)]
     pub fn reset(&self) {
         let mut state = self.state.write();
         info!(circuit = %self.name, "Circuit manually reset");
-        *state = CircuitState::Closed;
+        let from = std::mem::replace(&mut *state, CircuitState::Closed);
         self.failure_count.store(0, Ordering::Relaxed);
         self.success_count.store(0, Ordering::Relaxed);
         self.half_open_requests.store(0, Ordering::Relaxed);
+        if from != CircuitState::Closed {
+            self.notify_state_change(from, CircuitState::Closed);
+        }
     }
 }
 
 /// Statistics for a circuit breaker
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct CircuitBreakerStats {
     pub state: CircuitState,
@@ -313,15 +562,20 @@ mod tests {
             half_open_max_requests: 3,
         };
         
-        let cb = CircuitBreaker::new("test", config);
+        let clock = MockClock::new();
+        let cb = CircuitBreaker::new("test", config).with_clock(Arc::new(clock.clone()));
         
         // Trip the circuit
         cb.record_failure();
         cb.record_failure();
         assert_eq!(cb.state(), CircuitState::Open);
         
-        // Wait for open duration
-        std::thread::sleep(Duration::from_millis(20));
+        // Still open just before the open duration elapses
+        clock.advance(Duration::from_millis(9));
+        assert!(!cb.allow_request());
+        assert_eq!(cb.state(), CircuitState::Open);
+        
+        clock.advance(Duration::from_millis(1));
         
         // Should transition to half-open
         assert!(cb.allow_request());
@@ -335,6 +589,39 @@ mod tests {
         assert_eq!(cb.state(), CircuitState::Closed);
     }
 
+    #[test]
+    fn test_state_change_hook_sees_each_transition() {
+        let config = CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_duration: Duration::from_millis(10),
+            success_threshold: 1,
+            half_open_max_requests: 1,
+        };
+
+        let clock = MockClock::new();
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let seen = changes.clone();
+        let cb = CircuitBreaker::new("test", config)
+            .with_clock(Arc::new(clock.clone()))
+            .with_on_state_change(Arc::new(move |change| seen.lock().push((change.from, change.to))));
+
+        cb.record_failure();
+        cb.record_failure();
+        clock.advance(Duration::from_millis(10));
+        assert!(cb.allow_request());
+        cb.record_success();
+        cb.reset();
+
+        assert_eq!(
+            *changes.lock(),
+            vec![
+                (CircuitState::Closed, CircuitState::Open),
+                (CircuitState::Open, CircuitState::HalfOpen),
+                (CircuitState::HalfOpen, CircuitState::Closed),
+            ]
+        );
+    }
+
     #[test]
     fn test_circuit_breaker_half_open_failure() {
         let config = CircuitBreakerConfig {
@@ -344,18 +631,111 @@ mod tests {
             half_open_max_requests: 3,
         };
         
-        let cb = CircuitBreaker::new("test", config);
+        let clock = MockClock::new();
+        let cb = CircuitBreaker::new("test", config).with_clock(Arc::new(clock.clone()));
         
         // Trip the circuit
         cb.record_failure();
         cb.record_failure();
         
-        // Wait and transition to half-open
-        std::thread::sleep(Duration::from_millis(20));
+        // Advance past the open duration and transition to half-open
+        clock.advance(Duration::from_millis(20));
         assert!(cb.allow_request());
+        assert_eq!(cb.state(), CircuitState::HalfOpen);
         
-        // Failure in half-open should go back to open
+        // Failure in half-open should go back to open, restarting the timer
         cb.record_failure();
         assert_eq!(cb.state(), CircuitState::Open);
+        assert!(!cb.allow_request());
+        
+        clock.advance(Duration::from_millis(10));
+        assert!(cb.allow_request());
+        assert_eq!(cb.state(), CircuitState::HalfOpen);
+    }
+
+    #[test]
+    fn test_mock_clock_drives_probe_timing() {
+        let config = CircuitBreakerConfig {
+            open_duration: Duration::from_secs(30),
+            ..CircuitBreakerConfig::default()
+        };
+        let clock = MockClock::new();
+        let cb = CircuitBreaker::new("test", config).with_clock(Arc::new(clock.clone()));
+
+        cb.trip();
+        assert_eq!(cb.time_until_probe(), Duration::from_secs(30));
+
+        clock.advance(Duration::from_secs(25));
+        assert_eq!(cb.time_until_probe(), Duration::from_secs(5));
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cb.time_until_probe(), Duration::ZERO);
+        assert!(cb.allow_request());
+        assert_eq!(cb.state(), CircuitState::HalfOpen);
+    }
+
+    #[tokio::test]
+    async fn test_probe_closes_circuit_without_foreground_requests() {
+        let config = CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_duration: Duration::from_millis(100),
+            success_threshold: 2,
+            half_open_max_requests: 2,
+        };
+        let cb = Arc::new(CircuitBreaker::new("probe", config));
+        let probes = Arc::new(AtomicU32::new(0));
+
+        // Mimics a breaker-protected health check
+        let probe_cb = cb.clone();
+        let probe_count = probes.clone();
+        let _probe = cb.spawn_probe(move || {
+            let cb = probe_cb.clone();
+            let probes = probe_count.clone();
+            async move {
+                probes.fetch_add(1, Ordering::Relaxed);
+                if !cb.allow_request() {
+                    return false;
+                }
+                cb.record_success();
+                true
+            }
+        });
+
+        cb.trip();
+        assert_eq!(cb.state(), CircuitState::Open);
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert_eq!(cb.state(), CircuitState::Closed);
+        assert_eq!(probes.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_probe_not_using_breaker_stays_bounded() {
+        let config = CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_duration: Duration::from_millis(100),
+            success_threshold: 3,
+            half_open_max_requests: 3,
+        };
+        let cb = Arc::new(CircuitBreaker::new("probe", config));
+        let probes = Arc::new(AtomicU32::new(0));
+
+        // Like the X API health check: healthy without touching the breaker
+        let probe_count = probes.clone();
+        let _probe = cb.spawn_probe(move || {
+            let probes = probe_count.clone();
+            async move {
+                probes.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+        });
+
+        cb.trip();
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        assert_eq!(cb.state(), CircuitState::Closed);
+        assert_eq!(probes.load(Ordering::Relaxed), 3);
+        assert_eq!(cb.stats().total_successes, 3);
     }
 }
@@ -2,7 +2,18 @@
 
 use anyhow::Result;
 use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::append_log::LogGranularity;
+use crate::dedup::{DedupCanonicalization, DedupHash};
+use crate::message_bus::MessageBusType;
+use crate::metrics::{STAGE_EMBED, STAGE_ENRICH, STAGE_NORMALIZE, STAGE_PUBLISH};
+use crate::pipeline::stages::PayloadFilters;
+use crate::pipeline::{OnError, OnFull};
+use crate::schemas::IngestionDataType;
+use crate::sources::SourceId;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
@@ -11,6 +22,8 @@ pub struct Config {
     pub monad_rpc_url: String,
     #[serde(default = "default_monad_ws")]
     pub monad_rpc_url_ws: String,
+    /// Comma-separated fallback RPC URLs tried in order when the primary fails
+    pub monad_rpc_fallback_urls: Option<String>,
     
     // nad.fun API
     #[serde(default = "default_nadfun_api")]
@@ -19,7 +32,24 @@ pub struct Config {
     
     // Database
     pub database_url: Option<String>,
+    /// Postgres pool sizing: most and fewest open connections, and how long
+    /// a query waits for a free one
+    pub database_pool_size: Option<u32>,
+    pub database_pool_min_connections: Option<u32>,
+    pub database_pool_acquire_timeout_secs: Option<u64>,
+    /// Apply the embedded schema migrations when storage connects
+    #[serde(default)]
+    pub run_migrations: bool,
     pub redis_url: Option<String>,
+    /// PEM CA certificate for `rediss://` servers not trusted by the system roots
+    pub redis_ca_cert: Option<PathBuf>,
+    /// Redis TTLs for cached read-model data
+    #[serde(default = "default_cache_ttl_trending")]
+    pub cache_ttl_trending_secs: u64,
+    #[serde(default = "default_cache_ttl_new_tokens")]
+    pub cache_ttl_new_tokens_secs: u64,
+    #[serde(default = "default_cache_ttl_chain_stats")]
+    pub cache_ttl_chain_stats_secs: u64,
     
     // Rate limiting (requests per minute)
     #[serde(default = "default_rate_limit")]
@@ -33,6 +63,41 @@ pub struct Config {
     #[serde(default = "default_social_rate_limit")]
     pub x_api_rate_limit_rpm: u32,
     
+    // Per-source concurrency caps, under `max_concurrent_requests`
+    pub concurrency_nadfun: Option<usize>,
+    pub concurrency_monad: Option<usize>,
+    pub concurrency_newsapi: Option<usize>,
+    pub concurrency_cryptopanic: Option<usize>,
+    pub concurrency_x_api: Option<usize>,
+    
+    // Derive event ids from dedup keys so replays keep the same ids
+    #[serde(default)]
+    pub deterministic_event_ids: bool,
+    
+    // NewsAPI search queries, `;`-separated (default: NewsApiSource's one
+    // built-in query), and how many are fetched at once
+    pub newsapi_queries: Option<String>,
+    pub newsapi_query_concurrency: Option<usize>,
+    
+    // Publisher clock skew allowed when filtering items by `since` (seconds)
+    pub clock_skew_tolerance_secs: Option<u64>,
+    
+    // Engagement thresholds for event priority (default: PriorityConfig::default)
+    pub priority_social_followers_high: Option<u64>,
+    pub priority_social_likes_medium: Option<u64>,
+    pub priority_social_reposts_medium: Option<u64>,
+    pub priority_news_important_critical: Option<u32>,
+    pub priority_news_positive_critical: Option<u32>,
+    pub priority_news_important_high: Option<u32>,
+    pub priority_news_positive_high: Option<u32>,
+    pub priority_news_important_medium: Option<u32>,
+    pub priority_news_positive_medium: Option<u32>,
+    
+    // Per-source User-Agent overrides (default: HttpClientConfig.user_agent)
+    pub newsapi_user_agent: Option<String>,
+    pub cryptopanic_user_agent: Option<String>,
+    pub x_api_user_agent: Option<String>,
+    
     // Harvesting intervals (milliseconds)
     #[serde(default = "default_trending_interval")]
     pub trending_interval_ms: u64,
@@ -44,6 +109,14 @@ pub struct Config {
     pub news_interval_ms: u64,
     #[serde(default = "default_social_interval")]
     pub social_interval_ms: u64,
+    /// Quota units per minute shared by the polling loops; a fetch whose
+    /// source's estimated cost doesn't fit is skipped (unset is unlimited)
+    pub fetch_budget_per_minute: Option<u32>,
+    /// Most pages a harvest cycle follows per source (default 1)
+    pub harvest_max_pages: Option<usize>,
+    /// Keeps only items in this language (ISO 639-1 code, like `en`) on
+    /// every fetch; the `--language` flag overrides it
+    pub language_filter: Option<String>,
     
     // External APIs
     pub news_api_key: Option<String>,
@@ -60,6 +133,21 @@ pub struct Config {
     pub circuit_breaker_failure_threshold: u32,
     #[serde(default = "default_circuit_breaker_timeout")]
     pub circuit_breaker_open_duration_secs: u64,
+    /// Probe open circuits with a health check instead of waiting for traffic
+    #[serde(default)]
+    pub circuit_breaker_probe_enabled: bool,
+    /// Interval between source health sweeps (feeds `/readyz` and `status`)
+    #[serde(default = "default_health_check_interval")]
+    pub health_check_interval_secs: u64,
+    /// A source health check taking longer than this counts as unhealthy
+    #[serde(default = "default_health_check_timeout")]
+    pub health_check_timeout_ms: u64,
+    /// `/readyz` fails while an enabled polled source has gone this long
+    /// without returning events (unchecked when unset)
+    pub readyz_max_event_age_secs: Option<u64>,
+    /// Minimum seconds between summaries of a repeating source error
+    #[serde(default = "default_error_log_summary")]
+    pub error_log_summary_secs: u64,
     
     // Storage
     #[serde(default = "default_storage_type")]
@@ -69,12 +157,45 @@ pub struct Config {
     pub s3_bucket: Option<String>,
     pub s3_prefix: Option<String>,
     pub s3_endpoint_url: Option<String>,
+    /// Whether raw API responses are written to the append log
+    #[serde(default = "default_log_raw_responses")]
+    pub log_raw_responses: bool,
+    /// Logged raw responses above this size keep only a truncated prefix
+    #[serde(default = "default_max_raw_bytes")]
+    pub max_raw_bytes: u64,
+    /// Filesystem append-log file rollover (`daily` or `hourly`)
+    #[serde(default)]
+    pub append_log_granularity: LogGranularity,
+    /// Entries the news/social loops buffer before a batched append
+    #[serde(default = "default_append_batch_size")]
+    pub append_batch_size: usize,
+    /// Longest time buffered entries wait before being flushed
+    #[serde(default = "default_append_flush_interval")]
+    pub append_flush_interval_ms: u64,
     
     // Deduplication
     #[serde(default = "default_dedup_cache_size")]
     pub dedup_cache_size: usize,
     #[serde(default = "default_dedup_ttl")]
     pub dedup_ttl_seconds: u64,
+    #[serde(default)]
+    pub dedup_hash: DedupHash,
+    /// Title/post text canonicalization before dedup hashing (default: each
+    /// key's original scheme)
+    pub dedup_canonicalization: Option<DedupCanonicalization>,
+    /// Sources whose events bypass dedup, like `nadfun,monad` (for snapshot
+    /// feeds that repeat identical-looking events on purpose)
+    pub skip_dedup_sources: Option<String>,
+    /// Bypass dedup for every source (set by the `--no-dedup` flag)
+    #[serde(skip)]
+    pub no_dedup: bool,
+    
+    // Payload filtering
+    /// Per-source payload keys to keep, like `newsapi=title|url|publishedAt`
+    /// (other keys are dropped before events are logged or published)
+    pub payload_allow_fields: Option<String>,
+    /// Per-source payload keys to drop, like `x_api=raw|authorEmail`
+    pub payload_deny_fields: Option<String>,
     
     // Checkpointing
     #[serde(default = "default_checkpoint_dir")]
@@ -89,8 +210,37 @@ pub struct Config {
     pub pipeline_enrich_workers: Option<usize>,
     pub pipeline_embed_workers: Option<usize>,
     pub pipeline_publish_workers: Option<usize>,
+    /// Events published per `publish_batch` call (1 publishes one at a time)
+    pub pipeline_publish_batch_size: Option<usize>,
+    /// Longest a partial publish batch waits before it is sent
+    pub pipeline_publish_flush_interval_ms: Option<u64>,
     pub pipeline_enable_enrich: Option<bool>,
     pub pipeline_enable_embed: Option<bool>,
+    pub pipeline_shutdown_deadline_secs: Option<u64>,
+    pub pipeline_max_payload_bytes: Option<u64>,
+    pub pipeline_submit_backpressure_timeout_ms: Option<u64>,
+    /// Soft RSS limit (bytes) above which Low-priority submissions are shed
+    pub pipeline_max_memory_bytes: Option<u64>,
+    pub pipeline_on_full: Option<OnFull>,
+    pub pipeline_embedding_model: Option<String>,
+    pub pipeline_embedding_dim: Option<usize>,
+    /// Events whose `data_timestamp` is older than this are tagged stale in enrich
+    pub pipeline_stale_after_secs: Option<u64>,
+    /// Per-stage error handling like `publish=dead_letter,enrich=retry`
+    /// (unlisted stages drop items they fail on)
+    pub pipeline_on_error: Option<String>,
+    /// In-worker retries for stages set to `retry`
+    pub pipeline_stage_max_retries: Option<u32>,
+    /// Longest a stage may spend on one item before it counts as failed
+    pub pipeline_stage_timeout_secs: Option<u64>,
+    /// Per-stage timeouts in seconds like `embed=120,publish=10`
+    /// (unlisted stages use `pipeline_stage_timeout_secs`)
+    pub pipeline_stage_timeouts: Option<String>,
+    /// Stream dead-lettered items are published to
+    pub pipeline_dead_letter_stream: Option<String>,
+    /// Comma-separated sources given their own pipeline (and bus connection)
+    /// in `pipeline` mode, so a stall in one doesn't block the others
+    pub pipeline_isolated_sources: Option<String>,
     
     // Message bus configuration
     #[serde(default = "default_message_bus_type")]
@@ -98,12 +248,26 @@ pub struct Config {
     pub nats_url: Option<String>,
     #[serde(default = "default_message_bus_stream")]
     pub message_bus_stream: String,
+    /// Stream/subject receiving Critical events for alerting consumers
+    #[serde(default = "default_message_bus_priority_stream")]
+    pub message_bus_priority_stream: String,
+    /// Per-data-type streams like `news=neuro:news,social=neuro:social`
+    /// (unlisted types go to `message_bus_stream`)
+    pub message_bus_data_type_streams: Option<String>,
+    /// Stream/subject that also receives audit events (append log only when unset)
+    pub message_bus_audit_stream: Option<String>,
     
     // Metrics server
     #[serde(default = "default_metrics_port")]
     pub metrics_port: u16,
     #[serde(default = "default_metrics_enabled")]
     pub metrics_enabled: bool,
+    /// Bearer token for admin endpoints on the metrics server (disabled if unset)
+    pub metrics_auth_token: Option<String>,
+
+    // Logging
+    /// Log filter applied on SIGHUP (startup uses `RUST_LOG` or `--log-level`)
+    pub log_level: Option<String>,
 }
 
 fn default_monad_rpc() -> String {
@@ -166,6 +330,18 @@ fn default_circuit_breaker_timeout() -> u64 {
     30
 }
 
+fn default_health_check_interval() -> u64 {
+    60
+}
+
+fn default_health_check_timeout() -> u64 {
+    10_000
+}
+
+fn default_error_log_summary() -> u64 {
+    300
+}
+
 fn default_storage_type() -> String {
     "filesystem".to_string()
 }
@@ -174,6 +350,34 @@ fn default_data_dir() -> PathBuf {
     PathBuf::from("./data/append_log")
 }
 
+fn default_log_raw_responses() -> bool {
+    true
+}
+
+fn default_max_raw_bytes() -> u64 {
+    1024 * 1024 // 1 MiB
+}
+
+fn default_append_batch_size() -> usize {
+    50
+}
+
+fn default_append_flush_interval() -> u64 {
+    5000 // 5 seconds
+}
+
+fn default_cache_ttl_trending() -> u64 {
+    60
+}
+
+fn default_cache_ttl_new_tokens() -> u64 {
+    30
+}
+
+fn default_cache_ttl_chain_stats() -> u64 {
+    10
+}
+
 fn default_dedup_cache_size() -> usize {
     100_000
 }
@@ -198,6 +402,10 @@ fn default_message_bus_stream() -> String {
     "neuro:ingestion".to_string()
 }
 
+fn default_message_bus_priority_stream() -> String {
+    "neuro:ingestion:critical".to_string()
+}
+
 fn default_metrics_port() -> u16 {
     9090
 }
@@ -224,13 +432,167 @@ impl Config {
         Ok(cfg)
     }
 
+    /// Reloads configuration, letting `.env` values replace ones already set
+    pub fn reload() -> Result<Self> {
+        dotenvy::dotenv_override().ok();
+        Self::load()
+    }
+
     /// Validates the configuration
     pub fn validate(&self) -> Result<()> {
         // Check for required API keys based on enabled sources
         // (We'll make these optional for now and validate at runtime)
+        self.data_type_streams()?;
+        self.skip_dedup_sources()?;
+        self.isolated_sources()?;
+        self.payload_filters()?;
+        self.stage_on_error()?;
+        self.stage_timeouts()?;
+        if let Some(code) = self.language_filter.as_deref().map(str::trim) {
+            if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+                anyhow::bail!("LANGUAGE_FILTER must be an ISO 639-1 code like 'en', got '{}'", code);
+            }
+        }
+        for source in SourceId::ALL {
+            if self.concurrency_limit(source) == Some(0) {
+                anyhow::bail!("Concurrency cap for {} must be greater than zero", source);
+            }
+        }
+        self.validate_storage()?;
+        self.validate_message_bus()?;
         Ok(())
     }
 
+    /// Checks the append log storage settings fit together
+    fn validate_storage(&self) -> Result<()> {
+        match self.storage_type.as_str() {
+            "filesystem" | "local" => Ok(()),
+            "s3" => match self.s3_bucket.as_deref().map(str::trim) {
+                Some(bucket) if !bucket.is_empty() => Ok(()),
+                _ => anyhow::bail!("STORAGE_TYPE=s3 requires S3_BUCKET to be set"),
+            },
+            other => anyhow::bail!(
+                "Unknown STORAGE_TYPE '{}' (expected filesystem, local or s3)",
+                other
+            ),
+        }
+    }
+
+    /// Checks the message bus type is known and, when a bus URL is given,
+    /// that it is the one for the selected bus
+    ///
+    /// Having no bus URL at all is fine: only `pipeline` mode needs one.
+    fn validate_message_bus(&self) -> Result<()> {
+        let bus_type: MessageBusType = self.message_bus_type.parse()?;
+        let (needed, other) = match bus_type {
+            MessageBusType::Redis => (("REDIS_URL", &self.redis_url), ("NATS_URL", &self.nats_url)),
+            MessageBusType::Nats => (("NATS_URL", &self.nats_url), ("REDIS_URL", &self.redis_url)),
+            MessageBusType::Mock => return Ok(()),
+        };
+
+        if needed.1.is_none() && other.1.is_some() {
+            anyhow::bail!(
+                "MESSAGE_BUS_TYPE={} requires {}, but only {} is set",
+                self.message_bus_type,
+                needed.0,
+                other.0
+            );
+        }
+        Ok(())
+    }
+
+    /// Gets the sources whose events bypass dedup
+    pub fn skip_dedup_sources(&self) -> Result<HashSet<SourceId>> {
+        match &self.skip_dedup_sources {
+            Some(sources) => parse_source_ids(sources),
+            None => Ok(HashSet::new()),
+        }
+    }
+
+    /// Gets the sources run through their own pipeline in `pipeline` mode
+    pub fn isolated_sources(&self) -> Result<HashSet<SourceId>> {
+        match &self.pipeline_isolated_sources {
+            Some(sources) => parse_source_ids(sources),
+            None => Ok(HashSet::new()),
+        }
+    }
+
+    /// Gets the per-source payload keys to keep
+    pub fn payload_filters(&self) -> Result<PayloadFilters> {
+        let parse = |fields: &Option<String>| -> Result<HashMap<String, HashSet<String>>> {
+            let fields = match fields {
+                Some(fields) => parse_payload_fields(fields)?,
+                None => HashMap::new(),
+            };
+            Ok(fields.into_iter().map(|(source, keys)| (source.as_str().to_string(), keys)).collect())
+        };
+        Ok(PayloadFilters::new(
+            parse(&self.payload_allow_fields)?,
+            parse(&self.payload_deny_fields)?,
+        ))
+    }
+
+    /// Gets the per-data-type stream mapping for published events
+    pub fn data_type_streams(&self) -> Result<HashMap<IngestionDataType, String>> {
+        match &self.message_bus_data_type_streams {
+            Some(streams) => parse_data_type_streams(streams),
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    /// Gets the per-stage error handling policies
+    pub fn stage_on_error(&self) -> Result<HashMap<String, OnError>> {
+        match &self.pipeline_on_error {
+            Some(policies) => parse_stage_on_error(policies),
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    /// Gets the per-stage timeout overrides
+    pub fn stage_timeouts(&self) -> Result<HashMap<String, Duration>> {
+        match &self.pipeline_stage_timeouts {
+            Some(timeouts) => parse_stage_timeouts(timeouts),
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    /// Gets the configured NewsAPI search queries (empty if unset)
+    pub fn newsapi_queries(&self) -> Vec<String> {
+        self.newsapi_queries
+            .as_deref()
+            .map(|queries| {
+                queries
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|query| !query.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Gets the publisher clock skew allowed when filtering by `since`
+    pub fn clock_skew_tolerance(&self) -> chrono::Duration {
+        self.clock_skew_tolerance_secs
+            .map(|secs| chrono::Duration::seconds(secs as i64))
+            .unwrap_or(crate::sources::DEFAULT_CLOCK_SKEW_TOLERANCE)
+    }
+
+    /// Gets all Monad RPC URLs, primary first
+    pub fn monad_rpc_urls(&self) -> Vec<String> {
+        let mut urls = vec![self.monad_rpc_url.clone()];
+        if let Some(ref fallbacks) = self.monad_rpc_fallback_urls {
+            urls.extend(
+                fallbacks
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|url| !url.is_empty())
+                    .map(String::from),
+            );
+        }
+        urls
+    }
+
     /// Checks if NewsAPI is configured
     pub fn has_newsapi(&self) -> bool {
         self.news_api_key.is_some()
@@ -246,11 +608,19 @@ impl Config {
         self.twitter_bearer_token.is_some()
     }
 
+    /// Gets the size cap for raw responses written to the append log
+    /// (`None` when raw responses aren't logged)
+    pub fn raw_log_limit(&self) -> Option<u64> {
+        self.log_raw_responses.then_some(self.max_raw_bytes)
+    }
+
     /// Gets the message bus connection URL
     pub fn message_bus_url(&self) -> Option<&str> {
         match self.message_bus_type.as_str() {
             "redis" | "redis_streams" => self.redis_url.as_deref(),
             "nats" | "nats_jetstream" => self.nats_url.as_deref(),
+            // In-memory bus, nothing to connect to
+            "mock" => Some("mock://"),
             _ => None,
         }
     }
@@ -259,6 +629,186 @@ impl Config {
     pub fn has_message_bus(&self) -> bool {
         self.message_bus_url().is_some()
     }
+
+    /// Gets the configured rate limit (requests per minute) for a source
+    pub fn rate_limit_rpm(&self, source: SourceId) -> u32 {
+        match source {
+            SourceId::NadFun => self.nadfun_rate_limit_rpm,
+            SourceId::Monad => self.rpc_rate_limit_rpm,
+            SourceId::NewsApi => self.newsapi_rate_limit_rpm,
+            SourceId::CryptoPanic => self.cryptopanic_rate_limit_rpm,
+            SourceId::XApi => self.x_api_rate_limit_rpm,
+        }
+    }
+
+    /// Gets the concurrency cap for a source (`None` if only the global
+    /// `max_concurrent_requests` applies)
+    pub fn concurrency_limit(&self, source: SourceId) -> Option<usize> {
+        match source {
+            SourceId::NadFun => self.concurrency_nadfun,
+            SourceId::Monad => self.concurrency_monad,
+            SourceId::NewsApi => self.concurrency_newsapi,
+            SourceId::CryptoPanic => self.concurrency_cryptopanic,
+            SourceId::XApi => self.concurrency_x_api,
+        }
+    }
+
+    /// Replaces config-derived rate limits with per-source overrides
+    pub fn apply_rate_limit_overrides(&mut self, overrides: &HashMap<SourceId, u32>) {
+        for (&source, &rpm) in overrides {
+            let field = match source {
+                SourceId::NadFun => &mut self.nadfun_rate_limit_rpm,
+                SourceId::Monad => &mut self.rpc_rate_limit_rpm,
+                SourceId::NewsApi => &mut self.newsapi_rate_limit_rpm,
+                SourceId::CryptoPanic => &mut self.cryptopanic_rate_limit_rpm,
+                SourceId::XApi => &mut self.x_api_rate_limit_rpm,
+            };
+            *field = rpm;
+        }
+    }
+}
+
+/// Parses a source list like `nadfun,monad` (blank entries are ignored)
+pub fn parse_source_ids(input: &str) -> Result<HashSet<SourceId>> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| Ok(s.parse::<SourceId>()?))
+        .collect()
+}
+
+/// Parses rate limit overrides like `newsapi=30,x_api=15`
+///
+/// Source names must be known source ids and rates must be positive.
+pub fn parse_rate_limit_overrides(input: &str) -> Result<HashMap<SourceId, u32>> {
+    let mut overrides = HashMap::new();
+
+    for pair in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (name, rpm) = pair
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid rate limit override '{}' (expected source=rpm)", pair))?;
+
+        let source: SourceId = name.trim().parse()?;
+        let rpm: u32 = rpm
+            .trim()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid rate limit for {}: '{}'", source, rpm.trim()))?;
+        if rpm == 0 {
+            anyhow::bail!("Rate limit for {} must be greater than zero", source);
+        }
+
+        overrides.insert(source, rpm);
+    }
+
+    Ok(overrides)
+}
+
+/// Parses per-source payload keys like `x_api=raw|authorEmail,newsapi=content`
+///
+/// Source names must be known source ids; a source listed twice gets the
+/// keys from both entries.
+pub fn parse_payload_fields(input: &str) -> Result<HashMap<SourceId, HashSet<String>>> {
+    let mut fields: HashMap<SourceId, HashSet<String>> = HashMap::new();
+
+    for pair in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (name, keys) = pair
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid payload field list '{}' (expected source=key|key)", pair))?;
+
+        let source: SourceId = name.trim().parse()?;
+        let keys: Vec<&str> = keys.split('|').map(str::trim).filter(|k| !k.is_empty()).collect();
+        if keys.is_empty() {
+            anyhow::bail!("No payload keys listed for {}", source);
+        }
+
+        fields.entry(source).or_default().extend(keys.into_iter().map(String::from));
+    }
+
+    Ok(fields)
+}
+
+/// Parses per-data-type streams like `news=neuro:news,social=neuro:social`
+///
+/// Data types use their snake_case names (`token_data`, `news`, ...).
+pub fn parse_data_type_streams(input: &str) -> Result<HashMap<IngestionDataType, String>> {
+    let mut streams = HashMap::new();
+
+    for pair in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (name, stream) = pair
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid data type stream '{}' (expected type=stream)", pair))?;
+
+        let data_type: IngestionDataType =
+            serde_json::from_value(serde_json::Value::String(name.trim().to_string()))
+                .map_err(|_| anyhow::anyhow!("Unknown data type: '{}'", name.trim()))?;
+        let stream = stream.trim();
+        if stream.is_empty() {
+            anyhow::bail!("Stream for data type '{}' must not be empty", name.trim());
+        }
+
+        streams.insert(data_type, stream.to_string());
+    }
+
+    Ok(streams)
+}
+
+/// Pipeline stages that process items (and so take per-stage settings)
+const PROCESSING_STAGES: [&str; 4] = [STAGE_NORMALIZE, STAGE_ENRICH, STAGE_EMBED, STAGE_PUBLISH];
+
+/// Parses per-stage timeouts in seconds like `embed=120,publish=10`
+///
+/// Stages are the same as for `parse_stage_on_error`; timeouts must be
+/// positive.
+pub fn parse_stage_timeouts(input: &str) -> Result<HashMap<String, Duration>> {
+    let mut timeouts = HashMap::new();
+
+    for pair in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (stage, secs) = pair
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid stage timeout '{}' (expected stage=seconds)", pair))?;
+
+        let stage = stage.trim();
+        if !PROCESSING_STAGES.contains(&stage) {
+            anyhow::bail!("Unknown pipeline stage: '{}'", stage);
+        }
+        let secs: u64 = secs
+            .trim()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid timeout for stage '{}': '{}'", stage, secs.trim()))?;
+        if secs == 0 {
+            anyhow::bail!("Timeout for stage '{}' must be greater than zero", stage);
+        }
+
+        timeouts.insert(stage.to_string(), Duration::from_secs(secs));
+    }
+
+    Ok(timeouts)
+}
+
+/// Parses per-stage error policies like `publish=dead_letter,enrich=retry`
+///
+/// Stages are `normalize`, `enrich`, `embed` and `publish`; policies are
+/// `drop`, `retry` and `dead_letter`.
+pub fn parse_stage_on_error(input: &str) -> Result<HashMap<String, OnError>> {
+    let mut policies = HashMap::new();
+
+    for pair in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (stage, policy) = pair
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid stage policy '{}' (expected stage=policy)", pair))?;
+
+        let stage = stage.trim();
+        if !PROCESSING_STAGES.contains(&stage) {
+            anyhow::bail!("Unknown pipeline stage: '{}'", stage);
+        }
+        let policy: OnError = serde_json::from_value(serde_json::Value::String(policy.trim().to_string()))
+            .map_err(|_| anyhow::anyhow!("Unknown error policy for stage '{}': '{}'", stage, policy.trim()))?;
+
+        policies.insert(stage.to_string(), policy);
+    }
+
+    Ok(policies)
 }
 
 #[cfg(test)]
@@ -270,20 +820,53 @@ mod tests {
         let config = Config {
             monad_rpc_url: default_monad_rpc(),
             monad_rpc_url_ws: default_monad_ws(),
+            monad_rpc_fallback_urls: Some("https://rpc-2.monad.xyz, ,https://rpc-3.monad.xyz".to_string()),
             nadfun_api_url: default_nadfun_api(),
             nadfun_api_key: None,
             database_url: None,
+            database_pool_size: None,
+            database_pool_min_connections: None,
+            database_pool_acquire_timeout_secs: None,
+            run_migrations: false,
             redis_url: None,
+            redis_ca_cert: None,
+            cache_ttl_trending_secs: default_cache_ttl_trending(),
+            cache_ttl_new_tokens_secs: default_cache_ttl_new_tokens(),
+            cache_ttl_chain_stats_secs: default_cache_ttl_chain_stats(),
             nadfun_rate_limit_rpm: default_rate_limit(),
             rpc_rate_limit_rpm: default_rpc_rate_limit(),
             newsapi_rate_limit_rpm: default_news_rate_limit(),
             cryptopanic_rate_limit_rpm: default_news_rate_limit(),
             x_api_rate_limit_rpm: default_social_rate_limit(),
+            concurrency_nadfun: None,
+            concurrency_monad: None,
+            concurrency_newsapi: None,
+            concurrency_cryptopanic: None,
+            concurrency_x_api: None,
+            deterministic_event_ids: false,
+            newsapi_queries: None,
+            newsapi_query_concurrency: None,
+            clock_skew_tolerance_secs: None,
+            priority_social_followers_high: None,
+            priority_social_likes_medium: None,
+            priority_social_reposts_medium: None,
+            priority_news_important_critical: None,
+            priority_news_positive_critical: None,
+            priority_news_important_high: None,
+            priority_news_positive_high: None,
+            priority_news_important_medium: None,
+            priority_news_positive_medium: None,
+            newsapi_user_agent: None,
+            cryptopanic_user_agent: None,
+            x_api_user_agent: None,
             trending_interval_ms: default_trending_interval(),
             new_tokens_interval_ms: default_new_tokens_interval(),
             market_data_interval_ms: default_market_data_interval(),
             news_interval_ms: default_news_interval(),
             social_interval_ms: default_social_interval(),
+            fetch_budget_per_minute: None,
+            harvest_max_pages: None,
+            language_filter: None,
             news_api_key: None,
             cryptopanic_api_key: None,
             coingecko_api_key: None,
@@ -291,19 +874,204 @@ mod tests {
             max_concurrent_requests: default_max_concurrent_requests(),
             circuit_breaker_failure_threshold: default_circuit_breaker_threshold(),
             circuit_breaker_open_duration_secs: default_circuit_breaker_timeout(),
+            circuit_breaker_probe_enabled: false,
+            health_check_interval_secs: default_health_check_interval(),
+            health_check_timeout_ms: default_health_check_timeout(),
+            readyz_max_event_age_secs: None,
+            error_log_summary_secs: default_error_log_summary(),
             storage_type: default_storage_type(),
             data_dir: default_data_dir(),
             s3_bucket: None,
             s3_prefix: None,
             s3_endpoint_url: None,
+            log_raw_responses: default_log_raw_responses(),
+            max_raw_bytes: default_max_raw_bytes(),
+            append_log_granularity: LogGranularity::Daily,
+            append_batch_size: default_append_batch_size(),
+            append_flush_interval_ms: default_append_flush_interval(),
             dedup_cache_size: default_dedup_cache_size(),
+            skip_dedup_sources: None,
+            no_dedup: false,
+            payload_allow_fields: None,
+            payload_deny_fields: None,
             dedup_ttl_seconds: default_dedup_ttl(),
+            dedup_hash: DedupHash::Sha256,
+            dedup_canonicalization: None,
             checkpoint_dir: default_checkpoint_dir(),
             checkpoint_interval_secs: default_checkpoint_interval(),
+            pipeline_channel_capacity: None,
+            pipeline_fetch_workers: None,
+            pipeline_normalize_workers: None,
+            pipeline_enrich_workers: None,
+            pipeline_embed_workers: None,
+            pipeline_publish_workers: None,
+            pipeline_publish_batch_size: None,
+            pipeline_publish_flush_interval_ms: None,
+            pipeline_enable_enrich: None,
+            pipeline_enable_embed: None,
+            pipeline_shutdown_deadline_secs: None,
+            pipeline_max_payload_bytes: None,
+            pipeline_submit_backpressure_timeout_ms: None,
+            pipeline_max_memory_bytes: None,
+            pipeline_on_full: None,
+            pipeline_embedding_model: None,
+            pipeline_embedding_dim: None,
+            pipeline_stale_after_secs: None,
+            pipeline_on_error: None,
+            pipeline_stage_max_retries: None,
+            pipeline_stage_timeout_secs: None,
+            pipeline_stage_timeouts: None,
+            pipeline_dead_letter_stream: None,
+            pipeline_isolated_sources: None,
+            message_bus_type: default_message_bus_type(),
+            nats_url: None,
+            message_bus_stream: default_message_bus_stream(),
+            message_bus_priority_stream: default_message_bus_priority_stream(),
+            message_bus_data_type_streams: None,
+            message_bus_audit_stream: None,
+            metrics_port: default_metrics_port(),
+            metrics_enabled: default_metrics_enabled(),
+            metrics_auth_token: None,
+            log_level: None,
         };
         
         assert_eq!(config.monad_rpc_url, "https://rpc.monad.xyz");
+        assert_eq!(
+            config.monad_rpc_urls(),
+            vec!["https://rpc.monad.xyz", "https://rpc-2.monad.xyz", "https://rpc-3.monad.xyz"]
+        );
         assert_eq!(config.nadfun_rate_limit_rpm, 60);
         assert_eq!(config.max_concurrent_requests, 10);
     }
+
+    #[test]
+    fn test_parse_rate_limit_overrides_rejects_invalid() {
+        assert!(parse_rate_limit_overrides("").unwrap().is_empty());
+        assert!(parse_rate_limit_overrides("coingecko=10").is_err());
+        assert!(parse_rate_limit_overrides("newsapi").is_err());
+        assert!(parse_rate_limit_overrides("newsapi=fast").is_err());
+        assert!(parse_rate_limit_overrides("newsapi=0").is_err());
+    }
+
+    #[test]
+    fn test_parse_payload_fields() {
+        let fields = parse_payload_fields("x_api=raw| authorEmail , newsapi=content,x_api=media").unwrap();
+        assert_eq!(
+            fields[&SourceId::XApi],
+            HashSet::from(["raw".to_string(), "authorEmail".to_string(), "media".to_string()])
+        );
+        assert_eq!(fields[&SourceId::NewsApi], HashSet::from(["content".to_string()]));
+
+        assert!(parse_payload_fields("twitter=raw").is_err());
+        assert!(parse_payload_fields("x_api").is_err());
+        assert!(parse_payload_fields("x_api=|").is_err());
+    }
+
+    #[test]
+    fn test_parse_data_type_streams() {
+        let streams = parse_data_type_streams("news=neuro:news, social=neuro:social").unwrap();
+        assert_eq!(streams.len(), 2);
+        assert_eq!(streams[&IngestionDataType::News], "neuro:news");
+        assert_eq!(streams[&IngestionDataType::Social], "neuro:social");
+
+        assert!(parse_data_type_streams("").unwrap().is_empty());
+        assert!(parse_data_type_streams("gossip=neuro:gossip").is_err());
+        assert!(parse_data_type_streams("news").is_err());
+        assert!(parse_data_type_streams("news=").is_err());
+    }
+
+    #[test]
+    fn test_parse_stage_on_error() {
+        let policies = parse_stage_on_error("publish=dead_letter, enrich=retry,embed=drop").unwrap();
+        assert_eq!(policies.len(), 3);
+        assert_eq!(policies["publish"], OnError::DeadLetter);
+        assert_eq!(policies["enrich"], OnError::Retry);
+        assert_eq!(policies["embed"], OnError::Drop);
+
+        assert!(parse_stage_on_error("").unwrap().is_empty());
+        assert!(parse_stage_on_error("fetch=retry").is_err());
+        assert!(parse_stage_on_error("publish=requeue").is_err());
+        assert!(parse_stage_on_error("publish").is_err());
+    }
+
+    #[test]
+    fn test_parse_stage_timeouts() {
+        let timeouts = parse_stage_timeouts("embed=120, publish=10").unwrap();
+        assert_eq!(timeouts["embed"], Duration::from_secs(120));
+        assert_eq!(timeouts["publish"], Duration::from_secs(10));
+
+        assert!(parse_stage_timeouts("fetch=5").is_err());
+        assert!(parse_stage_timeouts("embed=0").is_err());
+        assert!(parse_stage_timeouts("embed=slow").is_err());
+    }
+
+    fn config_from(value: serde_json::Value) -> Config {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_validate_rejects_s3_without_bucket() {
+        let err = config_from(serde_json::json!({ "storage_type": "s3" })).validate().unwrap_err();
+        assert!(err.to_string().contains("S3_BUCKET"), "{}", err);
+
+        let err = config_from(serde_json::json!({ "storage_type": "s3", "s3_bucket": " " }))
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("S3_BUCKET"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_storage_type() {
+        let err = config_from(serde_json::json!({ "storage_type": "gcs" })).validate().unwrap_err();
+        assert!(err.to_string().contains("Unknown STORAGE_TYPE 'gcs'"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_rejects_bus_url_mismatch() {
+        let err = config_from(serde_json::json!({
+            "message_bus_type": "nats",
+            "redis_url": "redis://localhost:6379",
+        }))
+        .validate()
+        .unwrap_err();
+        assert_eq!(err.to_string(), "MESSAGE_BUS_TYPE=nats requires NATS_URL, but only REDIS_URL is set");
+
+        let err = config_from(serde_json::json!({
+            "message_bus_type": "redis",
+            "nats_url": "nats://localhost:4222",
+        }))
+        .validate()
+        .unwrap_err();
+        assert_eq!(err.to_string(), "MESSAGE_BUS_TYPE=redis requires REDIS_URL, but only NATS_URL is set");
+    }
+
+    #[test]
+    fn test_validate_checks_language_filter() {
+        assert!(config_from(serde_json::json!({ "language_filter": "en" })).validate().is_ok());
+        for code in ["english", "e1", ""] {
+            let err = config_from(serde_json::json!({ "language_filter": code })).validate().unwrap_err();
+            assert!(err.to_string().contains("LANGUAGE_FILTER"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_bus_type() {
+        let err = config_from(serde_json::json!({ "message_bus_type": "kafka" })).validate().unwrap_err();
+        assert!(err.to_string().contains("Unknown message bus type"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_accepts_consistent_storage_and_bus() {
+        let config = config_from(serde_json::json!({
+            "storage_type": "s3",
+            "s3_bucket": "neuro-ingestion",
+            "message_bus_type": "nats",
+            "nats_url": "nats://localhost:4222",
+            "redis_url": "redis://localhost:6379",
+        }));
+        config.validate().unwrap();
+
+        // No bus configured at all is fine outside pipeline mode
+        config_from(serde_json::json!({})).validate().unwrap();
+    }
 }
@@ -1,24 +1,116 @@
 //! Deduplication Module
 //!
 //! Prevents duplicate data ingestion using:
-//! - Content hash (SHA-256)
+//! - Content hash (SHA-256, or BLAKE3 for high-volume deployments)
 //! - Canonical URL normalization
 //!
+//! Titles and post text are canonicalized before hashing (see
+//! `DedupCanonicalization`), so near-identical items can share a key.
+//!
 //! Supports in-memory cache and Redis for distributed dedup.
 
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use parking_lot::RwLock;
 use tracing::{debug, warn};
 use url::Url;
 
+/// Hash algorithm used for dedup content hashes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DedupHash {
+    /// Untagged hex SHA-256 (the original format, so existing keys stay valid)
+    #[default]
+    Sha256,
+    /// `blake3:`-tagged hex BLAKE3, faster on hot paths
+    Blake3,
+}
+
+impl DedupHash {
+    /// Hashes content with this algorithm
+    pub fn hash(self, content: &str) -> String {
+        match self {
+            DedupHash::Sha256 => sha256_hex(content),
+            DedupHash::Blake3 => format!("blake3:{}", blake3::hash(content.as_bytes()).to_hex()),
+        }
+    }
+}
+
+/// Text canonicalization applied to titles and post content before hashing
+///
+/// When none is configured each key keeps its original scheme (`Lowercase`
+/// for news titles, `Conservative` for post content), so existing keys stay
+/// valid. Setting one changes the other key's hashes, and items already seen
+/// under the old keys can come through once more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DedupCanonicalization {
+    /// Trim only; "GM" and "gm" stay distinct (the original post scheme)
+    Conservative,
+    /// Trim and lowercase (the original news title scheme)
+    Lowercase,
+    /// Lowercase, strip emoji/punctuation and collapse whitespace
+    Aggressive,
+}
+
+impl DedupCanonicalization {
+    /// Canonicalizes text with this policy
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            DedupCanonicalization::Conservative => text.trim().to_string(),
+            DedupCanonicalization::Lowercase => text.trim().to_lowercase(),
+            DedupCanonicalization::Aggressive => {
+                let stripped: String = text
+                    .to_lowercase()
+                    .chars()
+                    .map(|c| if c.is_alphanumeric() || c.is_whitespace() { c } else { ' ' })
+                    .collect();
+                stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+            }
+        }
+    }
+}
+
+/// Process-wide dedup hash algorithm (defaults to SHA-256 when unset)
+static DEDUP_HASH: OnceCell<DedupHash> = OnceCell::new();
+
+/// Process-wide dedup canonicalization (each key's original scheme when unset)
+static DEDUP_CANONICALIZATION: OnceCell<DedupCanonicalization> = OnceCell::new();
+
+/// Sets the process-wide dedup hash algorithm; only the first call takes effect
+pub fn set_dedup_hash(algorithm: DedupHash) {
+    if DEDUP_HASH.set(algorithm).is_err() && dedup_hash() != algorithm {
+        warn!(requested = ?algorithm, active = ?dedup_hash(), "Dedup hash algorithm already set");
+    }
+}
+
+/// Gets the process-wide dedup hash algorithm
+pub fn dedup_hash() -> DedupHash {
+    DEDUP_HASH.get().copied().unwrap_or_default()
+}
+
+/// Sets the process-wide dedup canonicalization; only the first call takes effect
+pub fn set_dedup_canonicalization(policy: DedupCanonicalization) {
+    if DEDUP_CANONICALIZATION.set(policy).is_err() && dedup_canonicalization() != Some(policy) {
+        warn!(requested = ?policy, active = ?dedup_canonicalization(), "Dedup canonicalization already set");
+    }
+}
+
+/// Gets the process-wide dedup canonicalization, if one is set
+pub fn dedup_canonicalization() -> Option<DedupCanonicalization> {
+    DEDUP_CANONICALIZATION.get().copied()
+}
+
 /// Deduplication key
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct DedupKey {
     /// Source identifier (e.g., "newsapi", "cryptopanic")
     pub source: String,
-    /// Content hash (SHA-256)
+    /// Content hash (see `DedupHash`)
     pub content_hash: String,
     /// Canonical URL (if available)
     pub canonical_url: Option<String>,
@@ -55,14 +147,35 @@ impl DedupKey {
     }
 }
 
-/// Computes SHA-256 hash of content
+/// Computes the dedup hash of content with the configured algorithm
 pub fn compute_hash(content: &str) -> String {
+    dedup_hash().hash(content)
+}
+
+/// Computes the hex SHA-256 of content
+fn sha256_hex(content: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(content.as_bytes());
     let result = hasher.finalize();
     hex::encode(result)
 }
 
+/// Serializes an event payload canonically (keys sorted) so that equal
+/// payloads always produce identical bytes
+pub fn canonical_payload_json(payload: &HashMap<String, serde_json::Value>) -> String {
+    let sorted: BTreeMap<&String, &serde_json::Value> = payload.iter().collect();
+    serde_json::to_string(&sorted).unwrap_or_default()
+}
+
+/// Computes the canonical content hash stored in `IngestionEvent.payload_hash`
+///
+/// This is the single hashing scheme for `payload_hash`: `sha256:` followed by
+/// the hex SHA-256 of `canonical_payload_json`. Source-specific dedup hashes
+/// (title/url, author/text) only ever go into `deduplication_key`.
+pub fn payload_hash(payload: &HashMap<String, serde_json::Value>) -> String {
+    format!("sha256:{}", sha256_hex(&canonical_payload_json(payload)))
+}
+
 /// Normalizes URL to canonical form
 /// - Removes fragments (#...)
 /// - Removes tracking parameters (utm_*, fbclid, etc.)
@@ -109,6 +222,31 @@ pub fn canonicalize_url(url_str: &str) -> Result<String, url::ParseError> {
     Ok(result)
 }
 
+/// Cumulative dedup cache statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct DedupStats {
+    /// Keys currently held in memory
+    pub entries: usize,
+    /// Lookups that found a duplicate
+    pub hits: u64,
+    /// Lookups that found new content
+    pub misses: u64,
+    /// Keys dropped from memory to stay under the size limit
+    pub evictions: u64,
+}
+
+impl DedupStats {
+    /// Gets the fraction of lookups that were duplicates (0.0 with no lookups)
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
 /// In-memory deduplication store
 pub struct DedupStore {
     /// In-memory seen set
@@ -119,6 +257,12 @@ pub struct DedupStore {
     redis: Option<redis::aio::ConnectionManager>,
     /// TTL for Redis entries (seconds)
     redis_ttl: u64,
+    /// Lookups that found a duplicate
+    hits: AtomicU64,
+    /// Lookups that found new content
+    misses: AtomicU64,
+    /// Keys dropped by eviction
+    evictions: AtomicU64,
 }
 
 impl DedupStore {
@@ -129,6 +273,9 @@ impl DedupStore {
             max_entries,
             redis: None,
             redis_ttl: 86400, // 24 hours default
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
         }
     }
 
@@ -139,12 +286,23 @@ impl DedupStore {
             max_entries,
             redis: Some(redis),
             redis_ttl: ttl_seconds,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
         }
     }
 
     /// Checks if content is a duplicate and marks it as seen
     /// Returns true if duplicate, false if new
     pub async fn is_duplicate(&self, key: &DedupKey) -> bool {
+        let duplicate = self.lookup(key).await;
+        let counter = if duplicate { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        duplicate
+    }
+
+    /// Checks Redis, then memory, for a key
+    async fn lookup(&self, key: &DedupKey) -> bool {
         let combined = key.combined_key();
         
         // Check Redis first if available
@@ -194,6 +352,7 @@ impl DedupStore {
                 "Evicting dedup cache"
             );
             // In production, use LRU or time-based eviction
+            self.evictions.fetch_add(seen.len() as u64, Ordering::Relaxed);
             seen.clear();
         }
         
@@ -244,18 +403,72 @@ impl DedupStore {
         self.seen.read().is_empty()
     }
 
+    /// Gets cumulative hit/miss/eviction counts and the current size
+    pub fn stats(&self) -> DedupStats {
+        DedupStats {
+            entries: self.len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+        }
+    }
+
     /// Clears the in-memory cache
     pub fn clear(&self) {
         self.seen.write().clear();
     }
+
+    /// Forgets every key for `source` (all sources when `None`), in memory
+    /// and in Redis (`SCAN` + `DEL` of `dedup:{source}:*`), so items seen
+    /// before are ingested again. Returns the number of Redis keys deleted.
+    pub async fn clear_source(&self, source: Option<&str>) -> Result<u64, redis::RedisError> {
+        let prefix = source.map(|s| format!("{}:", s)).unwrap_or_default();
+        self.seen.write().retain(|key| !key.starts_with(&prefix));
+
+        let Some(ref redis) = self.redis else {
+            return Ok(0);
+        };
+        let mut conn = redis.clone();
+
+        let keys: Vec<String> = {
+            let mut iter: redis::AsyncIter<String> = redis::AsyncCommands::scan_match(
+                &mut conn,
+                format!("dedup:{}*", prefix),
+            ).await?;
+            let mut keys = Vec::new();
+            while let Some(key) = iter.next_item().await {
+                keys.push(key);
+            }
+            keys
+        };
+
+        let mut deleted = 0;
+        for chunk in keys.chunks(500) {
+            deleted += redis::cmd("DEL").arg(chunk).query_async::<u64>(&mut conn).await?;
+        }
+        Ok(deleted)
+    }
 }
 
 /// Convenience function to generate dedup key from news article
 pub fn news_dedup_key(source: &str, title: &str, url: Option<&str>, published_at: Option<&str>) -> DedupKey {
+    let policy = dedup_canonicalization().unwrap_or(DedupCanonicalization::Lowercase);
+    news_dedup_key_with(policy, source, title, url, published_at)
+}
+
+/// Generates a news dedup key canonicalizing the title with `policy`
+fn news_dedup_key_with(
+    policy: DedupCanonicalization,
+    source: &str,
+    title: &str,
+    url: Option<&str>,
+    published_at: Option<&str>,
+) -> DedupKey {
     // Combine title and publication date for content hash
+    let title = policy.apply(title);
     let content = match published_at {
-        Some(date) => format!("{}|{}", title.trim().to_lowercase(), date),
-        None => title.trim().to_lowercase(),
+        Some(date) => format!("{}|{}", title, date),
+        None => title,
     };
     
     DedupKey::from_content_and_url(source, &content, url)
@@ -263,8 +476,20 @@ pub fn news_dedup_key(source: &str, title: &str, url: Option<&str>, published_at
 
 /// Convenience function to generate dedup key from social post
 pub fn social_dedup_key(source: &str, author: &str, content: &str, post_id: Option<&str>) -> DedupKey {
+    let policy = dedup_canonicalization().unwrap_or(DedupCanonicalization::Conservative);
+    social_dedup_key_with(policy, source, author, content, post_id)
+}
+
+/// Generates a social dedup key canonicalizing the content with `policy`
+fn social_dedup_key_with(
+    policy: DedupCanonicalization,
+    source: &str,
+    author: &str,
+    content: &str,
+    post_id: Option<&str>,
+) -> DedupKey {
     // Use post_id as canonical URL if available
-    let combined = format!("{}|{}", author.to_lowercase(), content.trim());
+    let combined = format!("{}|{}", author.to_lowercase(), policy.apply(content));
     let canonical = post_id.map(|id| format!("{}:{}", source, id));
     
     DedupKey {
@@ -289,6 +514,34 @@ mod tests {
         assert_eq!(hash1.len(), 64); // SHA-256 = 64 hex chars
     }
 
+    #[test]
+    fn test_blake3_hash_stable_and_tagged() {
+        let hash1 = DedupHash::Blake3.hash("hello world");
+        let hash2 = DedupHash::Blake3.hash("hello world");
+        let hash3 = DedupHash::Blake3.hash("different content");
+
+        assert_eq!(hash1, hash2);
+        assert_ne!(hash1, hash3);
+        assert_eq!(
+            hash1,
+            "blake3:d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24"
+        );
+    }
+
+    #[test]
+    fn test_mixed_algorithm_keys_do_not_collide() {
+        let sha = DedupHash::Sha256.hash("hello world");
+        let blake = DedupHash::Blake3.hash("hello world");
+
+        assert_eq!(sha, sha256_hex("hello world"));
+        assert_ne!(sha, blake);
+        assert_ne!(sha, blake.trim_start_matches("blake3:"));
+
+        let sha_key = DedupKey { source: "x_api".to_string(), content_hash: sha, canonical_url: None };
+        let blake_key = DedupKey { source: "x_api".to_string(), content_hash: blake, canonical_url: None };
+        assert_ne!(sha_key.combined_key(), blake_key.combined_key());
+    }
+
     #[test]
     fn test_canonicalize_url() {
         // Remove tracking params
@@ -341,6 +594,61 @@ mod tests {
         assert!(store.check_and_mark(&key).await);
     }
 
+    #[tokio::test]
+    async fn test_clear_source_forgets_only_that_source() {
+        let store = DedupStore::new(1000);
+        let news = DedupKey::from_content("newsapi", "same article");
+        let panic = DedupKey::from_content("cryptopanic", "same article");
+        assert!(!store.check_and_mark(&news).await);
+        assert!(!store.check_and_mark(&panic).await);
+
+        assert_eq!(store.clear_source(Some("newsapi")).await.unwrap(), 0);
+        assert!(!store.is_duplicate(&news).await);
+        assert!(store.is_duplicate(&panic).await);
+
+        store.clear_source(None).await.unwrap();
+        assert!(store.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Redis"]
+    async fn test_clear_source_deletes_redis_keys() {
+        let url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://localhost:6379".to_string());
+        let client = redis::Client::open(url).unwrap();
+        let conn = redis::aio::ConnectionManager::new(client).await.unwrap();
+        let store = DedupStore::with_redis(1000, conn, 60);
+
+        let source = format!("reset-test-{}", uuid::Uuid::new_v4().simple());
+        let key = DedupKey::from_content(&source, "same article");
+        assert!(!store.check_and_mark(&key).await);
+        assert!(store.is_duplicate(&key).await);
+
+        assert_eq!(store.clear_source(Some(&source)).await.unwrap(), 1);
+        assert!(!store.is_duplicate(&key).await);
+    }
+
+    #[tokio::test]
+    async fn test_dedup_stats_accumulate() {
+        let store = DedupStore::new(2);
+        assert_eq!(store.stats(), DedupStats::default());
+
+        let first = DedupKey::from_content("test", "first");
+        let second = DedupKey::from_content("test", "second");
+        assert!(!store.check_and_mark(&first).await);
+        assert!(!store.check_and_mark(&second).await);
+        assert!(store.check_and_mark(&first).await);
+        assert!(store.is_duplicate(&second).await);
+
+        let stats = store.stats();
+        assert_eq!((stats.entries, stats.hits, stats.misses, stats.evictions), (2, 2, 2, 0));
+        assert_eq!(stats.hit_rate(), 0.5);
+
+        // A third key fills the cache, evicting both earlier keys
+        assert!(!store.check_and_mark(&DedupKey::from_content("test", "third")).await);
+        let stats = store.stats();
+        assert_eq!((stats.entries, stats.hits, stats.misses, stats.evictions), (1, 2, 3, 2));
+    }
+
     #[test]
     fn test_news_dedup_key() {
         let key1 = news_dedup_key("newsapi", "Breaking News", Some("https://example.com/news"), Some("2024-01-15"));
@@ -349,4 +657,53 @@ mod tests {
         // Should be considered same due to lowercase normalization and URL canonicalization
         assert_eq!(key1.content_hash, key2.content_hash);
     }
+
+    #[test]
+    fn test_canonicalization_policies() {
+        use DedupCanonicalization::*;
+
+        let a = social_dedup_key_with(Aggressive, "x_api", "alice", "GM  frens! 🚀", None);
+        let b = social_dedup_key_with(Aggressive, "x_api", "Alice", "gm frens", None);
+        assert_eq!(a.content_hash, b.content_hash);
+
+        let a = social_dedup_key_with(Conservative, "x_api", "alice", "GM", None);
+        let b = social_dedup_key_with(Conservative, "x_api", "alice", "gm", None);
+        assert_ne!(a.content_hash, b.content_hash);
+
+        let a = news_dedup_key_with(Aggressive, "newsapi", "Bitcoin hits $100K!", None, Some("2024-01-15"));
+        let b = news_dedup_key_with(Aggressive, "newsapi", "bitcoin hits 100k", None, Some("2024-01-15"));
+        assert_eq!(a.content_hash, b.content_hash);
+
+        let a = news_dedup_key_with(Conservative, "newsapi", "Breaking News", None, None);
+        let b = news_dedup_key_with(Conservative, "newsapi", "breaking news", None, None);
+        assert_ne!(a.content_hash, b.content_hash);
+
+        assert_eq!(Lowercase.apply("  Breaking News "), "breaking news");
+    }
+
+    #[test]
+    fn test_unset_canonicalization_keeps_original_keys() {
+        // Nothing in the tests sets the process-wide policy
+        assert_eq!(dedup_canonicalization(), None);
+
+        let social = social_dedup_key("x_api", "Alice", "  GM ", None);
+        assert_eq!(social.content_hash, compute_hash("alice|GM"));
+        let news = news_dedup_key("newsapi", " Breaking News", None, Some("2024-01-15"));
+        assert_eq!(news.content_hash, compute_hash("breaking news|2024-01-15"));
+    }
+
+    #[test]
+    fn test_payload_hash_is_canonical() {
+        let mut a = HashMap::new();
+        a.insert("title".to_string(), serde_json::json!("Bitcoin"));
+        a.insert("url".to_string(), serde_json::json!("https://example.com"));
+
+        let mut b = HashMap::new();
+        b.insert("url".to_string(), serde_json::json!("https://example.com"));
+        b.insert("title".to_string(), serde_json::json!("Bitcoin"));
+
+        assert_eq!(canonical_payload_json(&a), r#"{"title":"Bitcoin","url":"https://example.com"}"#);
+        assert_eq!(payload_hash(&a), payload_hash(&b));
+        assert!(payload_hash(&a).starts_with("sha256:"));
+    }
 }
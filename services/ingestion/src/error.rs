@@ -17,7 +17,7 @@ pub enum IngestionError {
     RedisError(#[from] redis::RedisError),
     
     #[error("WebSocket error: {0}")]
-    WebSocketError(#[from] tokio_tungstenite::tungstenite::Error),
+    WebSocketError(#[from] Box<tokio_tungstenite::tungstenite::Error>),
     
     #[error("Configuration error: {0}")]
     ConfigError(#[from] config::ConfigError),
@@ -46,6 +46,12 @@ pub enum IngestionError {
     #[error("Source not configured: {0}")]
     SourceNotConfigured(String),
     
+    #[error("Unknown source: {0}")]
+    UnknownSource(String),
+    
+    #[error("No data sources configured (set NEWS_API_KEY, CRYPTOPANIC_API_KEY or TWITTER_BEARER_TOKEN)")]
+    NoSourcesConfigured,
+    
     #[error("Duplicate content detected")]
     DuplicateContent,
     
@@ -62,4 +68,32 @@ pub enum IngestionError {
     ShutdownRequested,
 }
 
+impl IngestionError {
+    /// Short stable code for the error kind (the API code for `ApiError`)
+    pub fn code(&self) -> &str {
+        match self {
+            IngestionError::HttpError(_) => "http",
+            IngestionError::JsonError(_) => "json",
+            IngestionError::DatabaseError(_) => "database",
+            IngestionError::RedisError(_) => "redis",
+            IngestionError::WebSocketError(_) => "websocket",
+            IngestionError::ConfigError(_) => "config",
+            IngestionError::IoError(_) => "io",
+            IngestionError::RateLimitExceeded => "rate_limited",
+            IngestionError::CircuitBreakerOpen(_) => "circuit_open",
+            IngestionError::ApiError { code, .. } => code,
+            IngestionError::ValidationError(_) => "validation",
+            IngestionError::ConnectionLost(_) => "connection_lost",
+            IngestionError::SourceNotConfigured(_) => "source_not_configured",
+            IngestionError::UnknownSource(_) => "unknown_source",
+            IngestionError::NoSourcesConfigured => "no_sources",
+            IngestionError::DuplicateContent => "duplicate",
+            IngestionError::CheckpointError(_) => "checkpoint",
+            IngestionError::StorageError(_) => "storage",
+            IngestionError::ParseError(_) => "parse",
+            IngestionError::ShutdownRequested => "shutdown",
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, IngestionError>;
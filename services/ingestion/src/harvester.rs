@@ -8,28 +8,81 @@
 //! - Graceful shutdown support
 
 use anyhow::Result;
-use chrono::{Duration as ChronoDuration, Utc};
-use std::collections::HashMap;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use futures::future::join_all;
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::{self, BoxStream, FuturesUnordered, Stream, StreamExt};
+use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
+use std::collections::{HashMap, HashSet};
+use std::num::NonZeroU32;
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tokio::time::{interval, Duration};
-use tracing::{info, warn, error, debug, Span, instrument};
+use tokio::sync::{Mutex, RwLock};
+use tokio::time::{interval, Duration, Instant};
+use tracing::{info, info_span, warn, error, debug, instrument, Instrument};
 
-use crate::append_log::{AppendLogStorage, LogEntry, LogEntryType, create_append_log, FileSystemAppendLog};
+use crate::append_log::{AppendLogStorage, LogEntry, LogEntryType, create_append_log};
+use crate::audit::{self, AuditLogger};
 use crate::checkpoint::CheckpointManager;
-use crate::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+use crate::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
 use crate::config::Config;
-use crate::dedup::DedupStore;
+use crate::dedup::{DedupKey, DedupStats, DedupStore};
 use crate::error::{IngestionError, Result as IngestionResult};
 use crate::http_client::{ResilientHttpClient, HttpClientConfig};
+use crate::logging::ErrorLogThrottle;
+use crate::metrics;
+use crate::pipeline::stages::PayloadFilters;
 use crate::schemas::IngestionEvent;
-use crate::sources::{Source, SourceMetadata, FetchOptions, FetchResult};
+use crate::sources::{retain_language, PriorityConfig, Source, SourceHealth, SourceId, SourceSwitches, FetchOptions, FetchResult};
 use crate::sources::nadfun::NadFunSource;
 use crate::sources::monad::MonadSource;
-use crate::sources::newsapi::NewsApiSource;
+use crate::sources::newsapi::{NewsApiSource, DEFAULT_QUERY_CONCURRENCY};
 use crate::sources::cryptopanic::CryptoPanicSource;
 use crate::sources::x_api::{XApiSource, OfficialXApiAdapter};
-use crate::storage::Storage;
+use crate::storage::{CacheTtls, DbPoolConfig, Storage};
+
+/// Outcome of a single harvest cycle
+#[derive(Debug, Clone, Default)]
+pub struct HarvestReport {
+    /// Sources harvested without error
+    pub succeeded: Vec<SourceId>,
+    /// Sources that failed, with the error message
+    pub failed: Vec<(SourceId, String)>,
+    /// Events stored across all sources
+    pub total_events: usize,
+}
+
+impl HarvestReport {
+    /// Whether every attempted source failed
+    pub fn is_total_failure(&self) -> bool {
+        self.succeeded.is_empty() && !self.failed.is_empty()
+    }
+}
+
+/// Events fetched from every enabled source in one pass (pipeline mode)
+#[derive(Debug, Default)]
+pub struct FetchReport {
+    /// Fetched events, oldest first
+    pub events: Vec<IngestionEvent>,
+    /// Events kept per source that fetched successfully
+    pub counts: HashMap<SourceId, usize>,
+    /// Sources that failed, with the error message
+    pub failed: Vec<(SourceId, String)>,
+}
+
+impl FetchReport {
+    /// Records each source's harvest cycle, submitted events and fetch
+    /// failure, matching what the harvester daemon records per source
+    pub fn record_metrics(&self) {
+        for (source_id, count) in &self.counts {
+            metrics::record_harvest_cycle(source_id.as_str());
+            metrics::record_harvested_events(source_id.as_str(), metrics::HARVEST_STATUS_SUBMITTED, *count as u64);
+        }
+        for (source_id, _) in &self.failed {
+            metrics::record_harvest_cycle(source_id.as_str());
+            metrics::record_source_fetch_error(source_id.as_str());
+        }
+    }
+}
 
 /// Market data harvester with all protection mechanisms
 pub struct Harvester {
@@ -37,24 +90,57 @@ pub struct Harvester {
     correlation_id: String,
     
     // HTTP client with semaphore
+    #[allow(dead_code)]
     http_client: Arc<ResilientHttpClient>,
     
     // Circuit breakers per source
-    circuit_breakers: HashMap<String, Arc<CircuitBreaker>>,
+    circuit_breakers: HashMap<SourceId, Arc<CircuitBreaker>>,
+    
+    // Background circuit breaker probes
+    probe_handles: Vec<tokio::task::JoinHandle<()>>,
     
     // Data sources
-    sources: HashMap<String, Arc<dyn Source>>,
+    sources: HashMap<SourceId, Arc<dyn Source>>,
+    
+    // Runtime enable/disable flags (toggled via admin endpoint)
+    switches: SourceSwitches,
+    
+    // Latest health sweep results (read by /readyz)
+    health: SourceHealth,
     
     // Deduplication
     dedup: Arc<DedupStore>,
     
+    // Sources whose events bypass dedup
+    skip_dedup: Arc<HashSet<SourceId>>,
+
+    // Payload keys kept per source before events are logged
+    payload_filters: Arc<PayloadFilters>,
+    
     // Checkpoint manager
     checkpoint: Arc<RwLock<CheckpointManager>>,
     
     // Append-only log
     append_log: Arc<dyn AppendLogStorage>,
     
+    // Lifecycle audit events
+    audit: AuditLogger,
+    
+    // Deduplicated fetch failure logging for the news and social loops
+    error_log: Arc<ErrorLogThrottle>,
+    
+    // Pending append-log entries for the news and social loops
+    news_buffer: Arc<Mutex<AppendBuffer>>,
+    social_buffer: Arc<Mutex<AppendBuffer>>,
+    
+    // Quota budget shared by the news and social loops
+    budget: Option<Arc<FetchBudget>>,
+
+    // Most pages `harvest_source` follows in one cycle
+    max_pages: usize,
+    
     // Legacy storage (DB + Redis)
+    #[allow(dead_code)]
     storage: Option<Storage>,
     
     // Shutdown flag
@@ -70,6 +156,10 @@ impl Harvester {
         // Create HTTP client with semaphore limiting
         let http_config = HttpClientConfig {
             max_concurrent_requests: config.max_concurrent_requests,
+            source_concurrency: SourceId::ALL
+                .into_iter()
+                .filter_map(|id| config.concurrency_limit(id).map(|limit| (id.as_str().to_string(), limit)))
+                .collect(),
             ..Default::default()
         };
         let http_client = Arc::new(ResilientHttpClient::new(http_config)?);
@@ -81,20 +171,43 @@ impl Harvester {
             ..Default::default()
         };
 
-        // Create circuit breakers
+        // Initialize append-only log
+        let append_log: Arc<dyn AppendLogStorage> = Arc::from(create_append_log(
+            &config.storage_type,
+            Some(&config.data_dir),
+            config.s3_bucket.as_deref(),
+            config.s3_prefix.as_deref(),
+            config.s3_endpoint_url.as_deref(),
+            config.append_log_granularity,
+        ).await?);
+        info!(storage_type = %config.storage_type, "Append log initialized");
+
+        // Initialize checkpoint manager
+        let checkpoint_manager = CheckpointManager::new(&config.checkpoint_dir).await?;
+        info!(dir = %config.checkpoint_dir.display(), "Checkpoint manager initialized");
+
+        // Audit lifecycle events to the append log
+        let audit_logger = AuditLogger::new(append_log.clone(), correlation_id.clone())
+            .with_session_id(checkpoint_manager.session_id());
+
+        // Create circuit breakers (trips are audited, state changes logged per source)
         let mut circuit_breakers = HashMap::new();
-        for source_id in ["nadfun", "monad", "newsapi", "cryptopanic", "x_api"] {
-            circuit_breakers.insert(
-                source_id.to_string(),
-                Arc::new(CircuitBreaker::new(source_id, cb_config.clone())),
-            );
+        for source_id in SourceId::ALL {
+            let trip_audit = audit_logger.clone();
+            let change_audit = audit_logger.clone();
+            let breaker = CircuitBreaker::new(source_id.as_str(), cb_config.clone())
+                .with_on_trip(Arc::new(move |name| {
+                    trip_audit.record_in_background(audit::circuit_tripped(name))
+                }))
+                .with_on_state_change(Arc::new(move |change| change_audit.record_circuit_change(change)));
+            circuit_breakers.insert(source_id, Arc::new(breaker));
         }
 
         // Create sources
-        let mut sources: HashMap<String, Arc<dyn Source>> = HashMap::new();
+        let mut sources: HashMap<SourceId, Arc<dyn Source>> = HashMap::new();
 
         // nad.fun source (always available)
-        let nadfun = NadFunSource::new(
+        let _nadfun = NadFunSource::new(
             &config.nadfun_api_url,
             config.nadfun_api_key.as_deref(),
             config.nadfun_rate_limit_rpm,
@@ -102,10 +215,10 @@ impl Harvester {
         // Note: NadFunSource doesn't implement Source trait yet, we'll use it directly
 
         // Monad RPC source (always available)
-        let monad = MonadSource::new(
-            &config.monad_rpc_url,
+        let _monad = MonadSource::with_endpoints(
+            &config.monad_rpc_urls(),
             config.rpc_rate_limit_rpm,
-        );
+        )?;
         // Note: MonadSource doesn't implement Source trait yet, we'll use it directly
 
         // NewsAPI source (if configured)
@@ -114,9 +227,14 @@ impl Harvester {
                 http_client.clone(),
                 api_key.clone(),
                 config.newsapi_rate_limit_rpm,
-                circuit_breakers.get("newsapi").unwrap().clone(),
-            );
-            sources.insert("newsapi".to_string(), Arc::new(newsapi));
+                circuit_breakers[&SourceId::NewsApi].clone(),
+            )
+            .with_user_agent(config.newsapi_user_agent.clone())
+            .with_queries(config.newsapi_queries())
+            .with_query_concurrency(config.newsapi_query_concurrency.unwrap_or(DEFAULT_QUERY_CONCURRENCY))
+            .with_deterministic_ids(config.deterministic_event_ids)
+            .with_clock_skew_tolerance(config.clock_skew_tolerance());
+            sources.insert(SourceId::NewsApi, Arc::new(newsapi));
             info!("NewsAPI source initialized");
         }
 
@@ -126,9 +244,13 @@ impl Harvester {
                 http_client.clone(),
                 api_key.clone(),
                 config.cryptopanic_rate_limit_rpm,
-                circuit_breakers.get("cryptopanic").unwrap().clone(),
-            );
-            sources.insert("cryptopanic".to_string(), Arc::new(cryptopanic));
+                circuit_breakers[&SourceId::CryptoPanic].clone(),
+            )
+            .with_user_agent(config.cryptopanic_user_agent.clone())
+            .with_deterministic_ids(config.deterministic_event_ids)
+            .with_clock_skew_tolerance(config.clock_skew_tolerance())
+            .with_priorities(PriorityConfig::from_config(&config));
+            sources.insert(SourceId::CryptoPanic, Arc::new(cryptopanic));
             info!("CryptoPanic source initialized");
         }
 
@@ -138,36 +260,82 @@ impl Harvester {
                 http_client.clone(),
                 bearer_token.clone(),
                 config.x_api_rate_limit_rpm,
-                circuit_breakers.get("x_api").unwrap().clone(),
-            ));
-            let x_api = XApiSource::new(adapter, config.x_api_rate_limit_rpm);
-            sources.insert("x_api".to_string(), Arc::new(x_api));
+                circuit_breakers[&SourceId::XApi].clone(),
+            ).with_user_agent(config.x_api_user_agent.clone()));
+            let x_api = XApiSource::new(adapter, config.x_api_rate_limit_rpm)
+                .with_deterministic_ids(config.deterministic_event_ids)
+                .with_priorities(PriorityConfig::from_config(&config));
+            sources.insert(SourceId::XApi, Arc::new(x_api));
             info!("X API source initialized");
         }
 
+        // Probe open circuits with source health checks
+        let mut probe_handles = Vec::new();
+        if config.circuit_breaker_probe_enabled {
+            let timeout = Duration::from_millis(config.health_check_timeout_ms);
+            for (source_id, source) in &sources {
+                let source = source.clone();
+                let handle = circuit_breakers[source_id].spawn_probe(move || {
+                    let source = source.clone();
+                    async move {
+                        tokio::time::timeout(timeout, source.health_check())
+                            .await
+                            .is_ok_and(|result| result.unwrap_or(false))
+                    }
+                });
+                probe_handles.push(handle);
+            }
+            info!(count = probe_handles.len(), "Circuit breaker probes started");
+        }
+
         // Initialize deduplication store
         let dedup = Arc::new(DedupStore::new(config.dedup_cache_size));
         info!(cache_size = config.dedup_cache_size, "Dedup store initialized");
+        let skip_dedup = if config.no_dedup {
+            warn!("Dedup disabled (--no-dedup), repeated events will all be emitted");
+            Arc::new(SourceId::ALL.into_iter().collect())
+        } else {
+            let sources = config.skip_dedup_sources()?;
+            if !sources.is_empty() {
+                info!(sources = ?sources, "Dedup bypassed for sources");
+            }
+            Arc::new(sources)
+        };
+        let payload_filters = Arc::new(config.payload_filters()?);
 
-        // Initialize checkpoint manager
-        let checkpoint = Arc::new(RwLock::new(
-            CheckpointManager::new(&config.checkpoint_dir).await?
+        let checkpoint = Arc::new(RwLock::new(checkpoint_manager));
+
+        let flush_interval = Duration::from_millis(config.append_flush_interval_ms);
+        let news_buffer = Arc::new(Mutex::new(
+            AppendBuffer::new(config.append_batch_size, flush_interval)
+        ));
+        let social_buffer = Arc::new(Mutex::new(
+            AppendBuffer::new(config.append_batch_size, flush_interval)
         ));
-        info!(dir = %config.checkpoint_dir.display(), "Checkpoint manager initialized");
 
-        // Initialize append-only log
-        let append_log: Arc<dyn AppendLogStorage> = Arc::from(create_append_log(
-            &config.storage_type,
-            Some(&config.data_dir),
-            config.s3_bucket.as_deref(),
-            config.s3_prefix.as_deref(),
-            config.s3_endpoint_url.as_deref(),
-        ).await?);
-        info!(storage_type = %config.storage_type, "Append log initialized");
+        let budget = config
+            .fetch_budget_per_minute
+            .map(|units| Arc::new(FetchBudget::per_minute(units)));
+        let max_pages = config.harvest_max_pages.unwrap_or(DEFAULT_HARVEST_PAGES).max(1);
+
+        let error_log = Arc::new(ErrorLogThrottle::new(
+            Duration::from_secs(config.error_log_summary_secs)
+        ));
 
         // Initialize legacy storage if database URL is provided
         let storage = if let Some(ref db_url) = config.database_url {
-            Some(Storage::new(db_url, config.redis_url.as_deref()).await?)
+            let storage = Storage::new(
+                db_url,
+                config.redis_url.as_deref(),
+                config.redis_ca_cert.as_deref(),
+                DbPoolConfig::from_config(&config),
+            )
+            .await?
+            .with_cache_ttls(CacheTtls::from_config(&config));
+            if config.run_migrations {
+                storage.run_migrations().await?;
+            }
+            Some(storage)
         } else {
             warn!("No database URL configured - running without DB storage");
             None
@@ -178,10 +346,21 @@ impl Harvester {
             correlation_id,
             http_client,
             circuit_breakers,
+            probe_handles,
             sources,
+            switches: SourceSwitches::default(),
+            health: SourceHealth::default(),
             dedup,
+            skip_dedup,
+            payload_filters,
             checkpoint,
             append_log,
+            audit: audit_logger,
+            error_log,
+            news_buffer,
+            social_buffer,
+            budget,
+            max_pages,
             storage,
             running: Arc::new(RwLock::new(true)),
         })
@@ -190,24 +369,30 @@ impl Harvester {
     /// Runs the harvester continuously
     #[instrument(skip(self))]
     pub async fn run_continuous(&self) -> Result<()> {
+        self.ensure_sources()?;
         info!("Starting continuous harvesting...");
 
         // Spawn all harvester tasks
         let mut handles = Vec::new();
 
+        self.watch_polled_sources();
+
         // News harvester
-        if self.sources.contains_key("newsapi") || self.sources.contains_key("cryptopanic") {
+        if self.sources.contains_key(&SourceId::NewsApi) || self.sources.contains_key(&SourceId::CryptoPanic) {
             handles.push(self.spawn_news_harvester());
         }
 
         // Social harvester
-        if self.sources.contains_key("x_api") {
+        if self.sources.contains_key(&SourceId::XApi) {
             handles.push(self.spawn_social_harvester());
         }
 
         // Checkpoint auto-save
         handles.push(self.spawn_checkpoint_saver());
 
+        // Periodic source health sweep
+        handles.push(self.spawn_health_sweep());
+
         // Wait for any task to complete (or error)
         for handle in handles {
             if let Err(e) = handle.await {
@@ -219,22 +404,30 @@ impl Harvester {
     }
 
     /// Runs a single harvest cycle
+    ///
+    /// Per-source failures don't fail the cycle; check
+    /// `HarvestReport::is_total_failure` to detect that nothing worked.
     #[instrument(skip(self))]
-    pub async fn run_once(&self) -> Result<()> {
+    pub async fn run_once(&self) -> Result<HarvestReport> {
         info!("Running single harvest cycle...");
 
         let options = FetchOptions::new()
             .since(Utc::now() - ChronoDuration::hours(1))
-            .limit(100);
+            .limit(100)
+            .default_language(self.language());
 
         // Fetch from all configured sources
+        let mut report = HarvestReport::default();
         for (source_id, source) in &self.sources {
-            match self.harvest_source(source_id, source.as_ref(), options.clone()).await {
+            match self.harvest_source(*source_id, source.as_ref(), options.clone()).await {
                 Ok(count) => {
                     info!(source = %source_id, events = count, "Harvest completed");
+                    report.succeeded.push(*source_id);
+                    report.total_events += count;
                 }
                 Err(e) => {
                     warn!(source = %source_id, error = %e, "Harvest failed");
+                    report.failed.push((*source_id, e.to_string()));
                 }
             }
         }
@@ -242,108 +435,220 @@ impl Harvester {
         // Save checkpoint
         self.checkpoint.write().await.save().await?;
 
-        Ok(())
+        if report.is_total_failure() {
+            error!(failed = report.failed.len(), "All sources failed");
+        }
+
+        Ok(report)
     }
 
     /// Fetches data from a specific source (for CLI)
+    ///
+    /// Events come back oldest first (see `sort_chronologically`). With
+    /// `all`, `options.limit` caps the combined result to the newest events.
     pub async fn fetch_from_source(
         &self,
         source_id: &str,
         options: FetchOptions,
     ) -> IngestionResult<Vec<IngestionEvent>> {
+        let options = options.default_language(self.language());
         if source_id == "all" {
-            let mut all_events = Vec::new();
-            for (id, source) in &self.sources {
-                match source.fetch(options.clone()).await {
-                    Ok(result) => {
-                        all_events.extend(result.events);
-                    }
-                    Err(e) => {
-                        warn!(source = %id, error = %e, "Failed to fetch");
-                    }
-                }
-            }
-            return Ok(all_events);
+            return Ok(self.fetch_all(options).await.events);
         }
 
-        if let Some(source) = self.sources.get(source_id) {
-            let result = source.fetch(options).await?;
-            Ok(result.events)
+        let source_id: SourceId = source_id.parse()?;
+        if let Some(source) = self.sources.get(&source_id) {
+            let result = timed_fetch(source_id, source.as_ref(), options).await?;
+            let mut events = result.events;
+            self.stamp_lineage(&mut events).await;
+            sort_chronologically(&mut events);
+            Ok(events)
         } else {
             Err(IngestionError::SourceNotConfigured(source_id.to_string()))
         }
     }
 
+    /// Resolves the CLI `--since` value for `source_id` (or `all`) against
+    /// the saved checkpoints (see `CheckpointManager::resolve_since`)
+    pub async fn resolve_since(&self, source_id: &str, since: &str) -> anyhow::Result<DateTime<Utc>> {
+        let source_ids: Vec<&str> = if source_id == "all" {
+            self.enabled_sources().keys().map(|id| id.as_str()).collect()
+        } else {
+            vec![source_id.parse::<SourceId>()?.as_str()]
+        };
+        self.checkpoint
+            .read()
+            .await
+            .resolve_since(since, &source_ids, ChronoDuration::hours(1))
+    }
+
+    /// Gets the language every fetch is restricted to (`LANGUAGE_FILTER`)
+    fn language(&self) -> Option<&str> {
+        self.config.language_filter.as_deref()
+    }
+
+    /// Starts tracking event age for the polled sources, which are expected
+    /// to keep producing events (`/readyz`)
+    pub fn watch_polled_sources(&self) {
+        for source_id in [SourceId::NewsApi, SourceId::CryptoPanic, SourceId::XApi] {
+            if self.sources.contains_key(&source_id) {
+                self.health.watch_events(source_id);
+            }
+        }
+    }
+
+    /// Fetches from every enabled source, reporting each one's outcome
+    ///
+    /// Events come back oldest first, and `options.limit` caps the combined
+    /// result to the newest events. Sources that returned events count as
+    /// fresh for `/readyz` even if the cap drops them.
+    pub async fn fetch_all(&self, options: FetchOptions) -> FetchReport {
+        let options = options.default_language(self.language());
+        let mut report = fetch_all_sources(&self.enabled_sources(), &options, &self.health).await;
+        self.stamp_lineage(&mut report.events).await;
+        report
+    }
+
+    /// Stamps this run's session and correlation ids on `events` (ids
+    /// already set are kept)
+    async fn stamp_lineage(&self, events: &mut [IngestionEvent]) {
+        let session_id = self.checkpoint.read().await.session_id().to_string();
+        for event in events {
+            event.stamp_lineage(&session_id, &self.correlation_id);
+        }
+    }
+
+    /// Streams events from a specific source (for CLI `--output ndjson`)
+    ///
+    /// With `all` and no `options.limit`, each source's events are yielded
+    /// as soon as its fetch completes instead of after every source has
+    /// responded. A limit keeps the newest events, which needs every source's
+    /// events first, so those are yielded like `fetch_from_source`'s.
+    pub async fn stream_from_source(
+        &self,
+        source_id: &str,
+        options: FetchOptions,
+    ) -> IngestionResult<BoxStream<'static, IngestionEvent>> {
+        let options = options.default_language(self.language());
+        if source_id == "all" && options.limit.is_none() {
+            let session_id = self.checkpoint.read().await.session_id().to_string();
+            let correlation_id = self.correlation_id.clone();
+            return Ok(fetch_all_stream(&self.enabled_sources(), &options)
+                .map(move |mut event| {
+                    event.stamp_lineage(&session_id, &correlation_id);
+                    event
+                })
+                .boxed());
+        }
+        let events = self.fetch_from_source(source_id, options).await?;
+        Ok(stream::iter(events).boxed())
+    }
+
+    /// Sources not paused via the admin endpoint
+    fn enabled_sources(&self) -> HashMap<SourceId, Arc<dyn Source>> {
+        self.sources
+            .iter()
+            .filter(|(id, _)| self.switches.is_enabled(**id))
+            .map(|(id, source)| (*id, source.clone()))
+            .collect()
+    }
+
     /// Harvests from a single source with all protections
     async fn harvest_source(
         &self,
-        source_id: &str,
+        source_id: SourceId,
         source: &dyn Source,
         options: FetchOptions,
     ) -> IngestionResult<usize> {
+        if !self.switches.is_enabled(source_id) {
+            info!(source = %source_id, "Source paused, skipping");
+            return Ok(0);
+        }
+
         // Check circuit breaker
-        if let Some(cb) = self.circuit_breakers.get(source_id) {
+        if let Some(cb) = self.circuit_breakers.get(&source_id) {
             if !cb.allow_request() {
                 warn!(source = %source_id, "Circuit breaker open, skipping");
                 return Ok(0);
             }
         }
 
-        // Get checkpoint for since time
-        let since = {
+        // Get checkpoint for since time (and the page to resume from if the
+        // last fetch was interrupted)
+        let (since, resume_cursor) = {
             let checkpoint = self.checkpoint.read().await;
-            checkpoint.get_since(source_id, ChronoDuration::hours(1))
+            (
+                checkpoint.get_since(source_id.as_str(), ChronoDuration::hours(1)),
+                checkpoint.resume_cursor(source_id.as_str()),
+            )
         };
 
         let fetch_options = FetchOptions {
             since: Some(since),
+            cursor: resume_cursor.or(options.cursor),
             ..options
         };
 
         // Fetch data
-        let result = source.fetch(fetch_options).await?;
+        metrics::record_harvest_cycle(source_id.as_str());
+        let session_id = self.checkpoint.read().await.session_id().to_string();
+        let mut result = match fetch_pages(source_id, source, fetch_options, self.max_pages).await {
+            Ok(result) => result,
+            Err(mut partial) => {
+                // Keep the pages that did arrive and resume from the failed one
+                filter_payloads(&self.payload_filters, &mut partial.events);
+                let event_count = partial.events.len();
+                let stored_count = append_fetch_result(
+                    self.append_log.as_ref(),
+                    dedup_for(&self.dedup, &self.skip_dedup, source_id),
+                    source_id.as_str(),
+                    &self.correlation_id,
+                    &session_id,
+                    &FetchResult::with_events(partial.events),
+                    self.config.raw_log_limit(),
+                ).await;
+                warn!(
+                    source = %source_id,
+                    fetched = event_count,
+                    stored = stored_count,
+                    resume_cursor = ?partial.next_cursor,
+                    error = %partial.error,
+                    "Paginated fetch interrupted"
+                );
+                self.checkpoint.write().await.record_partial(
+                    source_id.as_str(),
+                    event_count as u32,
+                    partial.next_cursor,
+                    &partial.error.to_string(),
+                );
+                return Err(partial.error);
+            }
+        };
+        filter_payloads(&self.payload_filters, &mut result.events);
         let event_count = result.events.len();
+        if event_count > 0 {
+            self.health.record_events(source_id);
+        }
 
         // Process events
-        let mut stored_count = 0;
-        for event in &result.events {
-            // Check for duplicates
-            if let Some(ref dedup_key) = event.deduplication_key {
-                let key = crate::dedup::DedupKey::from_content(source_id, dedup_key);
-                if self.dedup.check_and_mark(&key).await {
-                    debug!(event_id = %event.id, "Duplicate event, skipping");
-                    continue;
-                }
-            }
-
-            // Store in append log
-            let log_entry = LogEntry {
-                id: event.id.clone(),
-                timestamp: Utc::now(),
-                source_id: source_id.to_string(),
-                correlation_id: self.correlation_id.clone(),
-                session_id: self.checkpoint.read().await.session_id().to_string(),
-                entry_type: LogEntryType::NormalizedEvent,
-                payload: serde_json::to_value(event).unwrap_or_default(),
-                payload_size: event.payload_size,
-                content_hash: event.payload_hash.clone().unwrap_or_default(),
-            };
-
-            if let Err(e) = self.append_log.append(&log_entry).await {
-                warn!(error = %e, "Failed to append to log");
-            }
-
-            stored_count += 1;
-        }
+        let stored_count = append_fetch_result(
+            self.append_log.as_ref(),
+            dedup_for(&self.dedup, &self.skip_dedup, source_id),
+            source_id.as_str(),
+            &self.correlation_id,
+            &session_id,
+            &result,
+            self.config.raw_log_limit(),
+        ).await;
 
         // Update checkpoint
         {
             let mut checkpoint = self.checkpoint.write().await;
-            checkpoint.record_success(source_id, event_count as u32, result.next_cursor);
+            checkpoint.record_success(source_id.as_str(), event_count as u32, result.next_cursor);
         }
 
         // Record success in circuit breaker
-        if let Some(cb) = self.circuit_breakers.get(source_id) {
+        if let Some(cb) = self.circuit_breakers.get(&source_id) {
             cb.record_success();
         }
 
@@ -361,11 +666,20 @@ impl Harvester {
     fn spawn_news_harvester(&self) -> tokio::task::JoinHandle<()> {
         let sources = self.sources.clone();
         let dedup = self.dedup.clone();
+        let skip_dedup = self.skip_dedup.clone();
+        let payload_filters = self.payload_filters.clone();
         let checkpoint = self.checkpoint.clone();
         let append_log = self.append_log.clone();
+        let buffer = self.news_buffer.clone();
         let correlation_id = self.correlation_id.clone();
         let circuit_breakers = self.circuit_breakers.clone();
+        let switches = self.switches.clone();
+        let health = self.health.clone();
+        let error_log = self.error_log.clone();
         let interval_ms = self.config.news_interval_ms;
+        let language = self.config.language_filter.clone();
+        let budget = self.budget.clone();
+        let raw_log_limit = self.config.raw_log_limit();
         let running = self.running.clone();
 
         tokio::spawn(async move {
@@ -375,85 +689,94 @@ impl Harvester {
                 ticker.tick().await;
 
                 if !*running.read().await {
+                    buffer.lock().await.flush(append_log.as_ref()).await;
                     info!("News harvester stopped");
                     break;
                 }
 
-                for source_id in ["newsapi", "cryptopanic"] {
-                    if let Some(source) = sources.get(source_id) {
+                buffer.lock().await.flush_if_due(append_log.as_ref()).await;
+
+                for source_id in [SourceId::NewsApi, SourceId::CryptoPanic] {
+                    if let Some(source) = sources.get(&source_id) {
+                        if !switches.is_enabled(source_id) {
+                            debug!(source = %source_id, "Source paused");
+                            continue;
+                        }
+
                         // Check circuit breaker
-                        if let Some(cb) = circuit_breakers.get(source_id) {
+                        if let Some(cb) = circuit_breakers.get(&source_id) {
                             if !cb.allow_request() {
                                 debug!(source = %source_id, "Circuit breaker open");
                                 continue;
                             }
                         }
 
+                        if let Some(budget) = &budget {
+                            if !budget.try_spend(source_id, source.estimated_cost()) {
+                                continue;
+                            }
+                        }
+
                         let since = {
                             let cp = checkpoint.read().await;
-                            cp.get_since(source_id, ChronoDuration::hours(1))
+                            cp.get_since(source_id.as_str(), ChronoDuration::hours(1))
                         };
 
                         let options = FetchOptions::new()
                             .since(since)
-                            .limit(100);
+                            .limit(100)
+                            .default_language(language.as_deref());
 
-                        match source.fetch(options).await {
-                            Ok(result) => {
+                        metrics::record_harvest_cycle(source_id.as_str());
+                        match timed_fetch(source_id, source.as_ref(), options).await {
+                            Ok(mut result) => {
+                                filter_payloads(&payload_filters, &mut result.events);
                                 debug!(
                                     source = %source_id,
                                     events = result.events.len(),
                                     "Fetched news"
                                 );
+                                if !result.events.is_empty() {
+                                    health.record_events(source_id);
+                                }
 
                                 // Process events with dedup
-                                for event in &result.events {
-                                    if let Some(ref key) = event.deduplication_key {
-                                        let dedup_key = crate::dedup::DedupKey::from_content(source_id, key);
-                                        if dedup.check_and_mark(&dedup_key).await {
-                                            continue;
-                                        }
-                                    }
-
-                                    // Log to append log
-                                    let log_entry = LogEntry {
-                                        id: event.id.clone(),
-                                        timestamp: Utc::now(),
-                                        source_id: source_id.to_string(),
-                                        correlation_id: correlation_id.clone(),
-                                        session_id: checkpoint.read().await.session_id().to_string(),
-                                        entry_type: LogEntryType::NormalizedEvent,
-                                        payload: serde_json::to_value(event).unwrap_or_default(),
-                                        payload_size: event.payload_size,
-                                        content_hash: event.payload_hash.clone().unwrap_or_default(),
-                                    };
-
-                                    if let Err(e) = append_log.append(&log_entry).await {
-                                        warn!(error = %e, "Failed to append to log");
-                                    }
-                                }
+                                let session_id = checkpoint.read().await.session_id().to_string();
+                                let entries = fetch_result_entries(
+                                    dedup_for(&dedup, &skip_dedup, source_id),
+                                    source_id.as_str(),
+                                    &correlation_id,
+                                    &session_id,
+                                    &result,
+                                    raw_log_limit,
+                                ).await;
+                                let mut pending = buffer.lock().await;
+                                pending.push(entries);
+                                pending.flush_if_due(append_log.as_ref()).await;
 
                                 // Update checkpoint
                                 checkpoint.write().await.record_success(
-                                    source_id,
+                                    source_id.as_str(),
                                     result.events.len() as u32,
                                     result.next_cursor,
                                 );
 
-                                if let Some(cb) = circuit_breakers.get(source_id) {
+                                if let Some(cb) = circuit_breakers.get(&source_id) {
                                     cb.record_success();
                                 }
+                                error_log.recovered(source_id.as_str());
                             }
                             Err(e) => {
-                                warn!(source = %source_id, error = %e, "News fetch failed");
-                                checkpoint.write().await.record_error(source_id, &e.to_string());
-                                if let Some(cb) = circuit_breakers.get(source_id) {
+                                error_log.warn(source_id.as_str(), e.code(), &e, "News fetch failed");
+                                checkpoint.write().await.record_error(source_id.as_str(), &e.to_string());
+                                if let Some(cb) = circuit_breakers.get(&source_id) {
                                     cb.record_failure();
                                 }
                             }
                         }
                     }
                 }
+
             }
         })
     }
@@ -462,11 +785,20 @@ impl Harvester {
     fn spawn_social_harvester(&self) -> tokio::task::JoinHandle<()> {
         let sources = self.sources.clone();
         let dedup = self.dedup.clone();
+        let skip_dedup = self.skip_dedup.clone();
+        let payload_filters = self.payload_filters.clone();
         let checkpoint = self.checkpoint.clone();
         let append_log = self.append_log.clone();
+        let buffer = self.social_buffer.clone();
         let correlation_id = self.correlation_id.clone();
         let circuit_breakers = self.circuit_breakers.clone();
+        let switches = self.switches.clone();
+        let health = self.health.clone();
+        let error_log = self.error_log.clone();
         let interval_ms = self.config.social_interval_ms;
+        let language = self.config.language_filter.clone();
+        let budget = self.budget.clone();
+        let raw_log_limit = self.config.raw_log_limit();
         let running = self.running.clone();
 
         tokio::spawn(async move {
@@ -476,82 +808,92 @@ impl Harvester {
                 ticker.tick().await;
 
                 if !*running.read().await {
+                    buffer.lock().await.flush(append_log.as_ref()).await;
                     info!("Social harvester stopped");
                     break;
                 }
 
-                if let Some(source) = sources.get("x_api") {
-                    let source_id = "x_api";
+                buffer.lock().await.flush_if_due(append_log.as_ref()).await;
+
+                let source_id = SourceId::XApi;
+                if !switches.is_enabled(source_id) {
+                    debug!(source = %source_id, "Source paused");
+                    continue;
+                }
+
+                if let Some(source) = sources.get(&source_id) {
 
                     // Check circuit breaker
-                    if let Some(cb) = circuit_breakers.get(source_id) {
+                    if let Some(cb) = circuit_breakers.get(&source_id) {
                         if !cb.allow_request() {
                             debug!(source = %source_id, "Circuit breaker open");
                             continue;
                         }
                     }
 
+                    if let Some(budget) = &budget {
+                        if !budget.try_spend(source_id, source.estimated_cost()) {
+                            continue;
+                        }
+                    }
+
                     let since = {
                         let cp = checkpoint.read().await;
-                        cp.get_since(source_id, ChronoDuration::hours(1))
+                        cp.get_since(source_id.as_str(), ChronoDuration::hours(1))
                     };
 
                     let options = FetchOptions::new()
                         .since(since)
-                        .limit(100);
+                        .limit(100)
+                        .default_language(language.as_deref());
 
-                    match source.fetch(options).await {
-                        Ok(result) => {
+                    metrics::record_harvest_cycle(source_id.as_str());
+                    match timed_fetch(source_id, source.as_ref(), options).await {
+                        Ok(mut result) => {
+                            filter_payloads(&payload_filters, &mut result.events);
                             debug!(
                                 source = %source_id,
                                 events = result.events.len(),
                                 "Fetched social posts"
                             );
-
-                            for event in &result.events {
-                                if let Some(ref key) = event.deduplication_key {
-                                    let dedup_key = crate::dedup::DedupKey::from_content(source_id, key);
-                                    if dedup.check_and_mark(&dedup_key).await {
-                                        continue;
-                                    }
-                                }
-
-                                let log_entry = LogEntry {
-                                    id: event.id.clone(),
-                                    timestamp: Utc::now(),
-                                    source_id: source_id.to_string(),
-                                    correlation_id: correlation_id.clone(),
-                                    session_id: checkpoint.read().await.session_id().to_string(),
-                                    entry_type: LogEntryType::NormalizedEvent,
-                                    payload: serde_json::to_value(event).unwrap_or_default(),
-                                    payload_size: event.payload_size,
-                                    content_hash: event.payload_hash.clone().unwrap_or_default(),
-                                };
-
-                                if let Err(e) = append_log.append(&log_entry).await {
-                                    warn!(error = %e, "Failed to append to log");
-                                }
+                            if !result.events.is_empty() {
+                                health.record_events(source_id);
                             }
 
+                            let session_id = checkpoint.read().await.session_id().to_string();
+                            let entries = fetch_result_entries(
+                                dedup_for(&dedup, &skip_dedup, source_id),
+                                source_id.as_str(),
+                                &correlation_id,
+                                &session_id,
+                                &result,
+                                raw_log_limit,
+                            ).await;
+                            let mut pending = buffer.lock().await;
+                            pending.push(entries);
+                            pending.flush_if_due(append_log.as_ref()).await;
+
                             checkpoint.write().await.record_success(
-                                source_id,
+                                source_id.as_str(),
                                 result.events.len() as u32,
                                 result.next_cursor,
                             );
 
-                            if let Some(cb) = circuit_breakers.get(source_id) {
+                            if let Some(cb) = circuit_breakers.get(&source_id) {
                                 cb.record_success();
                             }
+                            error_log.recovered(source_id.as_str());
                         }
                         Err(e) => {
-                            warn!(source = %source_id, error = %e, "Social fetch failed");
-                            checkpoint.write().await.record_error(source_id, &e.to_string());
-                            if let Some(cb) = circuit_breakers.get(source_id) {
+                            error_log.warn(source_id.as_str(), e.code(), &e, "Social fetch failed");
+                            checkpoint.write().await.record_error(source_id.as_str(), &e.to_string());
+                            if let Some(cb) = circuit_breakers.get(&source_id) {
                                 cb.record_failure();
                             }
                         }
                     }
                 }
+
             }
         })
     }
@@ -580,6 +922,33 @@ impl Harvester {
         })
     }
 
+    /// Spawns the periodic source health sweep behind `/readyz` (started by
+    /// `run_continuous`; pipeline mode starts it itself)
+    pub fn spawn_health_sweep(&self) -> tokio::task::JoinHandle<()> {
+        let sources = self.sources.clone();
+        let circuit_breakers = self.circuit_breakers.clone();
+        let health = self.health.clone();
+        let interval_secs = self.config.health_check_interval_secs.max(1);
+        let timeout = Duration::from_millis(self.config.health_check_timeout_ms);
+        let running = self.running.clone();
+
+        tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_secs(interval_secs));
+
+            loop {
+                ticker.tick().await;
+
+                if !*running.read().await {
+                    info!("Health sweep stopped");
+                    break;
+                }
+
+                let results = check_sources_health(&sources, &circuit_breakers, &health, timeout).await;
+                debug!(?results, "Source health sweep completed");
+            }
+        })
+    }
+
     /// Graceful shutdown
     /// Turkish: "Sistem kapanırken yarıda kalan işlemleri güvenli şekilde tamamla"
     pub async fn shutdown(&self) {
@@ -591,9 +960,19 @@ impl Harvester {
             *running = false;
         }
 
+        // Stop circuit breaker probes
+        for handle in &self.probe_handles {
+            handle.abort();
+        }
+
         // Wait a bit for tasks to finish current work
         tokio::time::sleep(Duration::from_millis(500)).await;
 
+        // Flush buffered append-log entries
+        for buffer in [&self.news_buffer, &self.social_buffer] {
+            buffer.lock().await.flush(self.append_log.as_ref()).await;
+        }
+
         // Save final checkpoint
         info!("Saving final checkpoint...");
         if let Err(e) = self.checkpoint.write().await.save_on_shutdown().await {
@@ -603,16 +982,1469 @@ impl Harvester {
         info!("Graceful shutdown complete");
     }
 
+    /// Fails with `NoSourcesConfigured` when no source implementing `Source`
+    /// is configured (nad.fun and Monad don't yet, so they don't count)
+    pub fn ensure_sources(&self) -> IngestionResult<()> {
+        if self.sources.is_empty() {
+            error!("No data sources configured; nothing to harvest");
+            return Err(IngestionError::NoSourcesConfigured);
+        }
+        Ok(())
+    }
+
+    /// Applies the configured per-source rate limits to running sources
+    pub fn apply_rate_limits(&self, config: &Config) {
+        for (&id, source) in &self.sources {
+            source.set_rate_limit(config.rate_limit_rpm(id));
+        }
+    }
+
+    /// Gets the runtime enable/disable flags shared with the admin endpoint
+    pub fn source_switches(&self) -> SourceSwitches {
+        self.switches.clone()
+    }
+
+    /// Gets the latest health sweep results shared with `/readyz`
+    pub fn source_health(&self) -> SourceHealth {
+        self.health.clone()
+    }
+
+    /// Health checks every source and records the results
+    ///
+    /// Sources with an open circuit report unhealthy without being called,
+    /// and checks exceeding `health_check_timeout_ms` report unhealthy.
+    #[allow(dead_code)]
+    pub async fn health_check_all(&self) -> HashMap<String, bool> {
+        let timeout = Duration::from_millis(self.config.health_check_timeout_ms);
+        check_sources_health(&self.sources, &self.circuit_breakers, &self.health, timeout).await
+    }
+
     /// Gets circuit breaker status for all sources
-    pub fn circuit_breaker_status(&self) -> HashMap<String, crate::circuit_breaker::CircuitBreakerStats> {
+    #[allow(dead_code)]
+    pub fn circuit_breaker_status(&self) -> HashMap<SourceId, crate::circuit_breaker::CircuitBreakerStats> {
         self.circuit_breakers
             .iter()
-            .map(|(k, v)| (k.clone(), v.stats()))
+            .map(|(k, v)| (*k, v.stats()))
             .collect()
     }
 
     /// Gets dedup statistics
-    pub fn dedup_stats(&self) -> (usize, bool) {
-        (self.dedup.len(), self.dedup.is_empty())
+    #[allow(dead_code)]
+    pub fn dedup_stats(&self) -> DedupStats {
+        self.dedup.stats()
+    }
+
+    /// Gets the shared dedup store (for the admin endpoint)
+    pub fn dedup_store(&self) -> Arc<DedupStore> {
+        self.dedup.clone()
+    }
+
+    /// Gets the audit logger (clones share the append log and bus)
+    pub fn audit(&self) -> AuditLogger {
+        self.audit.clone()
+    }
+}
+
+/// Health checks sources concurrently, recording results in `health`
+///
+/// A check that errors or takes longer than `timeout` counts as unhealthy.
+async fn check_sources_health(
+    sources: &HashMap<SourceId, Arc<dyn Source>>,
+    circuit_breakers: &HashMap<SourceId, Arc<CircuitBreaker>>,
+    health: &SourceHealth,
+    timeout: Duration,
+) -> HashMap<String, bool> {
+    let checks = sources.iter().map(|(source_id, source)| async move {
+        let circuit_open = circuit_breakers
+            .get(source_id)
+            .is_some_and(|cb| cb.state() == CircuitState::Open);
+
+        let healthy = if circuit_open {
+            false
+        } else {
+            match tokio::time::timeout(timeout, source.health_check()).await {
+                Ok(Ok(healthy)) => healthy,
+                Ok(Err(e)) => {
+                    warn!(source = %source_id, error = %e, "Health check failed");
+                    false
+                }
+                Err(_) => {
+                    warn!(source = %source_id, timeout_ms = timeout.as_millis() as u64, "Health check timed out");
+                    false
+                }
+            }
+        };
+        (*source_id, healthy)
+    });
+
+    join_all(checks)
+        .await
+        .into_iter()
+        .map(|(source_id, healthy)| {
+            health.set(source_id, healthy);
+            (source_id.as_str().to_string(), healthy)
+        })
+        .collect()
+}
+
+/// Fetches from `source` inside a `source_fetch` span, recording its duration
+///
+/// A language filter the source can't apply itself is applied to the events.
+async fn timed_fetch(
+    source_id: SourceId,
+    source: &dyn Source,
+    options: FetchOptions,
+) -> IngestionResult<FetchResult> {
+    let _timer = metrics::SourceFetchTimer::new(source_id.as_str());
+    let language = options
+        .language_code()
+        .filter(|_| !source.metadata().supports_language);
+    let mut result = source
+        .fetch(options)
+        .instrument(info_span!("source_fetch", source = %source_id))
+        .await?;
+    if let Some(language) = language {
+        retain_language(&mut result.events, &language);
+    }
+    Ok(result)
+}
+
+/// Pages `harvest_source` follows in one cycle unless `harvest_max_pages`
+/// is set
+///
+/// Pages past the cap are not fetched later: the next cycle starts again
+/// from the checkpoint's `since`, and only a page that failed is resumed.
+const DEFAULT_HARVEST_PAGES: usize = 1;
+
+/// Events collected by `fetch_pages` before a page failed
+///
+/// `next_cursor` is the cursor of the failed page, so fetching with it
+/// resumes where the fetch stopped.
+#[derive(Debug)]
+pub struct PartialFetch {
+    pub events: Vec<IngestionEvent>,
+    pub next_cursor: Option<String>,
+    pub error: IngestionError,
+}
+
+/// Fetches consecutive pages from `source`, following `next_cursor` until
+/// the source has no more, `options.limit` events were collected, or
+/// `max_pages` pages were fetched
+///
+/// With several pages, `raw_payload` holds an array of each page's payload.
+async fn fetch_pages(
+    source_id: SourceId,
+    source: &dyn Source,
+    options: FetchOptions,
+    max_pages: usize,
+) -> std::result::Result<FetchResult, PartialFetch> {
+    let limit = options.limit.map(|limit| limit as usize);
+    let mut cursor = options.cursor.clone();
+    let mut combined = FetchResult::empty();
+    let mut raw_pages = Vec::new();
+
+    for _ in 0..max_pages.max(1) {
+        let page_options = FetchOptions {
+            cursor: cursor.clone(),
+            limit: limit.map(|limit| (limit - combined.events.len()) as u32),
+            ..options.clone()
+        };
+        let page = match timed_fetch(source_id, source, page_options).await {
+            Ok(page) => page,
+            Err(error) => {
+                return Err(PartialFetch {
+                    events: combined.events,
+                    next_cursor: cursor,
+                    error,
+                })
+            }
+        };
+
+        combined.events.extend(page.events);
+        raw_pages.extend(page.raw_payload);
+        combined.has_more = page.has_more && page.next_cursor.is_some();
+        combined.next_cursor = page.next_cursor;
+        cursor = combined.next_cursor.clone();
+
+        if !combined.has_more || limit.is_some_and(|limit| combined.events.len() >= limit) {
+            break;
+        }
+    }
+
+    combined.raw_payload = match raw_pages.len() {
+        0 | 1 => raw_pages.pop(),
+        _ => Some(serde_json::Value::Array(raw_pages)),
+    };
+    Ok(combined)
+}
+
+/// Starts a fetch from every source, yielding each outcome as it completes.
+/// Overall concurrency stays bounded by the shared HTTP client semaphore.
+fn fetch_each_source(
+    sources: &HashMap<SourceId, Arc<dyn Source>>,
+    options: &FetchOptions,
+) -> FuturesUnordered<BoxFuture<'static, (SourceId, IngestionResult<FetchResult>)>> {
+    sources
+        .iter()
+        .map(|(id, source)| {
+            let (id, source, options) = (*id, source.clone(), options.clone());
+            async move { (id, timed_fetch(id, source.as_ref(), options).await) }.boxed()
+        })
+        .collect()
+}
+
+/// Fetches from all sources concurrently, logging per-source failures
+///
+/// Events are sorted oldest first (see `sort_chronologically`). Each source
+/// honors `options.limit` on its own, so the combined result is also capped
+/// to the newest `limit` events; `counts` reflect what was kept. Sources that
+/// returned any events are recorded in `health`.
+async fn fetch_all_sources(
+    sources: &HashMap<SourceId, Arc<dyn Source>>,
+    options: &FetchOptions,
+    health: &SourceHealth,
+) -> FetchReport {
+    let mut report = FetchReport::default();
+    let mut fetched = Vec::new();
+
+    let mut fetches = fetch_each_source(sources, options);
+    while let Some((id, result)) = fetches.next().await {
+        match result {
+            Ok(result) => {
+                if !result.events.is_empty() {
+                    health.record_events(id);
+                }
+                report.counts.insert(id, 0);
+                fetched.extend(result.events.into_iter().map(|event| (id, event)));
+            }
+            Err(e) => {
+                warn!(source = %id, error = %e, "Failed to fetch");
+                report.failed.push((id, e.to_string()));
+            }
+        }
+    }
+
+    fetched.sort_by_cached_key(|(_, event)| chronological_key(event));
+    if let Some(limit) = options.limit {
+        let excess = fetched.len().saturating_sub(limit as usize);
+        fetched.drain(..excess);
+    }
+    for (id, _) in &fetched {
+        *report.counts.entry(*id).or_default() += 1;
+    }
+    report.events = fetched.into_iter().map(|(_, event)| event).collect();
+    report
+}
+
+/// Like `fetch_all_sources`, yielding each source's events as soon as its
+/// fetch completes
+fn fetch_all_stream(
+    sources: &HashMap<SourceId, Arc<dyn Source>>,
+    options: &FetchOptions,
+) -> BoxStream<'static, IngestionEvent> {
+    fetch_each_source(sources, options)
+        .filter_map(|(id, result)| async move {
+            match result {
+                Ok(result) => Some(stream::iter(result.events)),
+                Err(e) => {
+                    warn!(source = %id, error = %e, "Failed to fetch");
+                    None
+                }
+            }
+        })
+        .flatten()
+        .boxed()
+}
+
+/// Sorts events oldest first by `data_timestamp`, falling back to
+/// `ingested_at` when it is missing or unparseable (events with neither
+/// sort first; ties keep their order)
+fn sort_chronologically(events: &mut [IngestionEvent]) {
+    events.sort_by_cached_key(chronological_key);
+}
+
+/// Gets the time `sort_chronologically` orders an event by
+fn chronological_key(event: &IngestionEvent) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    let parse = |timestamp: &str| chrono::DateTime::parse_from_rfc3339(timestamp).ok();
+    event
+        .data_timestamp
+        .as_deref()
+        .and_then(parse)
+        .or_else(|| parse(&event.ingested_at))
+}
+
+/// Writes each event as one compact JSON line as it arrives, returning the
+/// number of events written
+pub async fn write_ndjson<W: std::io::Write>(
+    mut events: impl Stream<Item = IngestionEvent> + Unpin,
+    mut writer: W,
+) -> Result<usize> {
+    let mut count = 0;
+    while let Some(event) = events.next().await {
+        serde_json::to_writer(&mut writer, &event)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Strips unwanted payload keys from freshly fetched events (see
+/// `PayloadFilters`)
+fn filter_payloads(filters: &PayloadFilters, events: &mut [IngestionEvent]) {
+    if filters.is_empty() {
+        return;
+    }
+    for event in events {
+        filters.apply(event);
+    }
+}
+
+/// Gets the dedup store to check `source_id` against (`None` when the
+/// source bypasses dedup)
+fn dedup_for<'a>(
+    dedup: &'a DedupStore,
+    skip_dedup: &HashSet<SourceId>,
+    source_id: SourceId,
+) -> Option<&'a DedupStore> {
+    (!skip_dedup.contains(&source_id)).then_some(dedup)
+}
+
+/// Writes one fetch to the append log: the raw response (when enabled),
+/// then every non-duplicate normalized event.
+/// Returns the number of events stored.
+async fn append_fetch_result(
+    append_log: &dyn AppendLogStorage,
+    dedup: Option<&DedupStore>,
+    source_id: &str,
+    correlation_id: &str,
+    session_id: &str,
+    result: &FetchResult,
+    raw_log_limit: Option<u64>,
+) -> usize {
+    let entries = fetch_result_entries(
+        dedup,
+        source_id,
+        correlation_id,
+        session_id,
+        result,
+        raw_log_limit,
+    ).await;
+    write_entries(append_log, &entries).await
+}
+
+/// Builds the append-log entries for one fetch: the raw response (when
+/// `raw_log_limit` is set, truncated above it), then every non-duplicate
+/// normalized event (all of them when `dedup` is `None`).
+async fn fetch_result_entries(
+    dedup: Option<&DedupStore>,
+    source_id: &str,
+    correlation_id: &str,
+    session_id: &str,
+    result: &FetchResult,
+    raw_log_limit: Option<u64>,
+) -> Vec<LogEntry> {
+    let mut entries = Vec::with_capacity(result.events.len() + 1);
+
+    if let Some(ref raw_payload) = result.raw_payload {
+        let raw = raw_payload.to_string();
+        metrics::record_raw_payload_bytes(source_id, raw.len());
+
+        if let Some(max_raw_bytes) = raw_log_limit {
+            let payload = if raw.len() as u64 > max_raw_bytes {
+                warn!(
+                    source = source_id,
+                    size = raw.len(),
+                    limit = max_raw_bytes,
+                    "Truncating oversized raw response"
+                );
+                truncate_raw_payload(&raw, max_raw_bytes as usize)
+            } else {
+                raw_payload.clone()
+            };
+            entries.push(LogEntry::raw_response(
+                source_id,
+                correlation_id,
+                session_id,
+                payload,
+            ));
+        }
+    }
+
+    let mut duplicate_count = 0;
+    for event in &result.events {
+        // Check for duplicates
+        if let (Some(dedup), Some(dedup_key)) = (dedup, &event.deduplication_key) {
+            let key = DedupKey::from_content(source_id, dedup_key);
+            if dedup.check_and_mark(&key).await {
+                debug!(event_id = %event.id, "Duplicate event, skipping");
+                duplicate_count += 1;
+                continue;
+            }
+        }
+
+        entries.push(LogEntry {
+            id: event.id.clone(),
+            timestamp: Utc::now(),
+            source_id: source_id.to_string(),
+            correlation_id: correlation_id.to_string(),
+            session_id: session_id.to_string(),
+            entry_type: LogEntryType::NormalizedEvent,
+            payload: {
+                let mut event = event.clone();
+                event.stamp_lineage(session_id, correlation_id);
+                serde_json::to_value(&event).unwrap_or_default()
+            },
+            payload_size: event.payload_size,
+            content_hash: event.payload_hash.clone().unwrap_or_default(),
+        });
+    }
+
+    metrics::record_harvested_events(source_id, metrics::HARVEST_STATUS_DUPLICATE, duplicate_count);
+
+    entries
+}
+
+/// Replaces an oversized raw response with the first `max_bytes` of its JSON,
+/// flagged `truncated`
+fn truncate_raw_payload(raw: &str, max_bytes: usize) -> serde_json::Value {
+    let mut end = max_bytes.min(raw.len());
+    while !raw.is_char_boundary(end) {
+        end -= 1;
+    }
+    serde_json::json!({
+        "truncated": true,
+        "original_bytes": raw.len(),
+        "raw_prefix": &raw[..end],
+    })
+}
+
+/// Appends entries as one batch and records stored/error counts per source.
+/// Returns the number of normalized events stored.
+async fn write_entries(append_log: &dyn AppendLogStorage, entries: &[LogEntry]) -> usize {
+    match append_log.append_batch(entries).await {
+        Ok(()) => record_entry_events(entries, metrics::HARVEST_STATUS_STORED),
+        Err(e) => {
+            warn!(entries = entries.len(), error = %e, "Failed to append to log");
+            record_entry_events(entries, metrics::HARVEST_STATUS_ERROR);
+            0
+        }
+    }
+}
+
+/// Records each source's normalized events in `entries` under `status`.
+/// Returns the number recorded.
+fn record_entry_events(entries: &[LogEntry], status: &str) -> usize {
+    let mut events_per_source: HashMap<&str, u64> = HashMap::new();
+    for entry in entries {
+        let count = events_per_source.entry(entry.source_id.as_str()).or_default();
+        if matches!(entry.entry_type, LogEntryType::NormalizedEvent) {
+            *count += 1;
+        }
+    }
+
+    let mut recorded = 0;
+    for (source_id, count) in events_per_source {
+        metrics::record_harvested_events(source_id, status, count);
+        recorded += count as usize;
+    }
+    recorded
+}
+
+/// Quota units per minute shared by the polling loops
+///
+/// Each fetch spends its source's `estimated_cost`. A fetch the remaining
+/// budget can't cover is skipped for that tick, so when the budget is tight
+/// expensive sources are polled less often than cheap ones.
+struct FetchBudget {
+    limiter: DefaultDirectRateLimiter,
+}
+
+impl FetchBudget {
+    /// Creates a budget of `units` per minute (at least 1)
+    fn per_minute(units: u32) -> Self {
+        let units = NonZeroU32::new(units).unwrap_or(NonZeroU32::MIN);
+        Self {
+            limiter: RateLimiter::direct(Quota::per_minute(units)),
+        }
+    }
+
+    /// Spends `cost` units for a fetch from `source_id`, returning whether
+    /// the budget covered it
+    fn try_spend(&self, source_id: SourceId, cost: u32) -> bool {
+        let Some(cost) = NonZeroU32::new(cost) else {
+            return true;
+        };
+        match self.limiter.check_n(cost) {
+            Ok(Ok(())) => true,
+            Ok(Err(_)) => {
+                debug!(source = %source_id, cost = cost.get(), "Fetch budget exhausted, skipping");
+                false
+            }
+            Err(_) => {
+                debug!(source = %source_id, cost = cost.get(), "Fetch cost exceeds the whole budget, skipping");
+                false
+            }
+        }
+    }
+}
+
+/// Batches an `AppendBuffer` keeps while the append log is failing
+const MAX_PENDING_BATCHES: usize = 10;
+
+/// Append-log entries buffered by a harvest loop
+///
+/// Flushed as a single batch once `batch_size` entries are pending or
+/// `flush_interval` has passed since the last flush. Entries a flush fails
+/// to write are kept for the next one, up to `MAX_PENDING_BATCHES` batches;
+/// past that the oldest are dropped and counted as harvest errors.
+struct AppendBuffer {
+    entries: Vec<LogEntry>,
+    batch_size: usize,
+    flush_interval: Duration,
+    last_flush: Instant,
+}
+
+impl AppendBuffer {
+    fn new(batch_size: usize, flush_interval: Duration) -> Self {
+        Self {
+            entries: Vec::new(),
+            batch_size,
+            flush_interval,
+            last_flush: Instant::now(),
+        }
+    }
+
+    fn push(&mut self, entries: Vec<LogEntry>) {
+        self.entries.extend(entries);
+    }
+
+    fn is_due(&self) -> bool {
+        !self.entries.is_empty()
+            && (self.entries.len() >= self.batch_size
+                || self.last_flush.elapsed() >= self.flush_interval)
+    }
+
+    /// Flushes when the size or interval threshold has been reached
+    async fn flush_if_due(&mut self, append_log: &dyn AppendLogStorage) -> usize {
+        if self.is_due() {
+            self.flush(append_log).await
+        } else {
+            0
+        }
+    }
+
+    /// Writes all pending entries. Returns the number of events stored.
+    async fn flush(&mut self, append_log: &dyn AppendLogStorage) -> usize {
+        self.last_flush = Instant::now();
+        if self.entries.is_empty() {
+            return 0;
+        }
+
+        let mut entries = std::mem::take(&mut self.entries);
+        match append_log.append_batch(&entries).await {
+            Ok(()) => record_entry_events(&entries, metrics::HARVEST_STATUS_STORED),
+            Err(e) => {
+                let max_pending = self.batch_size.max(1) * MAX_PENDING_BATCHES;
+                let dropped = entries.len().saturating_sub(max_pending);
+                record_entry_events(&entries[..dropped], metrics::HARVEST_STATUS_ERROR);
+                entries.drain(..dropped);
+                warn!(
+                    pending = entries.len(),
+                    dropped,
+                    error = %e,
+                    "Failed to append to log, keeping entries for the next flush"
+                );
+                self.entries = entries;
+                0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::append_log::FileSystemAppendLog;
+    use crate::schemas::{IngestionDataType, IngestionSourceType};
+    use crate::sources::SourceMetadata;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Instant;
+    use tempfile::tempdir;
+
+    /// Source that returns its events after a fixed delay, one event keyed
+    /// by its id unless `with_events` says otherwise
+    ///
+    /// With `with_pages` the cursor is the page number and each page's dedup
+    /// keys are prefixed with it; `fail_on` fails that page (0 never fails).
+    struct TestSource {
+        metadata: SourceMetadata,
+        delay: Duration,
+        fetches: Arc<AtomicUsize>,
+        events: Vec<IngestionEvent>,
+        pages: usize,
+        fail_on: AtomicUsize,
+    }
+
+    impl TestSource {
+        fn new(id: &str, delay: Duration) -> Self {
+            Self {
+                metadata: SourceMetadata {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    description: "Test source".to_string(),
+                    default_rate_limit: 60,
+                    supports_pagination: false,
+                    supports_since: false,
+                    supports_language: false,
+                    estimated_cost: 1,
+                },
+                delay,
+                fetches: Arc::new(AtomicUsize::new(0)),
+                events: vec![create_test_event(id)],
+                pages: 1,
+                fail_on: AtomicUsize::new(0),
+            }
+        }
+
+        fn with_events(mut self, events: Vec<IngestionEvent>) -> Self {
+            self.events = events;
+            self
+        }
+
+        fn with_pages(mut self, pages: usize) -> Self {
+            self.pages = pages;
+            self
+        }
+
+        fn fail_on(self, page: usize) -> Self {
+            self.fail_on.store(page, Ordering::SeqCst);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl Source for TestSource {
+        fn metadata(&self) -> &SourceMetadata {
+            &self.metadata
+        }
+
+        async fn fetch(&self, options: FetchOptions) -> IngestionResult<FetchResult> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+
+            let page: usize = options.cursor.as_deref().map_or(1, |cursor| cursor.parse().unwrap());
+            if page == self.fail_on.load(Ordering::SeqCst) {
+                return Err(IngestionError::ApiError {
+                    code: "503".to_string(),
+                    message: "Service unavailable".to_string(),
+                });
+            }
+            if self.pages == 1 {
+                return Ok(FetchResult::with_events(self.events.clone()));
+            }
+
+            let events = self
+                .events
+                .iter()
+                .map(|event| {
+                    let mut event = event.clone();
+                    event.deduplication_key = event.deduplication_key.map(|key| format!("page{}-{}", page, key));
+                    event
+                })
+                .collect();
+            let mut result = FetchResult::with_events(events);
+            if page < self.pages {
+                result.next_cursor = Some((page + 1).to_string());
+                result.has_more = true;
+            }
+            Ok(result)
+        }
+
+        async fn health_check(&self) -> IngestionResult<bool> {
+            tokio::time::sleep(self.delay).await;
+            Ok(true)
+        }
+    }
+
+    fn create_test_event(dedup_key: &str) -> IngestionEvent {
+        let mut event = IngestionEvent::new(
+            IngestionSourceType::NewsApi,
+            "newsapi".to_string(),
+            "NewsAPI".to_string(),
+            IngestionDataType::News,
+            std::collections::HashMap::new(),
+        );
+        event.deduplication_key = Some(dedup_key.to_string());
+        event
+    }
+
+    #[tokio::test]
+    async fn test_append_fetch_result_writes_raw_and_normalized() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+        let dedup = DedupStore::new(100);
+
+        let mut result = FetchResult::with_events(vec![create_test_event("article-1")]);
+        result.raw_payload = Some(serde_json::json!({"articles": [{"title": "article-1"}]}));
+
+        let stored = append_fetch_result(
+            &log, Some(&dedup), "newsapi", "corr-1", "sess-1", &result, Some(1024),
+        ).await;
+        assert_eq!(stored, 1);
+
+        let entries = log.list_entries(Some("newsapi"), None, 100).await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().any(|e| matches!(e.entry_type, LogEntryType::RawResponse)
+            && e.correlation_id == "corr-1"
+            && e.payload == serde_json::json!({"articles": [{"title": "article-1"}]})));
+        assert!(entries.iter().any(|e| matches!(e.entry_type, LogEntryType::NormalizedEvent)));
+    }
+
+    #[tokio::test]
+    async fn test_append_fetch_result_skips_raw_when_disabled() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+        let dedup = DedupStore::new(100);
+
+        let mut result = FetchResult::with_events(vec![create_test_event("article-1")]);
+        result.raw_payload = Some(serde_json::json!({"articles": []}));
+
+        append_fetch_result(&log, Some(&dedup), "newsapi", "corr-1", "sess-1", &result, None).await;
+
+        let entries = log.list_entries(Some("newsapi"), None, 100).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(matches!(entries[0].entry_type, LogEntryType::NormalizedEvent));
+    }
+
+    #[tokio::test]
+    async fn test_append_fetch_result_truncates_oversized_raw() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+        let dedup = DedupStore::new(100);
+
+        let mut result = FetchResult::with_events(vec![create_test_event("article-1")]);
+        let raw = serde_json::json!({"articles": [{"content": "é".repeat(2000)}]});
+        let raw_size = raw.to_string().len();
+        result.raw_payload = Some(raw);
+
+        let (count_before, sum_before) = metrics::raw_payload_bytes_observed("raw-size-test");
+        append_fetch_result(&log, Some(&dedup), "raw-size-test", "corr-1", "sess-1", &result, Some(101)).await;
+
+        let (count, sum) = metrics::raw_payload_bytes_observed("raw-size-test");
+        assert_eq!(count, count_before + 1);
+        assert_eq!(sum - sum_before, raw_size as f64);
+
+        let entries = log.list_entries(Some("raw-size-test"), None, 100).await.unwrap();
+        let raw_entry = entries
+            .iter()
+            .find(|e| matches!(e.entry_type, LogEntryType::RawResponse))
+            .unwrap();
+        assert_eq!(raw_entry.payload["truncated"], serde_json::json!(true));
+        assert_eq!(raw_entry.payload["original_bytes"], serde_json::json!(raw_size));
+        let prefix = raw_entry.payload["raw_prefix"].as_str().unwrap();
+        assert!(prefix.len() <= 101);
+        assert!(prefix.starts_with(r#"{"articles":[{"content":"é"#));
+
+        // Responses are sized even when raw logging is off
+        append_fetch_result(&log, Some(&dedup), "raw-size-test", "corr-1", "sess-1", &result, None).await;
+        assert_eq!(metrics::raw_payload_bytes_observed("raw-size-test").0, count_before + 2);
+    }
+
+    #[tokio::test]
+    async fn test_append_buffer_flushes_at_batch_size() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+        let dedup = DedupStore::new(100);
+        let mut buffer = AppendBuffer::new(2, Duration::from_secs(3600));
+
+        for (i, dedup_key) in ["article-1", "article-2"].iter().enumerate() {
+            let result = FetchResult::with_events(vec![create_test_event(dedup_key)]);
+            buffer.push(
+                fetch_result_entries(Some(&dedup), "newsapi", "corr-1", "sess-1", &result, None).await,
+            );
+            let stored = buffer.flush_if_due(&log).await;
+
+            let entries = log.list_entries(Some("newsapi"), None, 100).await.unwrap();
+            if i == 0 {
+                assert_eq!(stored, 0);
+                assert!(entries.is_empty());
+            } else {
+                assert_eq!(stored, 2);
+                assert_eq!(entries.len(), 2);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_append_buffer_keeps_entries_after_failed_flush() {
+        let temp_dir = tempdir().unwrap();
+        let log_dir = temp_dir.path().join("log");
+        let log = FileSystemAppendLog::new(&log_dir).await.unwrap();
+        let dedup = DedupStore::new(100);
+        let mut buffer = AppendBuffer::new(1, Duration::from_secs(3600));
+
+        let result = FetchResult::with_events(vec![create_test_event("article-kept")]);
+        buffer.push(fetch_result_entries(Some(&dedup), "newsapi", "corr-1", "sess-1", &result, None).await);
+
+        // A file where the log directory should be makes writes fail
+        std::fs::remove_dir_all(&log_dir).unwrap();
+        std::fs::write(&log_dir, b"").unwrap();
+        assert_eq!(buffer.flush_if_due(&log).await, 0);
+        assert!(buffer.is_due());
+
+        std::fs::remove_file(&log_dir).unwrap();
+        assert_eq!(buffer.flush_if_due(&log).await, 1);
+        assert_eq!(log.list_entries(Some("newsapi"), None, 100).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_sources_runs_concurrently() {
+        let mut sources: HashMap<SourceId, Arc<dyn Source>> = HashMap::new();
+        sources.insert(
+            SourceId::NewsApi,
+            Arc::new(TestSource::new("newsapi", Duration::from_millis(300))),
+        );
+        sources.insert(
+            SourceId::CryptoPanic,
+            Arc::new(TestSource::new("cryptopanic", Duration::from_millis(250))),
+        );
+
+        let start = Instant::now();
+        let report = fetch_all_sources(&sources, &FetchOptions::new(), &SourceHealth::default()).await;
+        let elapsed = start.elapsed();
+
+        assert_eq!(report.events.len(), 2);
+        // Closer to the slowest source (300ms) than the sum (550ms)
+        assert!(elapsed < Duration::from_millis(450), "took {:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn test_append_fetch_result_records_harvest_metrics() {
+        let temp_dir = tempdir().unwrap();
+        let log = FileSystemAppendLog::new(temp_dir.path()).await.unwrap();
+        let dedup = DedupStore::new(100);
+        let source_id = "metrics-test";
+
+        let stored_before = metrics::harvested_events_total(source_id, metrics::HARVEST_STATUS_STORED);
+        let duplicate_before = metrics::harvested_events_total(source_id, metrics::HARVEST_STATUS_DUPLICATE);
+
+        let result = FetchResult::with_events(vec![
+            create_test_event("article-1"),
+            create_test_event("article-1"),
+        ]);
+        append_fetch_result(&log, Some(&dedup), source_id, "corr-1", "sess-1", &result, None).await;
+
+        assert_eq!(
+            metrics::harvested_events_total(source_id, metrics::HARVEST_STATUS_STORED),
+            stored_before + 1
+        );
+        assert_eq!(
+            metrics::harvested_events_total(source_id, metrics::HARVEST_STATUS_DUPLICATE),
+            duplicate_before + 1
+        );
+    }
+
+    /// Source whose fetches always fail
+    struct FailingSource {
+        metadata: SourceMetadata,
+    }
+
+    impl FailingSource {
+        fn new(id: &str) -> Self {
+            Self {
+                metadata: TestSource::new(id, Duration::ZERO).metadata,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Source for FailingSource {
+        fn metadata(&self) -> &SourceMetadata {
+            &self.metadata
+        }
+
+        async fn fetch(&self, _options: FetchOptions) -> IngestionResult<FetchResult> {
+            Err(IngestionError::ConnectionLost("test failure".to_string()))
+        }
+
+        async fn health_check(&self) -> IngestionResult<bool> {
+            Ok(false)
+        }
+    }
+
+    async fn test_harvester(temp_dir: &tempfile::TempDir) -> Harvester {
+        let config: Config = serde_json::from_value(serde_json::json!({
+            "checkpoint_dir": temp_dir.path().join("checkpoints"),
+            "data_dir": temp_dir.path().join("data"),
+            "news_interval_ms": 20,
+        }))
+        .unwrap();
+        Harvester::new(config, "corr-1".to_string()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_run_once_reports_total_failure() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.sources.clear();
+        harvester.sources.insert(SourceId::NewsApi, Arc::new(FailingSource::new("newsapi")));
+        harvester.sources.insert(SourceId::CryptoPanic, Arc::new(FailingSource::new("cryptopanic")));
+
+        let report = harvester.run_once().await.unwrap();
+        assert!(report.is_total_failure());
+        assert!(report.succeeded.is_empty());
+        assert_eq!(report.failed.len(), 2);
+        assert_eq!(report.total_events, 0);
+
+        // One working source makes it a partial success
+        harvester.sources.insert(SourceId::XApi, Arc::new(TestSource::new("x_api", Duration::ZERO)));
+        let report = harvester.run_once().await.unwrap();
+        assert!(!report.is_total_failure());
+        assert_eq!(report.succeeded, vec![SourceId::XApi]);
+        assert_eq!(report.failed.len(), 2);
+        assert_eq!(report.total_events, 1);
+    }
+
+    #[tokio::test]
+    async fn test_skip_dedup_source_emits_repeated_events() {
+        let temp_dir = tempdir().unwrap();
+        let config: Config = serde_json::from_value(serde_json::json!({
+            "checkpoint_dir": temp_dir.path().join("checkpoints"),
+            "data_dir": temp_dir.path().join("data"),
+            "skip_dedup_sources": "x_api",
+        }))
+        .unwrap();
+        let harvester = Harvester::new(config, "corr-1".to_string()).await.unwrap();
+
+        // Both sources return the same dedup key on every fetch
+        let deduped = TestSource::new("newsapi", Duration::ZERO);
+        let snapshots = TestSource::new("x_api", Duration::ZERO);
+
+        let mut stored = Vec::new();
+        for _ in 0..2 {
+            stored.push((
+                harvester.harvest_source(SourceId::NewsApi, &deduped, FetchOptions::default()).await.unwrap(),
+                harvester.harvest_source(SourceId::XApi, &snapshots, FetchOptions::default()).await.unwrap(),
+            ));
+        }
+        assert_eq!(stored, vec![(1, 1), (0, 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_no_dedup_emits_repeated_events() {
+        let temp_dir = tempdir().unwrap();
+        let mut config: Config = serde_json::from_value(serde_json::json!({
+            "checkpoint_dir": temp_dir.path().join("checkpoints"),
+            "data_dir": temp_dir.path().join("data"),
+        }))
+        .unwrap();
+        config.no_dedup = true;
+        let harvester = Harvester::new(config, "corr-1".to_string()).await.unwrap();
+
+        // Every fetch returns an event with the same dedup key
+        let source = TestSource::new("newsapi", Duration::ZERO);
+        let mut stored = Vec::new();
+        for _ in 0..2 {
+            stored.push(harvester.harvest_source(SourceId::NewsApi, &source, FetchOptions::default()).await.unwrap());
+        }
+        assert_eq!(stored, vec![1, 1]);
+        assert_eq!(harvester.dedup.stats().hits, 0);
+    }
+
+    #[tokio::test]
+    async fn test_paused_source_skipped_until_resumed() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+
+        let source = TestSource::new("newsapi", Duration::ZERO);
+        let fetches = source.fetches.clone();
+        harvester.sources.insert(SourceId::NewsApi, Arc::new(source));
+
+        let switches = harvester.source_switches();
+        switches.pause(SourceId::NewsApi);
+        let handle = harvester.spawn_news_harvester();
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 0);
+
+        switches.resume(SourceId::NewsApi);
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(fetches.load(Ordering::SeqCst) > 0);
+
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_expensive_source_polled_less_under_budget() {
+        let temp_dir = tempdir().unwrap();
+        let config: Config = serde_json::from_value(serde_json::json!({
+            "checkpoint_dir": temp_dir.path().join("checkpoints"),
+            "data_dir": temp_dir.path().join("data"),
+            "news_interval_ms": 20,
+            "fetch_budget_per_minute": 60,
+        }))
+        .unwrap();
+        let mut harvester = Harvester::new(config, "corr-1".to_string()).await.unwrap();
+        harvester.sources.clear();
+
+        let cheap = TestSource::new("newsapi", Duration::ZERO);
+        let mut expensive = TestSource::new("cryptopanic", Duration::ZERO);
+        expensive.metadata.estimated_cost = 20;
+        let (cheap_fetches, expensive_fetches) = (cheap.fetches.clone(), expensive.fetches.clone());
+        harvester.sources.insert(SourceId::NewsApi, Arc::new(cheap));
+        harvester.sources.insert(SourceId::CryptoPanic, Arc::new(expensive));
+
+        let handle = harvester.spawn_news_harvester();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        handle.abort();
+
+        // 60 units cover two expensive fetches, and cheap ones every tick
+        let (cheap, expensive) = (cheap_fetches.load(Ordering::SeqCst), expensive_fetches.load(Ordering::SeqCst));
+        assert_eq!(expensive, 2);
+        assert!(cheap > 5, "cheap source fetched {} times", cheap);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_overrides_apply_to_sources() {
+        let temp_dir = tempdir().unwrap();
+        let mut config: Config = serde_json::from_value(serde_json::json!({
+            "checkpoint_dir": temp_dir.path().join("checkpoints"),
+            "data_dir": temp_dir.path().join("data"),
+            "news_api_key": "test-key",
+            "twitter_bearer_token": "test-token",
+        }))
+        .unwrap();
+
+        let overrides = crate::config::parse_rate_limit_overrides("newsapi=30, x_api=15").unwrap();
+        assert_eq!(overrides, HashMap::from([(SourceId::NewsApi, 30), (SourceId::XApi, 15)]));
+        config.apply_rate_limit_overrides(&overrides);
+
+        let harvester = Harvester::new(config, "corr-1".to_string()).await.unwrap();
+        assert_eq!(harvester.sources[&SourceId::NewsApi].metadata().default_rate_limit, 30);
+        assert_eq!(harvester.sources[&SourceId::XApi].metadata().default_rate_limit, 15);
+    }
+
+    #[tokio::test]
+    async fn test_health_check_all_reports_unhealthy_sources() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.sources.clear();
+        harvester.sources.insert(SourceId::NewsApi, Arc::new(TestSource::new("newsapi", Duration::ZERO)));
+        harvester.sources.insert(SourceId::CryptoPanic, Arc::new(FailingSource::new("cryptopanic")));
+        harvester.sources.insert(SourceId::XApi, Arc::new(TestSource::new("x_api", Duration::ZERO)));
+
+        // An open circuit is unhealthy even though the source would pass
+        harvester.circuit_breakers[&SourceId::XApi].trip();
+
+        let results = harvester.health_check_all().await;
+        let expected: HashMap<String, bool> = [("newsapi", true), ("cryptopanic", false), ("x_api", false)]
+            .into_iter()
+            .map(|(id, healthy)| (id.to_string(), healthy))
+            .collect();
+        assert_eq!(results, expected);
+
+        let health = harvester.source_health();
+        assert_eq!(health.get(SourceId::CryptoPanic), Some(false));
+        assert_eq!(health.get(SourceId::Monad), None);
+        assert!(!health.is_ready());
+    }
+
+    #[tokio::test]
+    async fn test_run_continuous_fails_without_sources() {
+        let temp_dir = tempdir().unwrap();
+        let harvester = test_harvester(&temp_dir).await;
+        assert!(harvester.sources.is_empty());
+
+        let err = tokio::time::timeout(Duration::from_secs(1), harvester.run_continuous())
+            .await
+            .expect("run_continuous should fail fast instead of idling")
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<IngestionError>(),
+            Some(IngestionError::NoSourcesConfigured)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_source_fetch_records_duration() {
+        let temp_dir = tempdir().unwrap();
+        let harvester = test_harvester(&temp_dir).await;
+        let source = TestSource::new("monad", Duration::from_millis(20));
+
+        let (count_before, sum_before) = metrics::source_fetch_duration_observed("monad");
+        harvester.harvest_source(SourceId::Monad, &source, FetchOptions::default()).await.unwrap();
+        let (count_after, sum_after) = metrics::source_fetch_duration_observed("monad");
+
+        assert!(count_after > count_before);
+        assert!(sum_after - sum_before >= 0.02);
+    }
+
+    #[tokio::test]
+    async fn test_write_ndjson_streams_one_event_per_line() {
+        let mut sources: HashMap<SourceId, Arc<dyn Source>> = HashMap::new();
+        for (id, name) in [(SourceId::NewsApi, "newsapi"), (SourceId::CryptoPanic, "cryptopanic"), (SourceId::XApi, "x_api")] {
+            sources.insert(id, Arc::new(TestSource::new(name, Duration::ZERO)));
+        }
+
+        let mut output = Vec::new();
+        let written = write_ndjson(fetch_all_stream(&sources, &FetchOptions::new()), &mut output)
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(written, 3);
+        assert!(output.ends_with('\n'));
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for line in lines {
+            let event: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(event.is_object());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hung_health_check_times_out_as_unhealthy() {
+        let temp_dir = tempdir().unwrap();
+        let config: Config = serde_json::from_value(serde_json::json!({
+            "checkpoint_dir": temp_dir.path().join("checkpoints"),
+            "data_dir": temp_dir.path().join("data"),
+            "health_check_timeout_ms": 50,
+        }))
+        .unwrap();
+        let mut harvester = Harvester::new(config, "corr-1".to_string()).await.unwrap();
+        harvester.sources.clear();
+        harvester.sources.insert(SourceId::NewsApi, Arc::new(TestSource::new("newsapi", Duration::ZERO)));
+        harvester.sources.insert(SourceId::XApi, Arc::new(TestSource::new("x_api", Duration::from_secs(30))));
+
+        let started = Instant::now();
+        let results = harvester.health_check_all().await;
+
+        assert!(started.elapsed() < Duration::from_secs(2), "took {:?}", started.elapsed());
+        assert!(results["newsapi"]);
+        assert!(!results["x_api"]);
+        assert_eq!(harvester.source_health().get(SourceId::XApi), Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_health_sweep_drives_pipeline_readiness() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.sources.clear();
+        harvester.sources.insert(SourceId::NewsApi, Arc::new(TestSource::new("newsapi", Duration::ZERO)));
+        harvester.sources.insert(SourceId::CryptoPanic, Arc::new(FailingSource::new("cryptopanic")));
+
+        // Pipeline mode builds its admin state like this, without run_continuous
+        let admin = crate::admin::AdminState {
+            auth_token: None,
+            switches: harvester.source_switches(),
+            dedup: None,
+            health: harvester.source_health(),
+            max_event_age: None,
+            audit: None,
+        };
+        let sweep = harvester.spawn_health_sweep();
+
+        let mut swept = false;
+        for _ in 0..50 {
+            if admin.health.get(SourceId::CryptoPanic).is_some() {
+                swept = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        sweep.abort();
+
+        assert!(swept, "health sweep never ran");
+        assert!(!admin.is_ready());
+    }
+
+    fn static_source(id: &str, timestamps: &[Option<&str>]) -> Arc<dyn Source> {
+        let events = timestamps
+            .iter()
+            .map(|timestamp| {
+                let mut event = create_test_event(id);
+                event.data_timestamp = timestamp.map(str::to_string);
+                event
+            })
+            .collect();
+        Arc::new(TestSource::new(id, Duration::ZERO).with_events(events))
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_sorts_events_by_data_timestamp() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.sources.clear();
+        harvester.sources.insert(
+            SourceId::NewsApi,
+            static_source("newsapi", &[Some("2024-01-01T12:00:00Z"), Some("2024-01-01T09:00:00Z")]),
+        );
+        harvester.sources.insert(
+            SourceId::XApi,
+            // 10:30 UTC, and one without a data timestamp (ingested now)
+            static_source("x_api", &[Some("2024-01-01T12:30:00+02:00"), None]),
+        );
+
+        let events = harvester.fetch_from_source("all", FetchOptions::new()).await.unwrap();
+
+        let timestamps: Vec<Option<&str>> = events.iter().map(|e| e.data_timestamp.as_deref()).collect();
+        assert_eq!(
+            timestamps,
+            vec![
+                Some("2024-01-01T09:00:00Z"),
+                Some("2024-01-01T12:30:00+02:00"),
+                Some("2024-01-01T12:00:00Z"),
+                None,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_honors_global_limit() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.sources.clear();
+
+        // 30 events per source, interleaved a minute apart across sources
+        let sources = [(SourceId::NewsApi, "newsapi"), (SourceId::CryptoPanic, "cryptopanic"), (SourceId::XApi, "x_api")];
+        for (offset, (source_id, name)) in sources.into_iter().enumerate() {
+            let timestamps: Vec<String> = (0..30)
+                .map(|i| format!("2024-01-01T{:02}:{:02}:00Z", (i * 3 + offset) / 60, (i * 3 + offset) % 60))
+                .collect();
+            let timestamps: Vec<Option<&str>> = timestamps.iter().map(|t| Some(t.as_str())).collect();
+            harvester.sources.insert(source_id, static_source(name, &timestamps));
+        }
+
+        let events = harvester
+            .fetch_from_source("all", FetchOptions::new().limit(50))
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 50);
+        // The newest 50 of the 90 minutes: 00:40 through 01:29
+        assert_eq!(events.first().unwrap().data_timestamp.as_deref(), Some("2024-01-01T00:40:00Z"));
+        assert_eq!(events.last().unwrap().data_timestamp.as_deref(), Some("2024-01-01T01:29:00Z"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_all_with_limit_keeps_newest_events() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.sources.clear();
+
+        // The first source to respond has the oldest events
+        let events = |id: &str, timestamps: [&str; 2]| {
+            timestamps
+                .iter()
+                .map(|timestamp| {
+                    let mut event = create_test_event(id);
+                    event.data_timestamp = Some(timestamp.to_string());
+                    event
+                })
+                .collect()
+        };
+        harvester.sources.insert(
+            SourceId::NewsApi,
+            Arc::new(TestSource::new("newsapi", Duration::ZERO).with_events(events("newsapi", ["2024-01-01T09:00:00Z", "2024-01-01T10:00:00Z"]))),
+        );
+        harvester.sources.insert(
+            SourceId::XApi,
+            Arc::new(TestSource::new("x_api", Duration::from_millis(50)).with_events(events("x_api", ["2024-01-01T11:00:00Z", "2024-01-01T12:00:00Z"]))),
+        );
+
+        let streamed: Vec<_> = harvester
+            .stream_from_source("all", FetchOptions::new().limit(2))
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        let timestamps: Vec<_> = streamed.iter().map(|e| e.data_timestamp.as_deref().unwrap()).collect();
+        assert_eq!(timestamps, vec!["2024-01-01T11:00:00Z", "2024-01-01T12:00:00Z"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_records_sources_returning_events() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.sources.clear();
+
+        let event_at = |id: &str, timestamp: &str| {
+            let mut event = create_test_event(id);
+            event.data_timestamp = Some(timestamp.to_string());
+            event
+        };
+        harvester.sources.insert(
+            SourceId::NewsApi,
+            Arc::new(TestSource::new("newsapi", Duration::ZERO).with_events(vec![event_at("newsapi", "2024-01-01T09:00:00Z")])),
+        );
+        harvester.sources.insert(
+            SourceId::XApi,
+            Arc::new(TestSource::new("x_api", Duration::ZERO).with_events(vec![event_at("x_api", "2024-01-01T12:00:00Z")])),
+        );
+        harvester.watch_polled_sources();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // newsapi's event is cut by the limit but still counts as fresh
+        let report = harvester.fetch_all(FetchOptions::new().limit(1)).await;
+        assert_eq!(report.counts.get(&SourceId::NewsApi), Some(&0));
+
+        let ages = harvester.source_health().event_ages();
+        assert_eq!(ages.len(), 2);
+        assert!(ages.values().all(|age| *age < Duration::from_millis(200)), "{:?}", ages);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_report_records_submitted_counts() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.sources.clear();
+        // Only this test records the submitted status and fetch errors
+        harvester.sources.insert(SourceId::NadFun, static_source("nadfun", &[None, None, None]));
+        harvester.sources.insert(SourceId::Monad, Arc::new(FailingSource::new("monad")));
+
+        let submitted_before = metrics::harvested_events_total("nadfun", metrics::HARVEST_STATUS_SUBMITTED);
+        let errors_before = metrics::source_fetch_errors_total("monad");
+
+        let report = harvester.fetch_all(FetchOptions::new()).await;
+        report.record_metrics();
+
+        assert_eq!(report.events.len(), 3);
+        assert_eq!(report.counts.get(&SourceId::NadFun), Some(&3));
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, SourceId::Monad);
+        assert_eq!(
+            metrics::harvested_events_total("nadfun", metrics::HARVEST_STATUS_SUBMITTED),
+            submitted_before + 3
+        );
+        assert_eq!(metrics::source_fetch_errors_total("monad"), errors_before + 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_stamps_lineage() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.sources.clear();
+        harvester.sources.insert(SourceId::NadFun, static_source("nadfun", &[None, None]));
+        let session_id = harvester.checkpoint.read().await.session_id().to_string();
+
+        let events = harvester.fetch_from_source("all", FetchOptions::new()).await.unwrap();
+        assert_eq!(events.len(), 2);
+        for event in &events {
+            assert_eq!(event.session_id.as_deref(), Some(session_id.as_str()));
+            assert_eq!(event.correlation_id.as_deref(), Some(harvester.correlation_id.as_str()));
+        }
+
+        let streamed: Vec<_> = harvester
+            .stream_from_source("all", FetchOptions::new())
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert!(streamed.iter().all(|e| e.session_id.as_deref() == Some(session_id.as_str())));
+    }
+
+    #[tokio::test]
+    async fn test_harvest_follows_one_page_by_default() {
+        let temp_dir = tempdir().unwrap();
+        let harvester = test_harvester(&temp_dir).await;
+        let source = TestSource::new("newsapi", Duration::ZERO)
+            .with_events(vec![create_test_event("a"), create_test_event("b")])
+            .with_pages(3);
+
+        let stored = harvester.harvest_source(SourceId::NewsApi, &source, FetchOptions::new()).await.unwrap();
+        assert_eq!(stored, 2);
+        assert_eq!(source.fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_pagination_returns_partial_and_resumes() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.max_pages = 5;
+        let source = TestSource::new("newsapi", Duration::ZERO)
+            .with_events(vec![create_test_event("a"), create_test_event("b")])
+            .with_pages(5)
+            .fail_on(3);
+
+        let partial = fetch_pages(SourceId::NewsApi, &source, FetchOptions::new(), 5)
+            .await
+            .unwrap_err();
+        assert_eq!(partial.events.len(), 4);
+        assert_eq!(partial.next_cursor.as_deref(), Some("3"));
+        assert!(matches!(partial.error, IngestionError::ApiError { .. }));
+
+        // The harvest keeps pages 1-2 and records where to resume
+        assert!(harvester.harvest_source(SourceId::NewsApi, &source, FetchOptions::new()).await.is_err());
+        assert_eq!(harvester.checkpoint.read().await.resume_cursor("newsapi").as_deref(), Some("3"));
+
+        // Once the source recovers, the next cycle picks up at page 3
+        source.fail_on.store(0, Ordering::SeqCst);
+        let stored = harvester.harvest_source(SourceId::NewsApi, &source, FetchOptions::new()).await.unwrap();
+        assert_eq!(stored, 6);
+        let checkpoint = harvester.checkpoint.read().await;
+        assert!(checkpoint.resume_cursor("newsapi").is_none());
+        assert_eq!(checkpoint.get_checkpoint("newsapi").unwrap().total_items_fetched, 10);
+    }
+
+    #[tokio::test]
+    async fn test_language_filter_applied_when_source_lacks_support() {
+        let events: Vec<_> = ["en", "tr", "en"]
+            .iter()
+            .map(|language| {
+                let mut event = create_test_event(language);
+                event.payload.insert("language".to_string(), serde_json::json!(language));
+                event
+            })
+            .collect();
+        let mut source = TestSource::new("newsapi", Duration::ZERO).with_events(events);
+
+        let options = FetchOptions::new().language("tr");
+        let result = timed_fetch(SourceId::NewsApi, &source, options.clone()).await.unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.events[0].payload["language"], "tr");
+
+        // A source filtering natively is trusted with the result
+        source.metadata.supports_language = true;
+        let result = timed_fetch(SourceId::NewsApi, &source, options).await.unwrap();
+        assert_eq!(result.events.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_configured_language_applies_to_every_fetch() {
+        let temp_dir = tempdir().unwrap();
+        let mut harvester = test_harvester(&temp_dir).await;
+        harvester.config.language_filter = Some("tr".to_string());
+        harvester.sources.clear();
+        let events: Vec<_> = ["en", "tr", "en"]
+            .iter()
+            .map(|language| {
+                let mut event = create_test_event(language);
+                event.payload.insert("language".to_string(), serde_json::json!(language));
+                event
+            })
+            .collect();
+        harvester.sources.insert(
+            SourceId::NewsApi,
+            Arc::new(TestSource::new("newsapi", Duration::ZERO).with_events(events)),
+        );
+
+        assert_eq!(harvester.fetch_all(FetchOptions::new()).await.events.len(), 1);
+        assert_eq!(harvester.fetch_from_source("newsapi", FetchOptions::new()).await.unwrap().len(), 1);
+        let streamed: Vec<_> = harvester
+            .stream_from_source("all", FetchOptions::new())
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(streamed.len(), 1);
+
+        // A language passed in explicitly wins
+        let events = harvester
+            .fetch_from_source("newsapi", FetchOptions::new().language("en"))
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 2);
     }
 }
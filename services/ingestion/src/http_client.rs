@@ -9,23 +9,29 @@
 //! Turkish: "Aynı anda çok fazla HTTP isteği atıp API anahtarlarımın
 //! banlanmaması için tokio::sync::Semaphore kullanarak eşzamanlı istek sayısını sınırla."
 
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use backoff::{ExponentialBackoff, ExponentialBackoffBuilder};
 use governor::{Quota, RateLimiter, state::NotKeyed, clock::DefaultClock, middleware::NoOpMiddleware};
-use reqwest::{Client, Request, Response, StatusCode};
+use reqwest::{header::{HeaderValue, AUTHORIZATION, USER_AGENT}, Client, Request, Response, StatusCode};
 use std::num::NonZeroU32;
 use tokio::sync::Semaphore;
 use tracing::{debug, warn};
 
 use crate::circuit_breaker::CircuitBreaker;
 use crate::error::{IngestionError, Result};
+use crate::retry::Backoff;
 
 /// Configuration for the HTTP client
 #[derive(Debug, Clone)]
 pub struct HttpClientConfig {
     /// Maximum concurrent requests across all sources
     pub max_concurrent_requests: usize,
+    /// Concurrency caps for individual sources (by source id), applied
+    /// under `max_concurrent_requests`
+    pub source_concurrency: HashMap<String, usize>,
     /// Request timeout
     pub request_timeout: Duration,
     /// Connection timeout
@@ -37,6 +43,7 @@ pub struct HttpClientConfig {
     /// Maximum retry delay
     pub max_retry_delay: Duration,
     /// Retry multiplier for exponential backoff
+    #[allow(dead_code)]
     pub retry_multiplier: f64,
     /// User agent string
     pub user_agent: String,
@@ -46,6 +53,7 @@ impl Default for HttpClientConfig {
     fn default() -> Self {
         Self {
             max_concurrent_requests: 10,
+            source_concurrency: HashMap::new(),
             request_timeout: Duration::from_secs(30),
             connect_timeout: Duration::from_secs(10),
             max_retries: 3,
@@ -63,6 +71,8 @@ pub struct ResilientHttpClient {
     client: Client,
     /// Global concurrency semaphore
     semaphore: Arc<Semaphore>,
+    /// Per-source concurrency semaphores (sources without a cap have none)
+    source_semaphores: HashMap<String, Arc<Semaphore>>,
     /// Configuration
     config: HttpClientConfig,
 }
@@ -77,18 +87,25 @@ impl ResilientHttpClient {
             .gzip(true)
             .brotli(true)
             .build()
-            .map_err(|e| IngestionError::HttpError(e))?;
+            .map_err(IngestionError::HttpError)?;
 
         let semaphore = Arc::new(Semaphore::new(config.max_concurrent_requests));
+        let source_semaphores = config
+            .source_concurrency
+            .iter()
+            .map(|(source_id, &limit)| (source_id.clone(), Arc::new(Semaphore::new(limit))))
+            .collect();
 
         Ok(Self {
             client,
             semaphore,
+            source_semaphores,
             config,
         })
     }
 
     /// Creates a client with default configuration
+    #[allow(dead_code)]
     pub fn with_defaults() -> Result<Self> {
         Self::new(HttpClientConfig::default())
     }
@@ -99,6 +116,7 @@ impl ResilientHttpClient {
     }
 
     /// Creates an exponential backoff with jitter
+    #[allow(dead_code)]
     fn create_backoff(&self) -> ExponentialBackoff {
         ExponentialBackoffBuilder::new()
             .with_initial_interval(self.config.initial_retry_delay)
@@ -125,17 +143,20 @@ impl ResilientHttpClient {
         );
 
         let mut attempt = 0u32;
-        let mut delay = self.config.initial_retry_delay;
+        let mut backoff = Backoff::new(self.config.initial_retry_delay, self.config.max_retry_delay);
         let max_retries = self.config.max_retries;
 
         loop {
             attempt += 1;
             
-            // Build request for this attempt
-            let req = self.client
-                .request(method.clone(), &url)
-                .build()
-                .map_err(|e| IngestionError::HttpError(e))?;
+            // Build request for this attempt (keeping per-request headers)
+            let req = match request.try_clone() {
+                Some(req) => req,
+                None => self.client
+                    .request(method.clone(), &url)
+                    .build()
+                    .map_err(IngestionError::HttpError)?,
+            };
 
             match self.client.execute(req).await {
                 Ok(response) => {
@@ -155,11 +176,7 @@ impl ResilientHttpClient {
                             max_retries = max_retries,
                             "Retryable error, will retry"
                         );
-                        // Apply jitter: random factor between 0.5 and 1.5
-                        let jitter = 0.5 + rand::random::<f64>();
-                        let jittered_delay = Duration::from_secs_f64(delay.as_secs_f64() * jitter);
-                        tokio::time::sleep(jittered_delay).await;
-                        delay = std::cmp::min(delay * 2, self.config.max_retry_delay);
+                        tokio::time::sleep(backoff.next_delay()).await;
                     } else {
                         // Non-retryable or max retries exceeded
                         let body = response.text().await.unwrap_or_default();
@@ -176,10 +193,7 @@ impl ResilientHttpClient {
                             attempt = attempt,
                             "Transient error, will retry"
                         );
-                        let jitter = 0.5 + rand::random::<f64>();
-                        let jittered_delay = Duration::from_secs_f64(delay.as_secs_f64() * jitter);
-                        tokio::time::sleep(jittered_delay).await;
-                        delay = std::cmp::min(delay * 2, self.config.max_retry_delay);
+                        tokio::time::sleep(backoff.next_delay()).await;
                     } else {
                         return Err(IngestionError::HttpError(e));
                     }
@@ -201,21 +215,79 @@ impl ResilientHttpClient {
     }
 
     /// Gets the number of available permits
+    #[allow(dead_code)]
     pub fn available_permits(&self) -> usize {
         self.semaphore.available_permits()
     }
+
+    /// Gets the concurrency semaphore for a source (`None` if uncapped)
+    pub fn source_semaphore(&self, source_id: &str) -> Option<Arc<Semaphore>> {
+        self.source_semaphores.get(source_id).cloned()
+    }
+}
+
+type DirectRateLimiter = RateLimiter<NotKeyed, governor::state::InMemoryState, DefaultClock, NoOpMiddleware>;
+
+/// Requests-per-minute rate limiter whose quota can be replaced at runtime
+pub struct ReloadableRateLimiter {
+    limiter: parking_lot::RwLock<Arc<DirectRateLimiter>>,
+    rpm: AtomicU32,
+}
+
+impl ReloadableRateLimiter {
+    /// Creates a limiter allowing `rpm` requests per minute (60 if zero)
+    pub fn per_minute(rpm: u32) -> Self {
+        Self {
+            limiter: parking_lot::RwLock::new(Arc::new(Self::direct(rpm))),
+            rpm: AtomicU32::new(rpm),
+        }
+    }
+
+    fn direct(rpm: u32) -> DirectRateLimiter {
+        let quota = Quota::per_minute(
+            NonZeroU32::new(rpm).unwrap_or(NonZeroU32::new(60).unwrap())
+        );
+        RateLimiter::direct(quota)
+    }
+
+    /// Replaces the quota (requests already waiting finish on the old one)
+    pub fn set_rpm(&self, rpm: u32) {
+        if self.rpm.swap(rpm, Ordering::Relaxed) != rpm {
+            *self.limiter.write() = Arc::new(Self::direct(rpm));
+        }
+    }
+
+    /// Gets the current requests-per-minute limit
+    #[allow(dead_code)]
+    pub fn rpm(&self) -> u32 {
+        self.rpm.load(Ordering::Relaxed)
+    }
+
+    /// Waits until a request is allowed
+    pub async fn until_ready(&self) {
+        let limiter = self.limiter.read().clone();
+        limiter.until_ready().await;
+    }
 }
 
 /// Source-specific HTTP client with rate limiting and circuit breaker
 pub struct SourceHttpClient {
     /// Resilient base client
     client: Arc<ResilientHttpClient>,
-    /// Source-specific rate limiter
-    rate_limiter: RateLimiter<NotKeyed, governor::state::InMemoryState, DefaultClock, NoOpMiddleware>,
+    /// Source-specific rate limiter (shared by clones)
+    rate_limiter: Arc<ReloadableRateLimiter>,
+    /// Source concurrency cap, acquired before the global semaphore
+    concurrency: Option<Arc<Semaphore>>,
     /// Circuit breaker
     circuit_breaker: Arc<CircuitBreaker>,
     /// Source identifier
     source_id: String,
+    /// User-Agent override (falls back to `HttpClientConfig.user_agent`)
+    user_agent: Option<HeaderValue>,
+    /// `Authorization` header sent with every request
+    authorization: Option<HeaderValue>,
+    /// Errors that are expected API answers rather than source failures
+    soft_error: Option<fn(&IngestionError) -> bool>,
 }
 
 impl SourceHttpClient {
@@ -226,19 +298,53 @@ impl SourceHttpClient {
         rate_limit_rpm: u32,
         circuit_breaker: Arc<CircuitBreaker>,
     ) -> Self {
-        let quota = Quota::per_minute(
-            NonZeroU32::new(rate_limit_rpm).unwrap_or(NonZeroU32::new(60).unwrap())
-        );
-        let rate_limiter = RateLimiter::direct(quota);
-
         Self {
+            concurrency: client.source_semaphore(source_id),
             client,
-            rate_limiter,
+            rate_limiter: Arc::new(ReloadableRateLimiter::per_minute(rate_limit_rpm)),
             circuit_breaker,
             source_id: source_id.to_string(),
+            user_agent: None,
+            authorization: None,
+            soft_error: None,
         }
     }
 
+    /// Overrides the User-Agent sent for this source
+    pub fn with_user_agent(mut self, user_agent: Option<String>) -> Self {
+        self.user_agent = user_agent.and_then(|ua| match HeaderValue::from_str(&ua) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                warn!(source = %self.source_id, error = %e, "Invalid User-Agent override, using default");
+                None
+            }
+        });
+        self
+    }
+
+    /// Sends `Authorization: Bearer <token>` with this source's requests
+    pub fn with_bearer_auth(mut self, token: &str) -> Self {
+        self.authorization = match HeaderValue::from_str(&format!("Bearer {}", token)) {
+            Ok(mut value) => {
+                value.set_sensitive(true);
+                Some(value)
+            }
+            Err(e) => {
+                warn!(source = %self.source_id, error = %e, "Invalid bearer token, sending requests unauthenticated");
+                None
+            }
+        };
+        self
+    }
+
+    /// Marks errors that should not count as circuit breaker failures
+    ///
+    /// Matching errors are still returned to the caller.
+    pub fn with_soft_errors(mut self, is_soft: fn(&IngestionError) -> bool) -> Self {
+        self.soft_error = Some(is_soft);
+        self
+    }
+
     /// Executes a GET request with all protections
     pub async fn get(&self, url: &str) -> Result<Response> {
         self.execute_with_protection(|| {
@@ -275,8 +381,22 @@ impl SourceHttpClient {
         self.rate_limiter.until_ready().await;
 
         // Build and execute request
-        let request = build_request()
-            .map_err(|e| IngestionError::HttpError(e))?;
+        let mut request = build_request()
+            .map_err(IngestionError::HttpError)?;
+        if let Some(ref user_agent) = self.user_agent {
+            request.headers_mut().insert(USER_AGENT, user_agent.clone());
+        }
+        if let Some(ref authorization) = self.authorization {
+            request.headers_mut().insert(AUTHORIZATION, authorization.clone());
+        }
+
+        // Wait for a source slot before taking a global one, so a capped
+        // source never holds global permits while queued
+        let _permit = match self.concurrency {
+            Some(ref semaphore) => Some(semaphore.acquire().await
+                .map_err(|_| IngestionError::ConnectionLost("Semaphore closed".to_string()))?),
+            None => None,
+        };
 
         match self.client.execute(request).await {
             Ok(response) => {
@@ -284,18 +404,35 @@ impl SourceHttpClient {
                 Ok(response)
             }
             Err(e) => {
-                self.circuit_breaker.record_failure();
+                if self.soft_error.is_some_and(|is_soft| is_soft(&e)) {
+                    debug!(source = %self.source_id, error = %e, "Soft error, not counted as failure");
+                } else {
+                    self.circuit_breaker.record_failure();
+                }
                 Err(e)
             }
         }
     }
 
     /// Gets the source ID
+    #[allow(dead_code)]
     pub fn source_id(&self) -> &str {
         &self.source_id
     }
 
+    /// Applies a new requests-per-minute limit
+    pub fn set_rate_limit(&self, rate_limit_rpm: u32) {
+        self.rate_limiter.set_rpm(rate_limit_rpm);
+    }
+
+    /// Gets the current requests-per-minute limit
+    #[allow(dead_code)]
+    pub fn rate_limit_rpm(&self) -> u32 {
+        self.rate_limiter.rpm()
+    }
+
     /// Checks if requests are currently allowed
+    #[allow(dead_code)]
     pub fn is_available(&self) -> bool {
         self.circuit_breaker.allow_request()
     }
@@ -303,14 +440,16 @@ impl SourceHttpClient {
 
 impl Clone for SourceHttpClient {
     fn clone(&self) -> Self {
-        // Rate limiter is not clone, so we create a new one with same config
-        // This is fine for cloning into tasks
-        let quota = Quota::per_minute(NonZeroU32::new(60).unwrap());
+        // Clones share the rate limiter, so reloads reach every copy
         Self {
             client: self.client.clone(),
-            rate_limiter: RateLimiter::direct(quota),
+            rate_limiter: self.rate_limiter.clone(),
+            concurrency: self.concurrency.clone(),
             circuit_breaker: self.circuit_breaker.clone(),
             source_id: self.source_id.clone(),
+            user_agent: self.user_agent.clone(),
+            authorization: self.authorization.clone(),
+            soft_error: self.soft_error,
         }
     }
 }
@@ -318,6 +457,19 @@ impl Clone for SourceHttpClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::circuit_breaker::CircuitBreakerConfig;
+    use std::time::Instant;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn source_client(client: Arc<ResilientHttpClient>, source_id: &str) -> SourceHttpClient {
+        SourceHttpClient::new(
+            client,
+            source_id,
+            600,
+            Arc::new(CircuitBreaker::new(source_id, CircuitBreakerConfig::default())),
+        )
+    }
 
     #[test]
     fn test_config_defaults() {
@@ -345,4 +497,109 @@ mod tests {
         assert!(!ResilientHttpClient::is_retryable_status(StatusCode::NOT_FOUND));
         assert!(!ResilientHttpClient::is_retryable_status(StatusCode::UNAUTHORIZED));
     }
+
+    #[tokio::test]
+    async fn test_source_user_agent_override() {
+        let server = MockServer::start().await;
+        let default_ua = HttpClientConfig::default().user_agent;
+
+        Mock::given(method("GET"))
+            .and(path("/custom"))
+            .and(header("user-agent", "neuro-bot/1.0 (by /u/neuro)"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/default"))
+            .and(header("user-agent", default_ua.as_str()))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Arc::new(ResilientHttpClient::with_defaults().unwrap());
+        let custom = source_client(client.clone(), "reddit")
+            .with_user_agent(Some("neuro-bot/1.0 (by /u/neuro)".to_string()));
+        let default = source_client(client, "newsapi");
+
+        let response = custom.get(&format!("{}/custom", server.uri())).await.unwrap();
+        assert!(response.status().is_success());
+        let response = default.get(&format!("{}/default", server.uri())).await.unwrap();
+        assert!(response.status().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_source_bearer_auth() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(header("authorization", "Bearer secret-token"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Arc::new(ResilientHttpClient::with_defaults().unwrap());
+        let source = source_client(client, "x_api").with_bearer_auth("secret-token");
+
+        let response = source.get(&format!("{}/search", server.uri())).await.unwrap();
+        assert!(response.status().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_source_concurrency_cap() {
+        let server = MockServer::start().await;
+        let delay = Duration::from_millis(200);
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(ResponseTemplate::new(200).set_delay(delay))
+            .mount(&server)
+            .await;
+
+        let config = HttpClientConfig {
+            max_concurrent_requests: 10,
+            source_concurrency: HashMap::from([("x_api".to_string(), 2)]),
+            ..Default::default()
+        };
+        let client = Arc::new(ResilientHttpClient::new(config).unwrap());
+        let capped = source_client(client.clone(), "x_api");
+        assert!(source_client(client, "monad").concurrency.is_none());
+
+        let url = format!("{}/slow", server.uri());
+        let started = Instant::now();
+        let requests: Vec<_> = (0..6)
+            .map(|_| {
+                let capped = capped.clone();
+                let url = url.clone();
+                tokio::spawn(async move { capped.get(&url).await.map(|r| r.status()) })
+            })
+            .collect();
+
+        // Midway through the first wave only two requests have reached the server
+        tokio::time::sleep(delay / 2).await;
+        assert!(server.received_requests().await.unwrap().len() <= 2);
+
+        for request in requests {
+            assert!(request.await.unwrap().unwrap().is_success());
+        }
+        // Six requests, two at a time, take at least three round trips
+        assert!(started.elapsed() >= delay * 3, "took {:?}", started.elapsed());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_reload_applies_new_quota() {
+        let limiter = ReloadableRateLimiter::per_minute(1);
+        limiter.until_ready().await;
+
+        // The single request per minute is used up
+        let wait = tokio::time::timeout(Duration::from_millis(100), limiter.until_ready()).await;
+        assert!(wait.is_err());
+
+        limiter.set_rpm(600);
+        assert_eq!(limiter.rpm(), 600);
+        tokio::time::timeout(Duration::from_millis(100), limiter.until_ready())
+            .await
+            .expect("reloaded quota should allow a request");
+    }
 }
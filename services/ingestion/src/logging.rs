@@ -0,0 +1,301 @@
+//! Log Formatting and Throttling
+//!
+//! `--json-logs` emits one compact JSON object per line (for log shippers);
+//! `--pretty` pretty-prints the same objects for reading locally.
+//!
+//! `--profile` (built with the `tokio-console` feature and
+//! `--cfg tokio_unstable`) adds the `console_subscriber` layer next to the
+//! log layer. The `EnvFilter` only
+//! filters the log layer, so the console still sees tokio's runtime spans.
+//!
+//! `ErrorLogThrottle` keeps a flapping source from logging the same error on
+//! every harvest: the first failure is logged, repeats are counted and
+//! summarized at most once per interval.
+
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tracing::{info, warn, Dispatch, Event, Subscriber};
+use tracing_subscriber::fmt::format::{Format, Json, JsonFields, Writer};
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::fmt::{self, FmtContext, FormatEvent, FormatFields, MakeWriter};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{reload, EnvFilter, Layer, Registry};
+
+use crate::reload::LogFilterHandle;
+
+/// Whether this build can serve tokio-console (`--profile`)
+pub const CONSOLE_AVAILABLE: bool = cfg!(all(feature = "tokio-console", tokio_unstable));
+
+/// Builds the subscriber stack: the log layer (JSON or text) behind a
+/// reloadable `filter`, plus the tokio-console layer when `profile` is set
+/// and the build supports it
+pub fn build_subscriber(
+    filter: EnvFilter,
+    json_output: bool,
+    pretty_json: bool,
+    writer: BoxMakeWriter,
+    profile: bool,
+) -> (Dispatch, LogFilterHandle) {
+    let (filter, handle) = reload::Layer::new(filter);
+    let log_layer: Box<dyn Layer<Registry> + Send + Sync> = if json_output {
+        json_layer(pretty_json, writer)
+    } else {
+        fmt::layer().with_target(true).with_thread_ids(true).with_writer(writer).boxed()
+    };
+    let subscriber = tracing_subscriber::registry().with(log_layer.with_filter(filter));
+
+    #[cfg(all(feature = "tokio-console", tokio_unstable))]
+    let subscriber = subscriber.with(profile.then(console_subscriber::spawn));
+    #[cfg(not(all(feature = "tokio-console", tokio_unstable)))]
+    let _ = profile;
+
+    (Dispatch::new(subscriber), handle)
+}
+
+/// Builds the JSON log layer writing to `writer`, pretty-printed or compact
+pub fn json_layer<S, W>(pretty: bool, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    if pretty {
+        fmt::layer()
+            .fmt_fields(JsonFields::new())
+            .event_format(PrettyJson(fmt::format().json()))
+            .with_writer(writer)
+            .boxed()
+    } else {
+        fmt::layer().json().with_writer(writer).boxed()
+    }
+}
+
+/// Deduplicates repeated errors per `(source, error code)`
+pub struct ErrorLogThrottle {
+    summary_interval: Duration,
+    failures: Mutex<HashMap<(String, String), RepeatedError>>,
+}
+
+struct RepeatedError {
+    first_seen: Instant,
+    last_logged: Instant,
+    total: u64,
+    suppressed: u64,
+}
+
+impl ErrorLogThrottle {
+    pub fn new(summary_interval: Duration) -> Self {
+        Self {
+            summary_interval,
+            failures: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Logs a failure of `source`, unless the same error was logged within
+    /// the summary interval
+    pub fn warn(&self, source: &str, code: &str, error: &dyn std::fmt::Display, message: &str) {
+        let now = Instant::now();
+        let mut failures = self.failures.lock();
+        let key = (source.to_string(), code.to_string());
+
+        let Some(repeated) = failures.get_mut(&key) else {
+            failures.insert(key, RepeatedError {
+                first_seen: now,
+                last_logged: now,
+                total: 1,
+                suppressed: 0,
+            });
+            warn!(source = %source, code = %code, error = %error, "{}", message);
+            return;
+        };
+
+        repeated.total += 1;
+        if now.duration_since(repeated.last_logged) < self.summary_interval {
+            repeated.suppressed += 1;
+            return;
+        }
+
+        warn!(
+            source = %source,
+            code = %code,
+            error = %error,
+            count = repeated.total,
+            suppressed = repeated.suppressed,
+            failing_for_secs = now.duration_since(repeated.first_seen).as_secs(),
+            "{} (still failing, {} times since first failure)",
+            message,
+            repeated.total,
+        );
+        repeated.last_logged = now;
+        repeated.suppressed = 0;
+    }
+
+    /// Clears the failures of `source` after a success, noting the recovery
+    pub fn recovered(&self, source: &str) {
+        let mut failures = self.failures.lock();
+        let mut total = 0;
+        failures.retain(|(failed_source, _), repeated| {
+            if failed_source != source {
+                return true;
+            }
+            total += repeated.total;
+            false
+        });
+        if total > 0 {
+            info!(source = %source, failures = total, "Source recovered");
+        }
+    }
+}
+
+/// Formats events like `fmt::format().json()`, pretty-printed
+struct PrettyJson(Format<Json>);
+
+impl<S, N> FormatEvent<S, N> for PrettyJson
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'w> FormatFields<'w> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> std::fmt::Result {
+        let mut line = String::new();
+        self.0.format_event(ctx, Writer::new(&mut line), event)?;
+
+        match serde_json::from_str::<serde_json::Value>(&line) {
+            Ok(value) => {
+                let pretty = serde_json::to_string_pretty(&value).map_err(|_| std::fmt::Error)?;
+                writeln!(writer, "{}", pretty)
+            }
+            // Not expected, but never lose a log line
+            Err(_) => writer.write_str(&line),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::Arc;
+
+    /// Writer appending to a shared buffer
+    #[derive(Clone, Default)]
+    struct Capture(Arc<parking_lot::Mutex<Vec<u8>>>);
+
+    impl io::Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn log_with(pretty: bool) -> String {
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::registry().with(json_layer(pretty, move || writer.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(source = "newsapi", events = 3, "Harvest complete");
+        });
+        let output = capture.0.lock().clone();
+        String::from_utf8(output).unwrap()
+    }
+
+    fn log_errors(throttle: &ErrorLogThrottle, capture: &Capture, count: usize) {
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::registry().with(json_layer(false, move || writer.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            for _ in 0..count {
+                throttle.warn("newsapi", "http", &"connection refused", "News fetch failed");
+            }
+        });
+    }
+
+    #[test]
+    fn test_repeated_errors_are_throttled() {
+        let capture = Capture::default();
+        let throttle = ErrorLogThrottle::new(Duration::from_millis(200));
+
+        log_errors(&throttle, &capture, 100);
+        std::thread::sleep(Duration::from_millis(250));
+        log_errors(&throttle, &capture, 100);
+
+        let output = String::from_utf8(capture.0.lock().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        // First occurrence, then one summary after the interval
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["fields"]["message"], "News fetch failed");
+        assert_eq!(lines[1]["fields"]["count"], 101);
+        assert_eq!(lines[1]["fields"]["suppressed"], 99);
+    }
+
+    #[test]
+    fn test_pretty_and_compact_json_logs() {
+        let compact = log_with(false);
+        let pretty = log_with(true);
+
+        assert_eq!(compact.trim_end().lines().count(), 1);
+        assert!(pretty.trim_end().lines().count() > 1);
+        assert!(pretty.contains("\n  \""));
+
+        let compact: serde_json::Value = serde_json::from_str(&compact).unwrap();
+        let pretty: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(compact["fields"], pretty["fields"]);
+        assert_eq!(pretty["fields"]["message"], "Harvest complete");
+        assert_eq!(pretty["fields"]["events"], 3);
+        assert_eq!(compact["level"], pretty["level"]);
+    }
+
+    /// Logs through a `build_subscriber` stack, reloading the filter from
+    /// `info` to `debug` halfway, and returns the output
+    fn log_through_stack(profile: bool) -> String {
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let (dispatch, handle) = build_subscriber(
+            EnvFilter::new("info"),
+            true,
+            false,
+            BoxMakeWriter::new(move || writer.clone()),
+            profile,
+        );
+
+        tracing::dispatcher::with_default(&dispatch, || {
+            tracing::info!("kept");
+            tracing::debug!("filtered");
+            handle.reload(EnvFilter::new("debug")).unwrap();
+            tracing::debug!("after reload");
+        });
+
+        let output = capture.0.lock().clone();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_subscriber_stack_filters_logs_and_reloads() {
+        let output = log_through_stack(false);
+        assert!(output.contains("kept"));
+        assert!(!output.contains("filtered"));
+        assert!(output.contains("after reload"));
+    }
+
+    /// The console layer sits beside the filtered log layer, not behind it
+    #[cfg(all(feature = "tokio-console", tokio_unstable))]
+    #[tokio::test]
+    async fn test_console_layer_leaves_log_filtering_alone() {
+        let output = log_through_stack(true);
+        assert!(output.contains("kept"));
+        assert!(!output.contains("filtered"));
+        assert!(output.contains("after reload"));
+    }
+}
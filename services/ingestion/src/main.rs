@@ -15,7 +15,9 @@
 //! - Prometheus metrics per stage
 //! - Message bus output (Redis Streams / NATS)
 
+mod admin;
 mod append_log;
+mod audit;
 mod checkpoint;
 mod circuit_breaker;
 mod config;
@@ -23,24 +25,32 @@ mod dedup;
 mod error;
 mod harvester;
 mod http_client;
+mod logging;
 pub mod message_bus;
 pub mod metrics;
 pub mod pipeline;
+mod reload;
+mod retry;
 pub mod schemas;
+mod shutdown;
 mod sources;
 mod storage;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::signal;
 use tokio::sync::broadcast;
-use tracing::{info, error};
-use tracing_subscriber::{fmt, EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+use tracing::{info, error, warn};
+use tracing_subscriber::{fmt::writer::BoxMakeWriter, EnvFilter, util::SubscriberInitExt};
 
+use crate::audit::AuditLogger;
 use crate::checkpoint::parse_since;
-use crate::config::Config;
-use crate::harvester::Harvester;
+use crate::config::{parse_rate_limit_overrides, Config};
+use crate::harvester::{write_ndjson, Harvester};
+use crate::reload::{ConfigReloader, LogFilterHandle};
+use crate::sources::SourceId;
 
 /// NEURO Ingestion Service - High-speed market data harvesting
 #[derive(Parser, Debug)]
@@ -59,6 +69,29 @@ struct Cli {
     /// Output logs as JSON
     #[arg(long, default_value = "false", global = true)]
     json_logs: bool,
+
+    /// Pretty-printed JSON logs (for reading locally; implies JSON logs)
+    #[arg(long, global = true, conflicts_with = "compact")]
+    pretty: bool,
+
+    /// Compact single-line JSON logs (for prod; implies JSON logs)
+    #[arg(long, global = true)]
+    compact: bool,
+
+    /// Serve runtime metrics to tokio-console (needs the `tokio-console`
+    /// feature and `RUSTFLAGS="--cfg tokio_unstable"`)
+    #[arg(long, global = true)]
+    profile: bool,
+
+    /// Disable dedup for every source, emitting repeated events (for
+    /// debugging missing events; dedup state is left untouched)
+    #[arg(long, global = true)]
+    no_dedup: bool,
+
+    /// Keep only items in this language (ISO 639-1 code, like `en`;
+    /// overrides LANGUAGE_FILTER)
+    #[arg(long, global = true)]
+    language: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -68,6 +101,10 @@ enum Commands {
         /// Run continuously (daemon mode)
         #[arg(short, long, default_value = "true")]
         daemon: bool,
+
+        /// Per-source rate limit overrides in requests/minute (e.g. "newsapi=30,x_api=15")
+        #[arg(long)]
+        rate_limit: Option<String>,
     },
 
     /// Start the pipeline service (fetch → normalize → enrich → embed → publish)
@@ -83,6 +120,18 @@ enum Commands {
         /// Enable embedding stage
         #[arg(long, default_value = "false")]
         embed: bool,
+
+        /// Embedding model name (overrides PIPELINE_EMBEDDING_MODEL)
+        #[arg(long)]
+        embedding_model: Option<String>,
+
+        /// Expected embedding dimension; other lengths are dropped (overrides PIPELINE_EMBEDDING_DIM)
+        #[arg(long)]
+        embedding_dim: Option<usize>,
+
+        /// Per-source rate limit overrides in requests/minute (e.g. "newsapi=30,x_api=15")
+        #[arg(long)]
+        rate_limit: Option<String>,
     },
 
     /// Harvest data from specific sources
@@ -91,11 +140,13 @@ enum Commands {
         #[arg(short, long, default_value = "all")]
         source: String,
 
-        /// Fetch data since this duration ago (e.g., "1h", "30m", "2d")
-        #[arg(long)]
-        since: Option<String>,
+        /// Fetch data since this duration ago (e.g., "1h", "30m", "2d"), or
+        /// since the source's last checkpointed fetch with "checkpoint"
+        /// (falling back to 1h for sources without one)
+        #[arg(long, default_value = checkpoint::SINCE_CHECKPOINT)]
+        since: String,
 
-        /// Maximum number of items to fetch
+        /// Maximum number of items to fetch (across all sources with `--source all`)
         #[arg(short = 'n', long)]
         limit: Option<u32>,
 
@@ -103,19 +154,115 @@ enum Commands {
         #[arg(short, long)]
         query: Option<String>,
 
-        /// Output format (json, table, summary)
+        /// Output format (json, ndjson, table, summary)
+        ///
+        /// `ndjson` streams one compact JSON event per line as sources
+        /// respond, with logs moved to stderr. With `--limit`, the newest
+        /// events are written once every source has responded.
         #[arg(short, long, default_value = "summary")]
         output: String,
     },
 
+    /// Consume published events from the message bus into Postgres/Redis
+    Consume {
+        /// Consumer group name
+        #[arg(long, default_value = "storage")]
+        group: String,
+
+        /// Consumer name within the group
+        #[arg(long, default_value = "storage-1")]
+        name: String,
+
+        /// Maximum number of messages read per poll
+        #[arg(long, default_value = "100")]
+        batch_size: usize,
+    },
+
+    /// Re-drive events already on the bus through the pipeline into another stream
+    Reprocess {
+        /// Stream id to start from, inclusive ("0" for the start of the stream)
+        #[arg(long, default_value = "0")]
+        from_id: String,
+
+        /// Maximum number of events to reprocess (default: until the end of the stream)
+        #[arg(short = 'n', long)]
+        count: Option<usize>,
+
+        /// Stream to publish reprocessed events to
+        #[arg(long)]
+        target_stream: String,
+
+        /// Copy events to the target stream as stored, skipping the pipeline
+        #[arg(long)]
+        raw: bool,
+    },
+
+    /// Replay normalized events from the append log through the pipeline
+    Replay {
+        /// Source to replay (default: all sources)
+        #[arg(short, long)]
+        source: Option<String>,
+
+        /// Only events logged since this duration ago (e.g., "1h", "30m", "2d")
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Maximum number of log entries read, oldest first
+        #[arg(short = 'n', long, default_value = "1000")]
+        limit: usize,
+
+        /// Keep each event's original ingested_at/data_timestamp (no
+        /// processing times are stamped) and flag its payload `replayed`
+        #[arg(long)]
+        preserve_timestamps: bool,
+
+        /// Stream to publish to (default: the configured stream)
+        #[arg(long)]
+        target_stream: Option<String>,
+    },
+
     /// Show status of sources and checkpoints
     Status,
 
+    /// Print a one-line summary of recent append log entries
+    InspectLog {
+        /// Source to inspect (default: all sources)
+        #[arg(short, long)]
+        source: Option<String>,
+
+        /// Only entries written since this duration ago (e.g., "1h", "30m", "2d")
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Maximum number of entries shown (the newest matching ones, printed
+        /// oldest first)
+        #[arg(short = 'n', long, default_value = "100")]
+        limit: usize,
+
+        /// Only print entries whose payload contains this text
+        #[arg(short, long)]
+        grep: Option<String>,
+    },
+
+    /// Print current metric values as a table
+    ///
+    /// Reads the running service's metrics endpoint, or with `--harvest`
+    /// runs one harvest cycle in-process and prints its metrics.
+    Metrics {
+        /// Run one harvest cycle here instead of querying the running service
+        #[arg(long)]
+        harvest: bool,
+    },
+
     /// Reset checkpoints for a source
     Reset {
         /// Source to reset (or "all")
         #[arg(short, long)]
         source: String,
+
+        /// Also clear the source's dedup keys so items are re-ingested
+        #[arg(long)]
+        with_dedup: bool,
     },
 }
 
@@ -125,21 +272,25 @@ fn generate_correlation_id() -> String {
 }
 
 /// Sets up structured logging with tracing
-fn setup_logging(log_level: &str, json_output: bool) {
+///
+/// Returns a handle for swapping the filter when config is reloaded.
+fn setup_logging(log_level: &str, json_output: bool, pretty_json: bool, to_stderr: bool, profile: bool) -> LogFilterHandle {
     let filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| EnvFilter::new(log_level));
-
-    if json_output {
-        tracing_subscriber::registry()
-            .with(filter)
-            .with(fmt::layer().json())
-            .init();
+    let writer = if to_stderr {
+        BoxMakeWriter::new(std::io::stderr)
     } else {
-        tracing_subscriber::registry()
-            .with(filter)
-            .with(fmt::layer().with_target(true).with_thread_ids(true))
-            .init();
+        BoxMakeWriter::new(std::io::stdout)
+    };
+
+    let (dispatch, handle) = logging::build_subscriber(filter, json_output, pretty_json, writer, profile);
+    dispatch.init();
+
+    if profile && !logging::CONSOLE_AVAILABLE {
+        warn!("--profile ignored: build with --features tokio-console and RUSTFLAGS=\"--cfg tokio_unstable\"");
     }
+
+    handle
 }
 
 /// Handles graceful shutdown on SIGTERM/SIGINT
@@ -179,8 +330,10 @@ async fn shutdown_signal(shutdown_tx: broadcast::Sender<()>) {
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    // Setup logging
-    setup_logging(&cli.log_level, cli.json_logs);
+    // Setup logging (kept off stdout while it carries ndjson events)
+    let ndjson_output = matches!(&cli.command, Commands::Harvest { output, .. } if output == "ndjson");
+    let json_logs = cli.json_logs || cli.pretty || cli.compact;
+    let log_filter = setup_logging(&cli.log_level, json_logs, cli.pretty, ndjson_output, cli.profile);
 
     // Generate session correlation ID
     let correlation_id = generate_correlation_id();
@@ -192,8 +345,16 @@ async fn main() -> Result<()> {
     );
 
     // Load configuration
-    let config = Config::load()?;
+    let mut config = Config::load()?;
+    if cli.language.is_some() {
+        config.language_filter = cli.language;
+    }
     config.validate()?;
+    config.no_dedup = cli.no_dedup;
+    dedup::set_dedup_hash(config.dedup_hash);
+    if let Some(policy) = config.dedup_canonicalization {
+        dedup::set_dedup_canonicalization(policy);
+    }
     
     info!(
         nadfun_api = %config.nadfun_api_url,
@@ -207,41 +368,151 @@ async fn main() -> Result<()> {
     let (shutdown_tx, _) = broadcast::channel::<()>(1);
 
     match cli.command {
-        Commands::Run { daemon } => {
-            run_daemon(config, correlation_id, shutdown_tx, daemon).await?;
+        Commands::Run { daemon, rate_limit } => {
+            let overrides = apply_rate_limits(&mut config, rate_limit.as_deref())?;
+            let reloader = ConfigReloader::new(log_filter).with_rate_limit_overrides(overrides);
+            run_daemon(config, correlation_id, shutdown_tx, reloader, daemon).await?;
         }
 
-        Commands::Pipeline { channel_capacity, enrich, embed } => {
-            run_pipeline(config, correlation_id, shutdown_tx, channel_capacity, enrich, embed).await?;
+        Commands::Pipeline { channel_capacity, enrich, embed, embedding_model, embedding_dim, rate_limit } => {
+            let overrides = apply_rate_limits(&mut config, rate_limit.as_deref())?;
+            let reloader = ConfigReloader::new(log_filter).with_rate_limit_overrides(overrides);
+            if embedding_model.is_some() {
+                config.pipeline_embedding_model = embedding_model;
+            }
+            if embedding_dim.is_some() {
+                config.pipeline_embedding_dim = embedding_dim;
+            }
+            run_pipeline(config, correlation_id, shutdown_tx, reloader, channel_capacity, enrich, embed).await?;
         }
 
         Commands::Harvest { source, since, limit, query, output } => {
             harvest_once(config, correlation_id, &source, since, limit, query, &output).await?;
         }
 
+        Commands::Consume { group, name, batch_size } => {
+            consume_to_storage(config, shutdown_tx, &group, &name, batch_size).await?;
+        }
+
+        Commands::Reprocess { from_id, count, target_stream, raw } => {
+            reprocess_stream(config, correlation_id, &from_id, count, &target_stream, raw).await?;
+        }
+
+        Commands::Replay { source, since, limit, preserve_timestamps, target_stream } => {
+            let options = ReplayOptions { source, since, limit, preserve_timestamps, target_stream };
+            replay_log(config, correlation_id, options).await?;
+        }
+
         Commands::Status => {
             show_status(config).await?;
         }
 
-        Commands::Reset { source } => {
-            reset_checkpoint(config, &source).await?;
+        Commands::InspectLog { source, since, limit, grep } => {
+            inspect_log(config, source.as_deref(), since.as_deref(), limit, grep.as_deref()).await?;
+        }
+
+        Commands::Metrics { harvest } => {
+            show_metrics(config, correlation_id, harvest).await?;
+        }
+
+        Commands::Reset { source, with_dedup } => {
+            reset_checkpoint(config, correlation_id, &source, with_dedup).await?;
         }
     }
 
     Ok(())
 }
 
+/// Applies `--rate-limit` overrides on top of the loaded configuration
+///
+/// Returns the overrides so config reloads can re-apply them.
+fn apply_rate_limits(config: &mut Config, rate_limit: Option<&str>) -> Result<HashMap<SourceId, u32>> {
+    let Some(rate_limit) = rate_limit else {
+        return Ok(HashMap::new());
+    };
+
+    let overrides = parse_rate_limit_overrides(rate_limit)?;
+    for (source, rpm) in &overrides {
+        info!(source = %source, rpm, "Overriding source rate limit");
+    }
+    config.apply_rate_limit_overrides(&overrides);
+    Ok(overrides)
+}
+
+/// Starts the SIGHUP handler that reloads log level and rate limits
+fn spawn_config_reload(
+    reloader: ConfigReloader,
+    harvester: &Arc<Harvester>,
+    shutdown_tx: &broadcast::Sender<()>,
+) {
+    #[cfg(unix)]
+    if let Err(e) = reloader.with_harvester(harvester.clone()).spawn(shutdown_tx.subscribe()) {
+        warn!(error = %e, "Failed to install SIGHUP handler; config reload disabled");
+    }
+
+    #[cfg(not(unix))]
+    let _ = (reloader, harvester, shutdown_tx);
+}
+
+/// Also publishes audit events to `MESSAGE_BUS_AUDIT_STREAM`, when set
+async fn attach_audit_bus(config: &Config, audit: &AuditLogger) -> Result<()> {
+    use crate::message_bus::{MessageBusConfig, create_message_bus};
+
+    let (Some(stream), Some(bus_url)) = (&config.message_bus_audit_stream, config.message_bus_url()) else {
+        return Ok(());
+    };
+
+    let bus_config = MessageBusConfig {
+        stream_name: stream.clone(),
+        max_len: Some(100_000),
+        tls_ca_cert: config.redis_ca_cert.clone(),
+        ..Default::default()
+    };
+    let bus = create_message_bus(config.message_bus_type.parse()?, bus_url, bus_config).await?;
+    audit.set_bus(Arc::from(bus));
+    info!(stream = %stream, "Audit events published to message bus");
+    Ok(())
+}
+
 /// Runs the harvester in daemon mode
 async fn run_daemon(
     config: Config,
     correlation_id: String,
     shutdown_tx: broadcast::Sender<()>,
+    reloader: ConfigReloader,
     daemon: bool,
 ) -> Result<()> {
+    use crate::admin::AdminState;
+    use crate::metrics::start_metrics_server;
+    use std::net::SocketAddr;
+
     // Initialize harvester
-    let harvester = Arc::new(Harvester::new(config, correlation_id.clone()).await?);
+    let harvester = Arc::new(Harvester::new(config.clone(), correlation_id.clone()).await?);
+    spawn_config_reload(reloader, &harvester, &shutdown_tx);
+    attach_audit_bus(&config, &harvester.audit()).await?;
     
     info!("NEURO Ingestion Service initialized");
+    let mode = if daemon { "daemon" } else { "once" };
+    harvester.audit().record(audit::service_started(mode)).await;
+
+    // Start metrics server (with admin endpoints for pausing sources)
+    if config.metrics_enabled {
+        let metrics_addr: SocketAddr = format!("0.0.0.0:{}", config.metrics_port).parse()?;
+        let admin = AdminState {
+            auth_token: config.metrics_auth_token.clone(),
+            switches: harvester.source_switches(),
+            dedup: Some(harvester.dedup_store()),
+            health: harvester.source_health(),
+            max_event_age: config.readyz_max_event_age_secs.map(std::time::Duration::from_secs),
+            audit: Some(harvester.audit()),
+        };
+        tokio::spawn(async move {
+            if let Err(e) = start_metrics_server(metrics_addr, Some(admin)).await {
+                error!(error = %e, "Metrics server failed");
+            }
+        });
+        info!(port = config.metrics_port, "Metrics server started at /metrics");
+    }
 
     // Spawn shutdown handler
     let shutdown_harvester = harvester.clone();
@@ -250,6 +521,7 @@ async fn run_daemon(
         
         info!("Shutting down harvester...");
         shutdown_harvester.shutdown().await;
+        shutdown_harvester.audit().record(audit::service_stopped()).await;
         info!("Harvester shutdown complete");
     });
 
@@ -262,9 +534,25 @@ async fn run_daemon(
         }
     } else {
         info!("Running single harvest cycle");
-        if let Err(e) = harvester.run_once().await {
-            error!(error = %e, "Harvest cycle failed");
-            return Err(e);
+        let report = match harvester.run_once().await {
+            Ok(report) => report,
+            Err(e) => {
+                error!(error = %e, "Harvest cycle failed");
+                return Err(e);
+            }
+        };
+
+        println!("\n📊 Harvest Summary");
+        println!("==================");
+        println!("Succeeded: {}", report.succeeded.len());
+        println!("Failed:    {}", report.failed.len());
+        for (source_id, error) in &report.failed {
+            println!("  {}: {}", source_id, error);
+        }
+        println!("Events:    {}", report.total_events);
+
+        if report.is_total_failure() {
+            anyhow::bail!("All {} sources failed", report.failed.len());
         }
     }
 
@@ -280,34 +568,27 @@ async fn harvest_once(
     config: Config,
     correlation_id: String,
     source: &str,
-    since: Option<String>,
+    since: String,
     limit: Option<u32>,
     query: Option<String>,
     output_format: &str,
 ) -> Result<()> {
     use crate::sources::FetchOptions;
-    use chrono::{Duration, Utc};
 
     info!(
         source = %source,
-        since = ?since,
+        since = %since,
         limit = ?limit,
         query = ?query,
         "Starting harvest"
     );
 
-    // Parse --since duration
-    let since_time = if let Some(since_str) = since {
-        let duration = parse_since(&since_str)?;
-        Some(Utc::now() - duration)
-    } else {
-        // Default: last 1 hour
-        Some(Utc::now() - Duration::hours(1))
-    };
-
     // Create harvester
     let harvester = Harvester::new(config, correlation_id).await?;
 
+    // Resolve --since (a duration, or the saved checkpoint)
+    let since_time = Some(harvester.resolve_since(source, &since).await?);
+
     // Build fetch options
     let options = FetchOptions {
         since: since_time,
@@ -317,6 +598,12 @@ async fn harvest_once(
         filters: std::collections::HashMap::new(),
     };
 
+    if output_format == "ndjson" {
+        let events = harvester.stream_from_source(source, options).await?;
+        write_ndjson(events, std::io::stdout()).await?;
+        return Ok(());
+    }
+
     // Fetch from source(s)
     let results = harvester.fetch_from_source(source, options).await?;
 
@@ -370,6 +657,223 @@ async fn harvest_once(
     Ok(())
 }
 
+/// Consumes events from the message bus into the storage read-model
+async fn consume_to_storage(
+    config: Config,
+    shutdown_tx: broadcast::Sender<()>,
+    group: &str,
+    name: &str,
+    batch_size: usize,
+) -> Result<()> {
+    use crate::message_bus::{MessageBusType, MessageBusConfig, ResilientPublisher, create_message_bus};
+    use crate::pipeline::worker::DeadLetterQueue;
+    use crate::pipeline::DEFAULT_DEAD_LETTER_STREAM;
+    use crate::storage::{BusToStorageConsumer, CacheTtls, DbPoolConfig, Storage};
+
+    let bus_url = config.message_bus_url()
+        .ok_or_else(|| anyhow::anyhow!("Message bus URL not configured (set REDIS_URL or NATS_URL)"))?;
+    let database_url = config.database_url.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("DATABASE_URL not configured"))?;
+
+    let bus_type: MessageBusType = config.message_bus_type.parse()?;
+
+    info!(
+        bus_type = ?bus_type,
+        stream = %config.message_bus_stream,
+        group,
+        name,
+        "Connecting to message bus"
+    );
+
+    let bus_config = MessageBusConfig {
+        stream_name: config.message_bus_stream.clone(),
+        tls_ca_cert: config.redis_ca_cert.clone(),
+        ..Default::default()
+    };
+    let message_bus = create_message_bus(bus_type, bus_url, bus_config).await?;
+    let consumer = message_bus.subscribe(group, name).await?;
+
+    let storage = Storage::new(
+        database_url,
+        config.redis_url.as_deref(),
+        config.redis_ca_cert.as_deref(),
+        DbPoolConfig::from_config(&config),
+    )
+    .await?
+    .with_cache_ttls(CacheTtls::from_config(&config));
+    if config.run_migrations {
+        storage.run_migrations().await?;
+    }
+
+    let shutdown_rx = shutdown_tx.subscribe();
+    tokio::spawn(shutdown_signal(shutdown_tx));
+
+    // Undecodable events go to the pipeline's dead-letter stream
+    let publisher = Arc::new(ResilientPublisher::new(message_bus, 3, std::time::Duration::from_millis(100)));
+    let dead_letter_stream = config
+        .pipeline_dead_letter_stream
+        .as_deref()
+        .unwrap_or(DEFAULT_DEAD_LETTER_STREAM);
+
+    BusToStorageConsumer::new(consumer, Arc::new(storage))
+        .with_batch_size(batch_size)
+        .with_dead_letter(DeadLetterQueue::new(publisher.clone(), dead_letter_stream))
+        .run(shutdown_rx)
+        .await?;
+
+    publisher.close().await?;
+    info!("Consumer stopped");
+    Ok(())
+}
+
+/// Replays the configured stream through a fresh pipeline into `target_stream`
+/// (or, with `raw`, copies the stored events there unchanged)
+async fn reprocess_stream(
+    config: Config,
+    correlation_id: String,
+    from_id: &str,
+    count: Option<usize>,
+    target_stream: &str,
+    raw: bool,
+) -> Result<()> {
+    use crate::message_bus::{
+        MessageBusType, MessageBusConfig, ResilientPublisher, create_message_bus, republish_raw,
+    };
+    use crate::pipeline::{Pipeline, PipelineConfig};
+
+    if target_stream == config.message_bus_stream {
+        anyhow::bail!(
+            "Target stream must differ from the source stream ({})",
+            config.message_bus_stream
+        );
+    }
+
+    let bus_url = config.message_bus_url()
+        .ok_or_else(|| anyhow::anyhow!("Message bus URL not configured (set REDIS_URL or NATS_URL)"))?;
+    let bus_type: MessageBusType = config.message_bus_type.parse()?;
+
+    info!(
+        bus_type = ?bus_type,
+        source_stream = %config.message_bus_stream,
+        target_stream,
+        from_id,
+        count = ?count,
+        raw,
+        "Reprocessing stream"
+    );
+
+    let source_config = MessageBusConfig {
+        stream_name: config.message_bus_stream.clone(),
+        tls_ca_cert: config.redis_ca_cert.clone(),
+        ..Default::default()
+    };
+    let source_bus = create_message_bus(bus_type, bus_url, source_config.clone()).await?;
+    let target_config = MessageBusConfig {
+        stream_name: target_stream.to_string(),
+        ..source_config
+    };
+    let target_bus = create_message_bus(bus_type, bus_url, target_config).await?;
+    let mut replay = source_bus.replay(from_id).await?;
+
+    if raw {
+        let publisher = ResilientPublisher::new(target_bus, 3, std::time::Duration::from_millis(100));
+        let copied = republish_raw(replay.as_mut(), &publisher, target_stream, count).await?;
+        publisher.close().await?;
+        source_bus.close().await?;
+
+        println!("Copied {} events into {}", copied, target_stream);
+        return Ok(());
+    }
+
+    // No priority stream: reprocessed events shouldn't re-alert
+    let pipeline_config = PipelineConfig::from_config(&config);
+    let drain_timeout = pipeline_config.shutdown_deadline;
+    let pipeline = Pipeline::new(pipeline_config, target_bus, None).await?;
+
+    let submitted = pipeline.resubmit_from(replay.as_mut(), count, &correlation_id).await?;
+
+    if let Err(e) = pipeline.drain(drain_timeout).await {
+        warn!(error = %e, "Reprocessed events may not all be published");
+    }
+    pipeline.shutdown().await;
+    source_bus.close().await?;
+
+    println!("Reprocessed {} events into {}", submitted, target_stream);
+    Ok(())
+}
+
+/// What `replay_log` replays, from the `replay` command's arguments
+struct ReplayOptions {
+    source: Option<String>,
+    since: Option<String>,
+    limit: usize,
+    preserve_timestamps: bool,
+    target_stream: Option<String>,
+}
+
+/// Replays normalized events from the append log through a fresh pipeline
+async fn replay_log(config: Config, correlation_id: String, options: ReplayOptions) -> Result<()> {
+    use crate::append_log::create_append_log;
+    use crate::message_bus::{MessageBusType, MessageBusConfig, create_message_bus};
+    use crate::pipeline::{Pipeline, PipelineConfig, PipelineItem};
+
+    let ReplayOptions { source, since, limit, preserve_timestamps, target_stream } = options;
+    let since_time = since
+        .as_deref()
+        .map(|s| parse_since(s).map(|duration| chrono::Utc::now() - duration))
+        .transpose()?;
+
+    let bus_url = config.message_bus_url()
+        .ok_or_else(|| anyhow::anyhow!("Message bus URL not configured (set REDIS_URL or NATS_URL)"))?;
+    let bus_type: MessageBusType = config.message_bus_type.parse()?;
+    let target_stream = target_stream.unwrap_or_else(|| config.message_bus_stream.clone());
+
+    let log = create_append_log(
+        &config.storage_type,
+        Some(&config.data_dir),
+        config.s3_bucket.as_deref(),
+        config.s3_prefix.as_deref(),
+        config.s3_endpoint_url.as_deref(),
+        config.append_log_granularity,
+    ).await?;
+    let entries = log.list_entries(source.as_deref(), since_time, limit).await?;
+
+    let bus_config = MessageBusConfig {
+        stream_name: target_stream.clone(),
+        tls_ca_cert: config.redis_ca_cert.clone(),
+        ..Default::default()
+    };
+    let bus = create_message_bus(bus_type, bus_url, bus_config).await?;
+
+    let items: Vec<PipelineItem> = entries
+        .iter()
+        .filter_map(|entry| PipelineItem::from_log_entry(entry, &correlation_id, preserve_timestamps))
+        .collect();
+
+    info!(
+        entries = entries.len(),
+        events = items.len(),
+        preserve_timestamps,
+        target_stream = %target_stream,
+        "Replaying append log"
+    );
+
+    // No priority stream: replayed events shouldn't re-alert
+    let pipeline_config = PipelineConfig::from_config(&config);
+    let drain_timeout = pipeline_config.shutdown_deadline;
+    let pipeline = Pipeline::new(pipeline_config, bus, None).await?;
+
+    let replayed = items.len();
+    pipeline.submit_batch(items).await?;
+    if let Err(e) = pipeline.drain(drain_timeout).await {
+        warn!(error = %e, "Replayed events may not all be published");
+    }
+    pipeline.shutdown().await;
+
+    println!("Replayed {} events into {}", replayed, target_stream);
+    Ok(())
+}
+
 /// Shows status of sources and checkpoints
 async fn show_status(config: Config) -> Result<()> {
     use crate::checkpoint::CheckpointManager;
@@ -385,6 +889,46 @@ async fn show_status(config: Config) -> Result<()> {
     println!("  - CryptoPanic: {}", if config.has_cryptopanic() { "✅" } else { "❌ (no API key)" });
     println!("  - X/Twitter:   {}", if config.has_x_api() { "✅" } else { "❌ (no bearer token)" });
 
+    // Show runtime state from a running service (via admin endpoint)
+    println!("\nRuntime State:");
+    match fetch_admin_json::<serde_json::Map<String, serde_json::Value>>(&config, "/admin/sources").await {
+        Ok(states) => {
+            let health = fetch_admin_json::<serde_json::Value>(&config, "/admin/health").await.ok();
+            for source_id in SourceId::ALL {
+                let enabled = states.get(source_id.as_str()).and_then(|v| v.as_bool()).unwrap_or(true);
+                let healthy = health
+                    .as_ref()
+                    .and_then(|h| h["sources"].get(source_id.as_str()))
+                    .and_then(|v| v.as_bool());
+                let health_label = match healthy {
+                    Some(true) => ", healthy",
+                    Some(false) => ", ❌ unhealthy",
+                    None => "",
+                };
+                println!(
+                    "  - {}: {}{}",
+                    source_id,
+                    if enabled { "enabled" } else { "⏸️  paused" },
+                    health_label
+                );
+            }
+        }
+        Err(e) => println!("  Unavailable ({})", e),
+    }
+
+    // Show dedup cache statistics from a running service
+    println!("\nDedup Cache:");
+    match fetch_admin_json::<crate::dedup::DedupStats>(&config, "/admin/dedup").await {
+        Ok(stats) => {
+            println!("  Entries:   {}", stats.entries);
+            println!("  Hits:      {}", stats.hits);
+            println!("  Misses:    {}", stats.misses);
+            println!("  Evictions: {}", stats.evictions);
+            println!("  Hit rate:  {:.1}%", stats.hit_rate() * 100.0);
+        }
+        Err(e) => println!("  Unavailable ({})", e),
+    }
+
     // Show checkpoints
     println!("\nCheckpoints:");
     let checkpoint_mgr = CheckpointManager::new(&config.checkpoint_dir).await?;
@@ -411,21 +955,130 @@ async fn show_status(config: Config) -> Result<()> {
     Ok(())
 }
 
-/// Resets checkpoint for a source
-async fn reset_checkpoint(config: Config, source: &str) -> Result<()> {
+/// Prints a summary line per append log entry, optionally grepping payloads
+async fn inspect_log(
+    config: Config,
+    source: Option<&str>,
+    since: Option<&str>,
+    limit: usize,
+    grep: Option<&str>,
+) -> Result<()> {
+    use crate::append_log::{create_append_log, payload_contains, write_entry_summaries};
+
+    let since_time = since
+        .map(|s| parse_since(s).map(|duration| chrono::Utc::now() - duration))
+        .transpose()?;
+
+    let log = create_append_log(
+        &config.storage_type,
+        Some(&config.data_dir),
+        config.s3_bucket.as_deref(),
+        config.s3_prefix.as_deref(),
+        config.s3_endpoint_url.as_deref(),
+        config.append_log_granularity,
+    ).await?;
+
+    let entries = log
+        .tail_entries(source, since_time, limit, &|entry| payload_contains(entry, grep))
+        .await?;
+    let printed = write_entry_summaries(&entries, None, std::io::stdout().lock())?;
+    println!("\nShown: {} entries", printed);
+    Ok(())
+}
+
+/// Queries a JSON admin endpoint on the local service
+async fn fetch_admin_json<T: serde::de::DeserializeOwned>(config: &Config, path: &str) -> Result<T> {
+    let token = config.metrics_auth_token.as_deref()
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| anyhow::anyhow!("no metrics auth token configured"))?;
+
+    let response = reqwest::Client::new()
+        .get(format!("http://127.0.0.1:{}{}", config.metrics_port, path))
+        .bearer_auth(token)
+        .timeout(std::time::Duration::from_secs(2))
+        .send()
+        .await?
+        .error_for_status()?;
+
+    Ok(response.json().await?)
+}
+
+/// Prints a metrics snapshot from the running service, or from one
+/// in-process harvest cycle
+async fn show_metrics(config: Config, correlation_id: String, harvest: bool) -> Result<()> {
+    let text = if harvest {
+        let harvester = Harvester::new(config, correlation_id).await?;
+        harvester.run_once().await?;
+        harvester.shutdown().await;
+        metrics::try_gather_metrics()?
+    } else {
+        reqwest::Client::new()
+            .get(format!("http://127.0.0.1:{}/metrics", config.metrics_port))
+            .timeout(std::time::Duration::from_secs(2))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| anyhow::anyhow!("metrics endpoint unavailable ({}); use --harvest to run in-process", e))?
+            .text()
+            .await?
+    };
+
+    print!("{}", metrics::format_metrics_table(&text));
+    Ok(())
+}
+
+/// Resets checkpoint for a source, and optionally its dedup keys
+async fn reset_checkpoint(config: Config, correlation_id: String, source: &str, with_dedup: bool) -> Result<()> {
     use crate::checkpoint::CheckpointManager;
 
+    let source_id: Option<SourceId> = match source {
+        "all" => None,
+        source => Some(source.parse()?),
+    };
+
     let mut checkpoint_mgr = CheckpointManager::new(&config.checkpoint_dir).await?;
     
-    if source == "all" {
+    if let Some(source_id) = source_id {
+        checkpoint_mgr.reset_source(source_id.as_str());
+        println!("✅ Reset checkpoint for source: {}", source_id);
+    } else {
         checkpoint_mgr.reset_all();
         println!("✅ Reset all checkpoints");
-    } else {
-        checkpoint_mgr.reset_source(source);
-        println!("✅ Reset checkpoint for source: {}", source);
     }
 
     checkpoint_mgr.save().await?;
+
+    if with_dedup {
+        reset_dedup(&config, source_id).await?;
+    }
+
+    AuditLogger::from_config(&config, correlation_id)
+        .await?
+        .record(audit::checkpoint_reset(source_id.map(|id| id.as_str()), with_dedup))
+        .await;
+    Ok(())
+}
+
+/// Clears Redis dedup keys for a source (or all sources)
+async fn reset_dedup(config: &Config, source_id: Option<SourceId>) -> Result<()> {
+    use crate::dedup::DedupStore;
+    use crate::message_bus::{redis_client, redis_connect_error};
+
+    let Some(ref redis_url) = config.redis_url else {
+        println!("ℹ️  No Redis configured; in-memory dedup caches clear when the harvester restarts");
+        return Ok(());
+    };
+
+    let client = redis_client(redis_url, config.redis_ca_cert.as_deref())?;
+    let conn = redis::aio::ConnectionManager::new(client).await.map_err(redis_connect_error)?;
+    let dedup = DedupStore::with_redis(config.dedup_cache_size, conn, config.dedup_ttl_seconds);
+    let deleted = dedup.clear_source(source_id.map(|id| id.as_str())).await?;
+
+    match source_id {
+        Some(source_id) => println!("✅ Cleared {} dedup keys for source: {}", deleted, source_id),
+        None => println!("✅ Cleared {} dedup keys", deleted),
+    }
+    println!("ℹ️  Running harvesters keep their in-memory dedup cache until restarted");
     Ok(())
 }
 
@@ -434,12 +1087,14 @@ async fn run_pipeline(
     config: Config,
     correlation_id: String,
     shutdown_tx: broadcast::Sender<()>,
+    reloader: ConfigReloader,
     channel_capacity: usize,
     enable_enrich: bool,
     enable_embed: bool,
 ) -> Result<()> {
     use crate::message_bus::{MessageBusType, MessageBusConfig, create_message_bus};
-    use crate::pipeline::{Pipeline, PipelineConfig, PipelineItem};
+    use crate::pipeline::{Pipeline, PipelineConfig, PipelineItem, SourcePipelines};
+    use crate::admin::AdminState;
     use crate::metrics::{start_metrics_server, MetricsReporter};
     use std::net::SocketAddr;
 
@@ -459,6 +1114,7 @@ async fn run_pipeline(
     info!(
         bus_type = ?bus_type,
         stream = %config.message_bus_stream,
+        priority_stream = %config.message_bus_priority_stream,
         "Connecting to message bus"
     );
 
@@ -466,10 +1122,20 @@ async fn run_pipeline(
     let bus_config = MessageBusConfig {
         stream_name: config.message_bus_stream.clone(),
         max_len: Some(100_000),
+        tls_ca_cert: config.redis_ca_cert.clone(),
+        data_type_streams: config.data_type_streams()?,
         ..Default::default()
     };
     
-    let message_bus = create_message_bus(bus_type, bus_url, bus_config).await?;
+    let message_bus = create_message_bus(bus_type, bus_url, bus_config.clone()).await?;
+
+    // Critical events are also routed to a dedicated stream for alerting consumers
+    let priority_bus_config = MessageBusConfig {
+        stream_name: config.message_bus_priority_stream.clone(),
+        data_type_streams: HashMap::new(),
+        ..bus_config.clone()
+    };
+    let priority_bus = create_message_bus(bus_type, bus_url, priority_bus_config.clone()).await?;
 
     // Create pipeline config
     let pipeline_config = PipelineConfig {
@@ -480,14 +1146,45 @@ async fn run_pipeline(
     };
 
     // Create pipeline
-    let pipeline = Pipeline::new(pipeline_config, message_bus).await?;
+    let pipeline = Pipeline::new(pipeline_config.clone(), message_bus, Some(priority_bus)).await?;
     let pipeline = Arc::new(pipeline);
 
+    // Isolated sources get their own pipeline and bus connections, so a stall
+    // in one doesn't back up the shared pipeline
+    let mut isolated = HashMap::new();
+    for source_id in config.isolated_sources()? {
+        let bus = create_message_bus(bus_type, bus_url, bus_config.clone()).await?;
+        let priority_bus = create_message_bus(bus_type, bus_url, priority_bus_config.clone()).await?;
+        let source_pipeline = Pipeline::new(pipeline_config.clone(), bus, Some(priority_bus)).await?;
+        info!(source = %source_id, "Source runs in its own pipeline");
+        isolated.insert(source_id.as_str().to_string(), Arc::new(source_pipeline));
+    }
+    let pipelines = Arc::new(SourcePipelines::new(pipeline, isolated));
+
+    // Initialize harvester for data source
+    let harvester = Arc::new(Harvester::new(config.clone(), correlation_id.clone()).await?);
+    harvester.ensure_sources()?;
+    spawn_config_reload(reloader, &harvester, &shutdown_tx);
+    attach_audit_bus(&config, &harvester.audit()).await?;
+    harvester.audit().record(audit::service_started("pipeline")).await;
+
+    // Fill the source health map `/readyz` reports (stops on harvester shutdown)
+    let _health_handle = harvester.spawn_health_sweep();
+    harvester.watch_polled_sources();
+
     // Start metrics server
     if config.metrics_enabled {
         let metrics_addr: SocketAddr = format!("0.0.0.0:{}", config.metrics_port).parse()?;
-        let metrics_handle = tokio::spawn(async move {
-            if let Err(e) = start_metrics_server(metrics_addr).await {
+        let admin = AdminState {
+            auth_token: config.metrics_auth_token.clone(),
+            switches: harvester.source_switches(),
+            dedup: Some(harvester.dedup_store()),
+            health: harvester.source_health(),
+            max_event_age: config.readyz_max_event_age_secs.map(std::time::Duration::from_secs),
+            audit: Some(harvester.audit()),
+        };
+        let _metrics_handle = tokio::spawn(async move {
+            if let Err(e) = start_metrics_server(metrics_addr, Some(admin)).await {
                 error!(error = %e, "Metrics server failed");
             }
         });
@@ -496,26 +1193,13 @@ async fn run_pipeline(
 
     // Start metrics reporter
     let reporter = MetricsReporter::new(30); // Log every 30 seconds
-    let reporter_handle = reporter.start();
-
-    // Initialize harvester for data source
-    let harvester = Arc::new(Harvester::new(config.clone(), correlation_id.clone()).await?);
+    let _reporter_handle = reporter.start();
 
     info!("Pipeline service initialized, starting data flow...");
 
-    // Spawn shutdown handler
-    let shutdown_pipeline = pipeline.clone();
-    let shutdown_harvester = harvester.clone();
-    let shutdown_reporter = reporter;
-    tokio::spawn(async move {
-        shutdown_signal(shutdown_tx).await;
-        
-        info!("Shutting down pipeline...");
-        shutdown_pipeline.shutdown().await;
-        shutdown_harvester.shutdown().await;
-        shutdown_reporter.stop();
-        info!("Pipeline shutdown complete");
-    });
+    // Spawn shutdown signal handler; the main loop stops on its signal
+    let mut shutdown_rx = shutdown_tx.subscribe();
+    tokio::spawn(shutdown_signal(shutdown_tx));
 
     // Main loop: fetch from harvester and submit to pipeline
     let mut interval = tokio::time::interval(std::time::Duration::from_millis(
@@ -523,7 +1207,10 @@ async fn run_pipeline(
     ));
 
     loop {
-        interval.tick().await;
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown_rx.recv() => break,
+        }
 
         // Fetch from all sources
         let fetch_options = crate::sources::FetchOptions {
@@ -534,40 +1221,58 @@ async fn run_pipeline(
             filters: std::collections::HashMap::new(),
         };
 
-        match harvester.fetch_from_source("all", fetch_options).await {
-            Ok(events) => {
-                let event_count = events.len();
-                if event_count > 0 {
-                    info!(count = event_count, "Fetched events from sources");
-
-                    // Submit to pipeline
-                    for event in events {
-                        let item = PipelineItem::new(
-                            event,
-                            &correlation_id,
-                            "harvester",
-                        );
-                        
-                        if let Err(e) = pipeline.submit(item).await {
-                            error!(error = %e, "Failed to submit to pipeline");
-                        }
-                    }
+        let report = harvester.fetch_all(fetch_options).await;
+        report.record_metrics();
+        for (source, error) in &report.failed {
+            error!(source = %source, error = %error, "Failed to fetch from source");
+        }
+
+        let event_count = report.events.len();
+        if event_count > 0 {
+            info!(count = event_count, sources = report.counts.len(), "Fetched events from sources");
+
+            // Submit to pipeline
+            let items = report
+                .events
+                .into_iter()
+                .map(|event| {
+                    let source = event.source_id.clone();
+                    PipelineItem::new(event, &correlation_id, &source)
+                })
+                .collect();
+
+            match pipelines.submit_batch(items).await {
+                Ok(()) => {}
+                // Backpressure: the events are fetched again next cycle
+                Err(e) if e.is_retryable() => {
+                    warn!(error = %e, "Pipeline busy, batch not fully submitted");
+                }
+                Err(e) => {
+                    error!(error = %e, "Pipeline closed, stopping data flow");
+                    break;
                 }
-            }
-            Err(e) => {
-                error!(error = %e, "Failed to fetch from sources");
             }
         }
 
         // Log pipeline stats
-        let stats = pipeline.stats();
-        if stats.has_backpressure() {
-            warn!(
-                bottleneck = stats.bottleneck(),
-                fetch_depth = stats.fetch_queue_depth,
-                normalize_depth = stats.normalize_queue_depth,
-                "Pipeline backpressure detected"
-            );
+        for (route, stats) in pipelines.stats() {
+            if stats.has_backpressure() {
+                warn!(
+                    route,
+                    bottleneck = stats.bottleneck(),
+                    fetch_depth = stats.fetch_queue_depth,
+                    normalize_depth = stats.normalize_queue_depth,
+                    "Pipeline backpressure detected"
+                );
+            }
         }
     }
+
+    // Fetching has stopped; drain what was submitted before stopping workers
+    info!("Shutting down pipeline...");
+    shutdown::shutdown_pipelines(&pipelines, &harvester, pipeline_config.shutdown_deadline).await;
+    harvester.audit().record(audit::service_stopped()).await;
+    reporter.stop();
+    info!("Pipeline shutdown complete");
+    Ok(())
 }
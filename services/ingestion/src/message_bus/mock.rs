@@ -0,0 +1,335 @@
+//! In-Memory Message Bus
+//!
+//! Records published events without any external infrastructure, for tests
+//! and dry runs. Consumers replay everything published so far, then wait for
+//! new events.
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Notify, Semaphore};
+
+use super::{Message, MessageBus, MessageBusConfig, MessageConsumer, PublishResult};
+use crate::schemas::{AuditLogEvent, IngestionEvent};
+
+// ============================================
+// MOCK BUS
+// ============================================
+
+/// Message bus that keeps published events in memory
+#[derive(Clone, Default)]
+pub struct MockMessageBus {
+    config: Arc<MessageBusConfig>,
+    events: Arc<Mutex<Vec<IngestionEvent>>>,
+    /// Stream each event in `events` was published to
+    streams: Arc<Mutex<Vec<String>>>,
+    /// Bytes of the events in `events` published via `publish_raw`, by index
+    raw: Arc<Mutex<HashMap<usize, Vec<u8>>>>,
+    published: Arc<Notify>,
+    /// Number of events in each `publish_batch` call
+    batches: Arc<Mutex<Vec<usize>>>,
+    acks: Arc<Mutex<Vec<String>>>,
+    nacks: Arc<Mutex<Vec<String>>>,
+    audits: Arc<Mutex<Vec<AuditLogEvent>>>,
+    /// `(stream, payload)` of every record published via `publish_json`
+    records: Arc<Mutex<Vec<(String, serde_json::Value)>>>,
+    /// Publishes wait here until `open_gate` when set
+    gate: Option<Arc<Semaphore>>,
+}
+
+impl MockMessageBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a bus that records streams per `config` (data-type routing)
+    pub fn with_config(config: MessageBusConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a bus whose `publish` and `publish_batch` wait until
+    /// `open_gate` is called, to back up a pipeline in front of it
+    pub fn gated() -> Self {
+        Self {
+            gate: Some(Arc::new(Semaphore::new(0))),
+            ..Self::default()
+        }
+    }
+
+    /// Lets every waiting and future publish through
+    pub fn open_gate(&self) {
+        if let Some(gate) = &self.gate {
+            if gate.available_permits() == 0 {
+                gate.add_permits(1);
+            }
+        }
+    }
+
+    /// Records `event` as published to `stream` and wakes consumers
+    fn push(&self, event: IngestionEvent, stream: &str, raw: Option<&[u8]>) -> usize {
+        let index = {
+            let mut events = self.events.lock();
+            events.push(event);
+            self.streams.lock().push(stream.to_string());
+            events.len() - 1
+        };
+        if let Some(bytes) = raw {
+            self.raw.lock().insert(index, bytes.to_vec());
+        }
+        self.published.notify_waiters();
+        index
+    }
+
+    /// Gets a copy of every event published so far (clones of the bus share
+    /// the same events, so keep one before boxing it)
+    pub fn published(&self) -> Vec<IngestionEvent> {
+        self.events.lock().clone()
+    }
+
+    /// Gets the events published to `stream` so far
+    pub fn published_to(&self, stream: &str) -> Vec<IngestionEvent> {
+        let events = self.events.lock();
+        let streams = self.streams.lock();
+        events
+            .iter()
+            .zip(streams.iter())
+            .filter(|(_, s)| s.as_str() == stream)
+            .map(|(event, _)| event.clone())
+            .collect()
+    }
+
+    /// Gets the size of every batch published via `publish_batch` so far
+    pub fn batch_sizes(&self) -> Vec<usize> {
+        self.batches.lock().clone()
+    }
+
+    /// Gets every audit event published so far
+    pub fn published_audits(&self) -> Vec<AuditLogEvent> {
+        self.audits.lock().clone()
+    }
+
+    /// Gets the records published to `stream` so far
+    pub fn published_records(&self, stream: &str) -> Vec<serde_json::Value> {
+        self.records
+            .lock()
+            .iter()
+            .filter(|(s, _)| s.as_str() == stream)
+            .map(|(_, payload)| payload.clone())
+            .collect()
+    }
+
+    /// Gets the message IDs acked by consumers of this bus
+    pub fn acked(&self) -> Vec<String> {
+        self.acks.lock().clone()
+    }
+
+    /// Gets the message IDs nacked by consumers of this bus
+    pub fn nacked(&self) -> Vec<String> {
+        self.nacks.lock().clone()
+    }
+}
+
+#[async_trait]
+impl MessageBus for MockMessageBus {
+    async fn publish(&self, event: &IngestionEvent) -> anyhow::Result<PublishResult> {
+        if let Some(gate) = &self.gate {
+            // The permit goes straight back, so an open gate stays open
+            drop(gate.acquire().await?);
+        }
+        let index = self.push(event.clone(), self.config.stream_for(&event.data_type), None);
+
+        Ok(PublishResult {
+            message_id: event.id.clone(),
+            stream_id: Some(index.to_string()),
+            success: true,
+            error: None,
+        })
+    }
+
+    async fn publish_batch(&self, events: &[IngestionEvent]) -> anyhow::Result<Vec<PublishResult>> {
+        self.batches.lock().push(events.len());
+        let mut results = Vec::with_capacity(events.len());
+        for event in events {
+            results.push(self.publish(event).await?);
+        }
+        Ok(results)
+    }
+
+    async fn subscribe(&self, _consumer_group: &str, _consumer_name: &str) -> anyhow::Result<Box<dyn MessageConsumer>> {
+        Ok(Box::new(MockConsumer {
+            events: self.events.clone(),
+            raw: self.raw.clone(),
+            published: self.published.clone(),
+            acks: self.acks.clone(),
+            nacks: self.nacks.clone(),
+            next_index: 0,
+        }))
+    }
+
+    async fn replay(&self, from_id: &str) -> anyhow::Result<Box<dyn MessageConsumer>> {
+        let next_index = from_id
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid mock message id: {}", from_id))?;
+
+        Ok(Box::new(MockConsumer {
+            events: self.events.clone(),
+            raw: self.raw.clone(),
+            published: self.published.clone(),
+            acks: self.acks.clone(),
+            nacks: self.nacks.clone(),
+            next_index,
+        }))
+    }
+
+    async fn publish_audit(&self, event: &AuditLogEvent) -> anyhow::Result<PublishResult> {
+        self.audits.lock().push(event.clone());
+
+        Ok(PublishResult {
+            message_id: event.id.clone(),
+            stream_id: None,
+            success: true,
+            error: None,
+        })
+    }
+
+    async fn publish_json(
+        &self,
+        stream: &str,
+        _kind: &str,
+        id: &str,
+        payload: &serde_json::Value,
+    ) -> anyhow::Result<PublishResult> {
+        self.records.lock().push((stream.to_string(), payload.clone()));
+
+        Ok(PublishResult {
+            message_id: id.to_string(),
+            stream_id: None,
+            success: true,
+            error: None,
+        })
+    }
+
+    /// Keeps `bytes` as published (consumers' `read_raw` returns them
+    /// unchanged) and the decoded event for typed consumers
+    async fn publish_raw(&self, stream: &str, bytes: &[u8]) -> anyhow::Result<PublishResult> {
+        let event: IngestionEvent = serde_json::from_slice(bytes)?;
+        let message_id = event.id.clone();
+        let index = self.push(event, stream, Some(bytes));
+
+        Ok(PublishResult {
+            message_id,
+            stream_id: Some(index.to_string()),
+            success: true,
+            error: None,
+        })
+    }
+
+    async fn is_healthy(&self) -> bool {
+        true
+    }
+
+    fn bus_type(&self) -> &'static str {
+        "mock"
+    }
+
+    async fn close(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+// ============================================
+// MOCK CONSUMER
+// ============================================
+
+/// Consumer reading a `MockMessageBus` from the first published event (or
+/// the replay start)
+pub struct MockConsumer {
+    events: Arc<Mutex<Vec<IngestionEvent>>>,
+    raw: Arc<Mutex<HashMap<usize, Vec<u8>>>>,
+    published: Arc<Notify>,
+    acks: Arc<Mutex<Vec<String>>>,
+    nacks: Arc<Mutex<Vec<String>>>,
+    next_index: usize,
+}
+
+impl MockConsumer {
+    /// Takes up to `count` undelivered events
+    fn take(&mut self, count: usize) -> Vec<Message<IngestionEvent>> {
+        let messages = self.pending(count);
+        self.next_index += messages.len();
+        messages
+    }
+
+    /// Gets up to `count` undelivered events without delivering them
+    fn pending(&self, count: usize) -> Vec<Message<IngestionEvent>> {
+        let events = self.events.lock();
+        let end = events.len().min(self.next_index + count);
+        events[self.next_index..end]
+            .iter()
+            .enumerate()
+            .map(|(offset, event)| Message {
+                id: (self.next_index + offset).to_string(),
+                timestamp: chrono::Utc::now(),
+                correlation_id: event.correlation_id.clone().unwrap_or_else(|| event.id.clone()),
+                source: event.source_id.clone(),
+                payload: event.clone(),
+                retry_count: 0,
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl MessageConsumer for MockConsumer {
+    async fn read(&mut self, count: usize, timeout: Duration) -> anyhow::Result<Vec<Message<IngestionEvent>>> {
+        let published = self.published.clone();
+        let notified = published.notified();
+        let messages = self.take(count);
+        if !messages.is_empty() {
+            return Ok(messages);
+        }
+
+        // Wait for the next publish, like a blocking stream read
+        let _ = tokio::time::timeout(timeout, notified).await;
+        Ok(self.take(count))
+    }
+
+    async fn peek(&mut self, count: usize) -> anyhow::Result<Vec<Message<IngestionEvent>>> {
+        Ok(self.pending(count))
+    }
+
+    async fn ack(&self, message_id: &str) -> anyhow::Result<()> {
+        self.acks.lock().push(message_id.to_string());
+        Ok(())
+    }
+
+    async fn nack(&self, message_id: &str) -> anyhow::Result<()> {
+        self.nacks.lock().push(message_id.to_string());
+        Ok(())
+    }
+
+    async fn position(&mut self) -> anyhow::Result<Option<String>> {
+        Ok(self.next_index.checked_sub(1).map(|index| index.to_string()))
+    }
+
+    /// Returns raw-published events byte for byte, serializing the rest
+    async fn read_raw(&mut self, count: usize, timeout: Duration) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+        let messages = self.read(count, timeout).await?;
+        let raw = self.raw.lock();
+        messages
+            .into_iter()
+            .map(|message| {
+                let bytes = match message.id.parse().ok().and_then(|index: usize| raw.get(&index)) {
+                    Some(bytes) => bytes.clone(),
+                    None => serde_json::to_vec(&message.payload)?,
+                };
+                Ok((message.id, bytes))
+            })
+            .collect()
+    }
+}
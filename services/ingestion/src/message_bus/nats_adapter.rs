@@ -10,18 +10,19 @@ use async_trait::async_trait;
 use async_nats::{
     jetstream::{
         self,
-        consumer::{pull::Config as ConsumerConfig, Consumer},
-        context::Publish,
-        stream::{Config as StreamConfig, RetentionPolicy, StorageType},
+        consumer::{pull::Config as ConsumerConfig, AckPolicy, Consumer, DeliverPolicy},
+        stream::{Config as StreamConfig, RetentionPolicy, StorageType, Stream},
         Context,
     },
     Client,
 };
+use parking_lot::Mutex;
+use std::collections::HashSet;
 use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
-use super::{Message, MessageBus, MessageBusConfig, MessageConsumer, PublishResult};
-use crate::schemas::IngestionEvent;
+use super::{Message, MessageBus, MessageBusConfig, MessageConsumer, PublishResult, RawEventHeader};
+use crate::schemas::{AuditLogEvent, IngestionEvent};
 
 // ============================================
 // NATS JETSTREAM BUS
@@ -31,6 +32,8 @@ pub struct NatsBus {
     client: Client,
     jetstream: Context,
     config: MessageBusConfig,
+    /// Streams `publish_json` has already ensured exist
+    record_streams: Mutex<HashSet<String>>,
 }
 
 impl NatsBus {
@@ -43,10 +46,13 @@ impl NatsBus {
             client,
             jetstream,
             config,
+            record_streams: Mutex::new(HashSet::new()),
         };
 
-        // Ensure stream exists
-        bus.ensure_stream().await?;
+        // Ensure streams exist (one per data-type stream)
+        for name in bus.config.stream_names() {
+            bus.ensure_stream(name).await?;
+        }
 
         info!(stream = %bus.config.stream_name, "Connected to NATS JetStream");
 
@@ -54,10 +60,10 @@ impl NatsBus {
     }
 
     /// Ensures the JetStream stream exists
-    async fn ensure_stream(&self) -> anyhow::Result<()> {
+    async fn ensure_stream(&self, name: &str) -> anyhow::Result<()> {
         let stream_config = StreamConfig {
-            name: self.config.stream_name.clone(),
-            subjects: vec![format!("{}.*", self.config.stream_name)],
+            name: name.to_string(),
+            subjects: vec![format!("{}.*", name)],
             retention: RetentionPolicy::Limits,
             max_messages: self.config.max_len.map(|l| l as i64).unwrap_or(100_000),
             max_bytes: 1024 * 1024 * 1024, // 1GB
@@ -67,12 +73,9 @@ impl NatsBus {
         };
 
         match self.jetstream.get_or_create_stream(stream_config).await {
-            Ok(stream) => {
-                info!(
-                    stream = %self.config.stream_name,
-                    messages = stream.info().await.ok().map(|i| i.state.messages).unwrap_or(0),
-                    "JetStream stream ready"
-                );
+            Ok(mut stream) => {
+                let messages = stream.info().await.ok().map(|i| i.state.messages).unwrap_or(0);
+                info!(stream = %name, messages, "JetStream stream ready");
             }
             Err(e) => {
                 error!(error = %e, "Failed to create/get JetStream stream");
@@ -85,7 +88,7 @@ impl NatsBus {
 
     /// Gets the subject for an event
     fn get_subject(&self, event: &IngestionEvent) -> String {
-        format!("{}.{:?}", self.config.stream_name, event.data_type)
+        format!("{}.{:?}", self.config.stream_for(&event.data_type), event.data_type)
     }
 }
 
@@ -192,7 +195,91 @@ impl MessageBus for NatsBus {
 
         let consumer = stream.get_or_create_consumer(consumer_group, consumer_config).await?;
 
-        Ok(Box::new(NatsConsumer { consumer }))
+        Ok(Box::new(NatsConsumer { stream, consumer }))
+    }
+
+    async fn replay(&self, from_id: &str) -> anyhow::Result<Box<dyn MessageConsumer>> {
+        let start_sequence: u64 = from_id
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid NATS stream sequence: {}", from_id))?;
+
+        let stream = self
+            .jetstream
+            .get_stream(&self.config.stream_name)
+            .await?;
+
+        // Ephemeral consumer, so the replay doesn't move any group's position
+        let consumer_config = ConsumerConfig {
+            deliver_policy: if start_sequence == 0 {
+                DeliverPolicy::All
+            } else {
+                DeliverPolicy::ByStartSequence { start_sequence }
+            },
+            ack_policy: AckPolicy::None,
+            ..Default::default()
+        };
+
+        let consumer = stream.create_consumer(consumer_config).await?;
+
+        Ok(Box::new(NatsConsumer { stream, consumer }))
+    }
+
+    async fn publish_audit(&self, event: &AuditLogEvent) -> anyhow::Result<PublishResult> {
+        let payload = serde_json::to_value(event)?;
+        self.publish_json(&self.config.stream_name, "Audit", &event.id, &payload).await
+    }
+
+    async fn publish_json(
+        &self,
+        stream: &str,
+        kind: &str,
+        id: &str,
+        payload: &serde_json::Value,
+    ) -> anyhow::Result<PublishResult> {
+        // Record streams aren't created on connect
+        let is_new = self.record_streams.lock().insert(stream.to_string());
+        if is_new && !self.config.stream_names().contains(&stream) {
+            if let Err(e) = self.ensure_stream(stream).await {
+                self.record_streams.lock().remove(stream);
+                return Err(e);
+            }
+        }
+
+        let subject = format!("{}.{}", stream, kind);
+        let ack = self
+            .jetstream
+            .publish(subject, serde_json::to_vec(payload)?.into())
+            .await?
+            .await?;
+
+        debug!(stream = %stream, kind = %kind, id = %id, sequence = ack.sequence, "Published record to NATS JetStream");
+
+        Ok(PublishResult {
+            message_id: id.to_string(),
+            stream_id: Some(ack.sequence.to_string()),
+            success: true,
+            error: None,
+        })
+    }
+
+    /// Publishes to the same `{stream}.{data_type}` subject as `publish`,
+    /// read from the event's header
+    async fn publish_raw(&self, stream: &str, bytes: &[u8]) -> anyhow::Result<PublishResult> {
+        let header = RawEventHeader::parse(bytes)?;
+        let ack = self
+            .jetstream
+            .publish(format!("{}.{:?}", stream, header.data_type), bytes.to_vec().into())
+            .await?
+            .await?;
+
+        debug!(stream = %stream, event_id = %header.id, bytes = bytes.len(), sequence = ack.sequence, "Published raw event to NATS JetStream");
+
+        Ok(PublishResult {
+            message_id: header.id,
+            stream_id: Some(ack.sequence.to_string()),
+            success: true,
+            error: None,
+        })
     }
 
     async fn is_healthy(&self) -> bool {
@@ -208,7 +295,8 @@ impl MessageBus for NatsBus {
     }
 
     async fn close(&self) -> anyhow::Result<()> {
-        // async_nats client doesn't need explicit close
+        // Deliver anything still buffered; the connection closes on drop
+        self.client.flush().await?;
         info!("NATS connection closed");
         Ok(())
     }
@@ -219,9 +307,76 @@ impl MessageBus for NatsBus {
 // ============================================
 
 pub struct NatsConsumer {
+    stream: Stream,
     consumer: Consumer<ConsumerConfig>,
 }
 
+/// How long a peek waits for the next messages
+const PEEK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Fetches up to `count` messages from a pull consumer
+///
+/// A fetch whose messages are all undecodable is followed by another, so
+/// only an empty fetch yields an empty batch.
+async fn fetch_messages(
+    consumer: &Consumer<ConsumerConfig>,
+    count: usize,
+    timeout: Duration,
+) -> anyhow::Result<Vec<Message<IngestionEvent>>> {
+    loop {
+        let (received, result) = fetch_batch(consumer, count, timeout).await?;
+        if received == 0 || !result.is_empty() {
+            return Ok(result);
+        }
+        warn!(skipped = received, "Skipping undecodable NATS messages");
+    }
+}
+
+/// Fetches one batch, returning how many messages arrived and the ones
+/// that decoded
+async fn fetch_batch(
+    consumer: &Consumer<ConsumerConfig>,
+    count: usize,
+    timeout: Duration,
+) -> anyhow::Result<(usize, Vec<Message<IngestionEvent>>)> {
+    let mut messages = consumer
+        .fetch()
+        .max_messages(count)
+        .expires(timeout)
+        .messages()
+        .await?;
+
+    let mut received = 0;
+    let mut result = Vec::new();
+
+    while let Some(msg) = messages.next().await {
+        match msg {
+            Ok(message) => {
+                received += 1;
+                if let Ok(event) = serde_json::from_slice::<IngestionEvent>(&message.payload) {
+                    result.push(Message {
+                        id: message
+                            .info()
+                            .ok()
+                            .map(|i| i.stream_sequence.to_string())
+                            .unwrap_or_default(),
+                        timestamp: chrono::Utc::now(),
+                        correlation_id: event.id.clone(),
+                        source: event.source_id.clone(),
+                        payload: event,
+                        retry_count: message.info().ok().map(|i| i.delivered as u32).unwrap_or(0),
+                    });
+                }
+            }
+            Err(e) => {
+                warn!(error = %e, "Error reading NATS message");
+            }
+        }
+    }
+
+    Ok((received, result))
+}
+
 #[async_trait]
 impl MessageConsumer for NatsConsumer {
     async fn read(
@@ -229,41 +384,27 @@ impl MessageConsumer for NatsConsumer {
         count: usize,
         timeout: Duration,
     ) -> anyhow::Result<Vec<Message<IngestionEvent>>> {
-        let mut messages = self
-            .consumer
-            .fetch()
-            .max_messages(count)
-            .expires(timeout)
-            .messages()
-            .await?;
+        fetch_messages(&self.consumer, count, timeout).await
+    }
 
-        let mut result = Vec::new();
-
-        while let Some(msg) = messages.next().await {
-            match msg {
-                Ok(message) => {
-                    if let Ok(event) = serde_json::from_slice::<IngestionEvent>(&message.payload) {
-                        result.push(Message {
-                            id: message
-                                .info()
-                                .ok()
-                                .map(|i| i.stream_sequence.to_string())
-                                .unwrap_or_default(),
-                            timestamp: chrono::Utc::now(),
-                            correlation_id: event.id.clone(),
-                            source: event.source_id.clone(),
-                            payload: event,
-                            retry_count: message.info().ok().map(|i| i.delivered as u32).unwrap_or(0),
-                        });
-                    }
-                }
-                Err(e) => {
-                    warn!(error = %e, "Error reading NATS message");
-                }
-            }
-        }
+    /// Views the next messages through a throwaway consumer starting after
+    /// this one's last delivery, so this consumer's position doesn't move.
+    /// Messages delivered but still unacked are not included.
+    async fn peek(&mut self, count: usize) -> anyhow::Result<Vec<Message<IngestionEvent>>> {
+        let info = self.consumer.info().await?;
+        let start_sequence = info.delivered.stream_sequence + 1;
+
+        let viewer = self
+            .stream
+            .create_consumer(ConsumerConfig {
+                deliver_policy: DeliverPolicy::ByStartSequence { start_sequence },
+                ack_policy: AckPolicy::None,
+                inactive_threshold: Duration::from_secs(5),
+                ..Default::default()
+            })
+            .await?;
 
-        Ok(result)
+        fetch_messages(&viewer, count, PEEK_TIMEOUT).await
     }
 
     async fn ack(&self, _message_id: &str) -> anyhow::Result<()> {
@@ -276,13 +417,19 @@ impl MessageConsumer for NatsConsumer {
         // NATS will automatically redeliver unacked messages
         Ok(())
     }
+
+    async fn position(&mut self) -> anyhow::Result<Option<String>> {
+        let info = self.consumer.info().await?;
+        let sequence = info.delivered.stream_sequence;
+        Ok((sequence > 0).then(|| sequence.to_string()))
+    }
 }
 
 use futures::StreamExt;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    
 
     // Integration tests require NATS running
     // Run with: cargo test --features integration-tests
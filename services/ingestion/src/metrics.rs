@@ -8,14 +8,16 @@
 //! - latency per stage (histogram)
 //! - queue depth per channel
 //! - error counts
+//! - harvest cycles and harvested events per source
+//! - forced aborts on shutdown
+//! - RPC requests per endpoint
 //! - memory usage
 
 use once_cell::sync::Lazy;
 use prometheus::{
-    register_counter_vec, register_gauge_vec, register_histogram_vec,
-    register_int_counter_vec, register_int_gauge_vec,
-    CounterVec, GaugeVec, HistogramVec, IntCounterVec, IntGaugeVec,
-    Encoder, TextEncoder, Registry, Opts, HistogramOpts,
+    register_gauge_vec, register_histogram_vec,
+    register_int_counter_vec, register_int_gauge_vec, GaugeVec, HistogramVec, IntCounterVec, IntGaugeVec,
+    Encoder, TextEncoder, HistogramOpts,
 };
 use std::sync::Arc;
 use tracing::{info, error};
@@ -58,6 +60,23 @@ static EVENTS_RATE: Lazy<GaugeVec> = Lazy::new(|| {
     ).expect("Failed to create events_rate metric")
 });
 
+// Events per second entering the pipeline, per source
+static SOURCE_EVENTS_RATE: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "ingestion_source_events_per_second",
+        "Events submitted to the pipeline per second by each source",
+        &["source"]
+    ).expect("Failed to create source_events_rate metric")
+});
+
+static SOURCE_LAST_EVENT_AGE: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "ingestion_source_last_event_age_seconds",
+        "Seconds since each polled source last returned events",
+        &["source"]
+    ).expect("Failed to create source_last_event_age metric")
+});
+
 // Latency histogram (in seconds)
 static STAGE_LATENCY: Lazy<HistogramVec> = Lazy::new(|| {
     let buckets = vec![
@@ -126,6 +145,34 @@ static BACKPRESSURE_EVENTS: Lazy<IntCounterVec> = Lazy::new(|| {
     ).expect("Failed to create backpressure_events metric")
 });
 
+// Raw API response sizes
+static RAW_PAYLOAD_BYTES: Lazy<HistogramVec> = Lazy::new(|| {
+    let buckets = vec![
+        1024.0, 4096.0, 16384.0, 65536.0, 262144.0, 1048576.0, 4194304.0, 16777216.0,
+    ];
+    register_histogram_vec!(
+        HistogramOpts::new(
+            "ingestion_raw_payload_bytes",
+            "Size of raw source responses in bytes"
+        ).buckets(buckets),
+        &["source"]
+    ).expect("Failed to create raw_payload_bytes metric")
+});
+
+// Per-source fetch duration (in seconds)
+static SOURCE_FETCH_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    let buckets = vec![
+        0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+    ];
+    register_histogram_vec!(
+        HistogramOpts::new(
+            "ingestion_source_fetch_duration_seconds",
+            "Duration of each source fetch in seconds"
+        ).buckets(buckets),
+        &["source"]
+    ).expect("Failed to create source_fetch_duration metric")
+});
+
 // Message bus publish latency
 static PUBLISH_LATENCY: Lazy<HistogramVec> = Lazy::new(|| {
     let buckets = vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0];
@@ -165,6 +212,128 @@ static DEDUP_HITS: Lazy<IntCounterVec> = Lazy::new(|| {
     ).expect("Failed to create dedup_hits metric")
 });
 
+// Harvest cycles per source
+static HARVEST_CYCLES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_harvest_cycles_total",
+        "Total number of harvest cycles run per source",
+        &["source"]
+    ).expect("Failed to create harvest_cycles metric")
+});
+
+// Harvested events per source and outcome
+static HARVESTED_EVENTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_harvested_events_total",
+        "Total number of harvested events by source and status",
+        &["source", "status"]
+    ).expect("Failed to create harvested_events metric")
+});
+
+// Stage tasks aborted after the shutdown deadline
+static FORCED_ABORTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_forced_aborts_total",
+        "Number of pipeline tasks aborted after the shutdown deadline",
+        &["stage"]
+    ).expect("Failed to create forced_aborts metric")
+});
+
+// Payloads truncated for exceeding the size limit
+static TRUNCATED_PAYLOADS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_truncated_payloads_total",
+        "Number of event payloads truncated for exceeding max_payload_bytes",
+        &["source"]
+    ).expect("Failed to create truncated_payloads metric")
+});
+
+// Items failed for taking longer than the stage timeout
+static STAGE_TIMEOUTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_stage_timeouts_total",
+        "Number of items a stage failed for exceeding its timeout",
+        &["stage"]
+    ).expect("Failed to create stage_timeouts metric")
+});
+
+// Append log lines that couldn't be read as any known entry shape
+static UNREADABLE_LOG_ENTRIES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_unreadable_log_entries_total",
+        "Number of append log lines skipped on read for not matching any known entry shape",
+        &["source"]
+    ).expect("Failed to create unreadable_log_entries metric")
+});
+
+// Submissions dropped or rejected while the fetch queue was full
+static SUBMIT_REJECTIONS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_submit_rejections_total",
+        "Number of pipeline submissions dropped or rejected under backpressure",
+        &["source", "action"]
+    ).expect("Failed to create submit_rejections metric")
+});
+
+// Embeddings dropped for having the wrong dimension
+static EMBEDDING_DIM_MISMATCHES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_embedding_dim_mismatch_total",
+        "Number of embeddings dropped for not matching the expected dimension",
+        &["source"]
+    ).expect("Failed to create embedding_dim_mismatch metric")
+});
+
+// Failed source fetches
+static SOURCE_FETCH_ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_source_fetch_errors_total",
+        "Number of source fetches that failed",
+        &["source"]
+    ).expect("Failed to create source_fetch_errors metric")
+});
+
+// Source records skipped for not matching the expected shape
+static PARSE_ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_parse_errors_total",
+        "Number of records in source responses skipped for failing to parse",
+        &["source"]
+    ).expect("Failed to create parse_errors metric")
+});
+
+// Reconnects of long-lived source streams (WebSocket, bus consumers)
+static STREAM_RECONNECTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_stream_reconnects_total",
+        "Number of times a source stream was reconnected after failing or ending",
+        &["source"]
+    ).expect("Failed to create stream_reconnects metric")
+});
+
+// RPC requests per endpoint and outcome
+static RPC_REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "ingestion_rpc_requests_total",
+        "Total number of RPC requests by endpoint host and status",
+        &["endpoint", "status"]
+    ).expect("Failed to create rpc_requests metric")
+});
+
+/// Harvested event statuses
+pub const HARVEST_STATUS_STORED: &str = "stored";
+pub const HARVEST_STATUS_DUPLICATE: &str = "duplicate";
+pub const HARVEST_STATUS_ERROR: &str = "error";
+/// Fetched and handed to the pipeline (pipeline mode)
+pub const HARVEST_STATUS_SUBMITTED: &str = "submitted";
+
+/// Memory usage types
+pub const MEMORY_TYPE_RSS: &str = "rss";
+
+/// RPC request statuses
+pub const RPC_STATUS_SUCCESS: &str = "success";
+pub const RPC_STATUS_ERROR: &str = "error";
+
 // ============================================
 // METRICS API
 // ============================================
@@ -179,6 +348,11 @@ pub fn record_events_processed(stage: &str, source: &str, count: u64) {
     EVENTS_PROCESSED.with_label_values(&[stage, source]).inc_by(count);
 }
 
+/// Gets the events processed total for a stage and source
+pub fn events_processed_total(stage: &str, source: &str) -> u64 {
+    EVENTS_PROCESSED.with_label_values(&[stage, source]).get()
+}
+
 /// Records stage latency
 pub fn record_stage_latency(stage: &str, latency_secs: f64) {
     STAGE_LATENCY.with_label_values(&[stage]).observe(latency_secs);
@@ -219,6 +393,38 @@ pub fn record_backpressure(stage: &str) {
     BACKPRESSURE_EVENTS.with_label_values(&[stage]).inc();
 }
 
+/// Records the size of a raw source response
+pub fn record_raw_payload_bytes(source: &str, bytes: usize) {
+    RAW_PAYLOAD_BYTES.with_label_values(&[source]).observe(bytes as f64);
+}
+
+/// Gets the number of raw responses and their total size for a source
+pub fn raw_payload_bytes_observed(source: &str) -> (u64, f64) {
+    let histogram = RAW_PAYLOAD_BYTES.with_label_values(&[source]);
+    (histogram.get_sample_count(), histogram.get_sample_sum())
+}
+
+/// Records how long a source fetch took
+pub fn record_source_fetch_duration(source: &str, duration_secs: f64) {
+    SOURCE_FETCH_DURATION.with_label_values(&[source]).observe(duration_secs);
+}
+
+/// Gets the number of fetches and their total duration for a source
+pub fn source_fetch_duration_observed(source: &str) -> (u64, f64) {
+    let histogram = SOURCE_FETCH_DURATION.with_label_values(&[source]);
+    (histogram.get_sample_count(), histogram.get_sample_sum())
+}
+
+/// Records a submission dropped or rejected under backpressure
+pub fn record_submit_rejection(source: &str, action: &str) {
+    SUBMIT_REJECTIONS.with_label_values(&[source, action]).inc();
+}
+
+/// Gets the submit rejection total for a source and action
+pub fn submit_rejections_total(source: &str, action: &str) -> u64 {
+    SUBMIT_REJECTIONS.with_label_values(&[source, action]).get()
+}
+
 /// Records publish latency
 pub fn record_publish_latency(bus_type: &str, latency_secs: f64) {
     PUBLISH_LATENCY.with_label_values(&[bus_type]).observe(latency_secs);
@@ -244,27 +450,178 @@ pub fn record_dedup_hit(source: &str) {
     DEDUP_HITS.with_label_values(&[source]).inc();
 }
 
+/// Records a harvest cycle for a source
+pub fn record_harvest_cycle(source: &str) {
+    HARVEST_CYCLES.with_label_values(&[source]).inc();
+}
+
+/// Records harvested events for a source with the given status
+pub fn record_harvested_events(source: &str, status: &str, count: u64) {
+    HARVESTED_EVENTS.with_label_values(&[source, status]).inc_by(count);
+}
+
+/// Gets the harvested events total for a source and status
+pub fn harvested_events_total(source: &str, status: &str) -> u64 {
+    HARVESTED_EVENTS.with_label_values(&[source, status]).get()
+}
+
+/// Records a stage task aborted after the shutdown deadline
+pub fn record_forced_abort(stage: &str) {
+    FORCED_ABORTS.with_label_values(&[stage]).inc();
+}
+
+/// Gets the forced abort total for a stage
+pub fn forced_aborts_total(stage: &str) -> u64 {
+    FORCED_ABORTS.with_label_values(&[stage]).get()
+}
+
+/// Records a payload truncated for exceeding the size limit
+pub fn record_truncated_payload(source: &str) {
+    TRUNCATED_PAYLOADS.with_label_values(&[source]).inc();
+}
+
+/// Gets the truncated payload total for a source
+pub fn truncated_payloads_total(source: &str) -> u64 {
+    TRUNCATED_PAYLOADS.with_label_values(&[source]).get()
+}
+
+/// Records an item a stage timed out on
+pub fn record_stage_timeout(stage: &str) {
+    STAGE_TIMEOUTS.with_label_values(&[stage]).inc();
+}
+
+/// Gets the stage timeout total for a stage
+pub fn stage_timeouts_total(stage: &str) -> u64 {
+    STAGE_TIMEOUTS.with_label_values(&[stage]).get()
+}
+
+/// Records an append log line that couldn't be read
+pub fn record_unreadable_log_entry(source: &str) {
+    UNREADABLE_LOG_ENTRIES.with_label_values(&[source]).inc();
+}
+
+/// Gets the unreadable append log line total for a source
+pub fn unreadable_log_entries_total(source: &str) -> u64 {
+    UNREADABLE_LOG_ENTRIES.with_label_values(&[source]).get()
+}
+
+/// Records a failed source fetch
+pub fn record_source_fetch_error(source: &str) {
+    SOURCE_FETCH_ERRORS.with_label_values(&[source]).inc();
+}
+
+/// Gets the failed fetch total for a source
+pub fn source_fetch_errors_total(source: &str) -> u64 {
+    SOURCE_FETCH_ERRORS.with_label_values(&[source]).get()
+}
+
+/// Records a source record skipped for failing to parse
+pub fn record_parse_error(source: &str) {
+    PARSE_ERRORS.with_label_values(&[source]).inc();
+}
+
+/// Gets the parse error total for a source
+pub fn parse_errors_total(source: &str) -> u64 {
+    PARSE_ERRORS.with_label_values(&[source]).get()
+}
+
+/// Records a source stream reconnect attempt
+pub fn record_stream_reconnect(source: &str) {
+    STREAM_RECONNECTS.with_label_values(&[source]).inc();
+}
+
+/// Gets the stream reconnect total for a source
+pub fn stream_reconnects_total(source: &str) -> u64 {
+    STREAM_RECONNECTS.with_label_values(&[source]).get()
+}
+
+/// Records an embedding dropped for having the wrong dimension
+pub fn record_embedding_dim_mismatch(source: &str) {
+    EMBEDDING_DIM_MISMATCHES.with_label_values(&[source]).inc();
+}
+
+/// Gets the embedding dimension mismatch total for a source
+pub fn embedding_dim_mismatches_total(source: &str) -> u64 {
+    EMBEDDING_DIM_MISMATCHES.with_label_values(&[source]).get()
+}
+
+/// Records an RPC request served (or failed) by an endpoint
+pub fn record_rpc_request(endpoint: &str, status: &str) {
+    RPC_REQUESTS.with_label_values(&[endpoint, status]).inc();
+}
+
+/// Gets the RPC request total for an endpoint and status
+pub fn rpc_requests_total(endpoint: &str, status: &str) -> u64 {
+    RPC_REQUESTS.with_label_values(&[endpoint, status]).get()
+}
+
 /// Updates events per second rate (call periodically)
 pub fn update_events_rate(stage: &str, rate: f64) {
     EVENTS_RATE.with_label_values(&[stage]).set(rate);
 }
 
+/// Updates a source's events per second rate (call periodically)
+pub fn update_source_events_rate(source: &str, rate: f64) {
+    SOURCE_EVENTS_RATE.with_label_values(&[source]).set(rate);
+}
+
+/// Gets the last computed events per second rate for a source
+pub fn source_events_rate(source: &str) -> f64 {
+    SOURCE_EVENTS_RATE.with_label_values(&[source]).get()
+}
+
+/// Updates the seconds since a source last returned events
+pub fn update_source_last_event_age(source: &str, age_secs: f64) {
+    SOURCE_LAST_EVENT_AGE.with_label_values(&[source]).set(age_secs);
+}
+
+/// Gets the last recorded age of a source's latest events
+pub fn source_last_event_age(source: &str) -> f64 {
+    SOURCE_LAST_EVENT_AGE.with_label_values(&[source]).get()
+}
+
 // ============================================
 // METRICS COLLECTION
 // ============================================
 
 /// Collects all metrics as Prometheus text format
 pub fn gather_metrics() -> String {
+    try_gather_metrics().unwrap_or_else(|e| {
+        error!(error = %e, "Failed to encode metrics");
+        String::new()
+    })
+}
+
+/// Collects all metrics as Prometheus text format, surfacing encode errors
+pub fn try_gather_metrics() -> anyhow::Result<String> {
     let encoder = TextEncoder::new();
     let metric_families = prometheus::gather();
     
     let mut buffer = Vec::new();
-    if let Err(e) = encoder.encode(&metric_families, &mut buffer) {
-        error!(error = %e, "Failed to encode metrics");
-        return String::new();
-    }
+    encoder.encode(&metric_families, &mut buffer)?;
     
-    String::from_utf8(buffer).unwrap_or_default()
+    Ok(String::from_utf8(buffer)?)
+}
+
+/// Formats Prometheus text output as an aligned `series  value` table,
+/// sorted by series (histogram buckets are left out; `_sum` and `_count`
+/// stay)
+pub fn format_metrics_table(text: &str) -> String {
+    let mut rows: Vec<(&str, &str)> = text
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.rsplit_once(' '))
+        .filter(|(series, _)| {
+            let name = series.split('{').next().unwrap_or(series);
+            !name.ends_with("_bucket")
+        })
+        .collect();
+    rows.sort_unstable();
+
+    let width = rows.iter().map(|(series, _)| series.len()).max().unwrap_or(0);
+    rows.iter()
+        .map(|(series, value)| format!("{:<width$}  {}\n", series, value, width = width))
+        .collect()
 }
 
 /// A timer for measuring stage latency
@@ -289,6 +646,28 @@ impl Drop for StageTimer {
     }
 }
 
+/// A timer for measuring a single source fetch
+pub struct SourceFetchTimer {
+    source: &'static str,
+    start: std::time::Instant,
+}
+
+impl SourceFetchTimer {
+    pub fn new(source: &'static str) -> Self {
+        Self {
+            source,
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+impl Drop for SourceFetchTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed().as_secs_f64();
+        record_source_fetch_duration(self.source, elapsed);
+    }
+}
+
 /// Macro for timing a stage
 #[macro_export]
 macro_rules! time_stage {
@@ -302,7 +681,8 @@ macro_rules! time_stage {
 // METRICS SERVER
 // ============================================
 
-use hyper::{body::Incoming, server::conn::http1, service::service_fn, Request, Response};
+use hyper::{body::Incoming, header, server::conn::http1, service::service_fn, HeaderMap, Method, Request, Response, StatusCode};
+use std::io::Write;
 use hyper_util::rt::TokioIo;
 use http_body_util::Full;
 use hyper::body::Bytes;
@@ -310,24 +690,118 @@ use std::convert::Infallible;
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
 
+use crate::admin::{handle_admin, handle_readyz, json_response, not_found, AdminState};
+
 /// Handles metrics HTTP requests
-async fn handle_metrics(_req: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
-    let metrics = gather_metrics();
-    Ok(Response::new(Full::new(Bytes::from(metrics))))
+async fn handle_metrics(
+    req: Request<Incoming>,
+    admin: Option<AdminState>,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    Ok(route(req.method(), req.uri().path(), req.headers(), admin.as_ref()))
+}
+
+/// Routes a metrics server request
+///
+/// - `GET /metrics` - Prometheus text format, gzipped if accepted
+/// - `GET /readyz` and `/admin/*` - only when admin state is given
+///
+/// Anything else is a JSON 404; failures are JSON 500s.
+fn route(
+    method: &Method,
+    path: &str,
+    headers: &HeaderMap,
+    admin: Option<&AdminState>,
+) -> Response<Full<Bytes>> {
+    match (method, path, admin) {
+        (&Method::GET, "/metrics", admin) => {
+            if let Some(admin) = admin {
+                admin.record_event_ages();
+            }
+            metrics_response(accepts_gzip(headers))
+        }
+        (&Method::GET, "/readyz", Some(admin)) => handle_readyz(admin),
+        (_, path, Some(admin)) if path.starts_with("/admin/") => {
+            let authorization = headers
+                .get(header::AUTHORIZATION)
+                .and_then(|v| v.to_str().ok());
+            handle_admin(method, path, authorization, admin)
+        }
+        _ => not_found(),
+    }
+}
+
+/// Whether `Accept-Encoding` allows gzip (and doesn't set `q=0`)
+fn accepts_gzip(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|coding| {
+            let mut parts = coding.split(';').map(str::trim);
+            let name = parts.next().unwrap_or_default();
+            let rejected = parts.any(|p| {
+                p.strip_prefix("q=")
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .is_some_and(|q| q == 0.0)
+            });
+            (name.eq_ignore_ascii_case("gzip") || name == "*") && !rejected
+        })
+}
+
+/// Gzips a response body
+fn gzip(body: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(body)?;
+    encoder.finish()
+}
+
+/// Encodes all metrics, or a JSON 500 if encoding fails
+fn metrics_response(gzipped: bool) -> Response<Full<Bytes>> {
+    let body = try_gather_metrics().and_then(|metrics| {
+        if gzipped {
+            Ok(gzip(metrics.as_bytes())?)
+        } else {
+            Ok(metrics.into_bytes())
+        }
+    });
+
+    match body {
+        Ok(body) => {
+            let mut response = Response::new(Full::new(Bytes::from(body)));
+            let headers = response.headers_mut();
+            headers.insert(
+                header::CONTENT_TYPE,
+                header::HeaderValue::from_static(prometheus::TEXT_FORMAT),
+            );
+            if gzipped {
+                headers.insert(header::CONTENT_ENCODING, header::HeaderValue::from_static("gzip"));
+            }
+            headers.insert(header::VARY, header::HeaderValue::from_static("accept-encoding"));
+            response
+        }
+        Err(e) => {
+            error!(error = %e, "Failed to encode metrics");
+            json_response(StatusCode::INTERNAL_SERVER_ERROR, serde_json::json!({
+                "error": format!("failed to encode metrics: {}", e),
+            }))
+        }
+    }
 }
 
 /// Starts the metrics HTTP server
-pub async fn start_metrics_server(addr: SocketAddr) -> anyhow::Result<()> {
+pub async fn start_metrics_server(addr: SocketAddr, admin: Option<AdminState>) -> anyhow::Result<()> {
     let listener = TcpListener::bind(addr).await?;
     info!(address = %addr, "Metrics server listening");
 
     loop {
         let (stream, _) = listener.accept().await?;
         let io = TokioIo::new(stream);
+        let admin = admin.clone();
 
         tokio::spawn(async move {
             if let Err(e) = http1::Builder::new()
-                .serve_connection(io, service_fn(handle_metrics))
+                .serve_connection(io, service_fn(move |req| handle_metrics(req, admin.clone())))
                 .await
             {
                 error!(error = %e, "Error serving metrics connection");
@@ -340,6 +814,61 @@ pub async fn start_metrics_server(addr: SocketAddr) -> anyhow::Result<()> {
 // METRICS REPORTER
 // ============================================
 
+/// Per-stage and per-source event rates, computed from `EVENTS_PROCESSED`
+/// deltas between ticks
+///
+/// A source's rate counts its items at the fetch stage, so each event is
+/// counted once however many stages it passes through.
+#[derive(Default)]
+struct EventRates {
+    prev_counts: std::collections::HashMap<(String, String), u64>,
+}
+
+impl EventRates {
+    /// Updates the rate gauges from the counts since the previous tick
+    fn tick(&mut self, elapsed: std::time::Duration) {
+        use prometheus::core::Collector;
+
+        let secs = elapsed.as_secs_f64();
+        if secs <= 0.0 {
+            return;
+        }
+
+        let mut stage_deltas: std::collections::HashMap<String, u64> =
+            ALL_STAGES.iter().map(|stage| (stage.to_string(), 0)).collect();
+        let mut source_deltas: std::collections::HashMap<String, u64> = std::collections::HashMap::new();
+
+        for family in EVENTS_PROCESSED.collect() {
+            for metric in family.get_metric() {
+                let label = |name: &str| {
+                    metric
+                        .get_label()
+                        .iter()
+                        .find(|pair| pair.get_name() == name)
+                        .map(|pair| pair.get_value().to_string())
+                        .unwrap_or_default()
+                };
+                let (stage, source) = (label("stage"), label("source"));
+                let current = metric.get_counter().get_value() as u64;
+                let prev = self.prev_counts.insert((stage.clone(), source.clone()), current).unwrap_or(0);
+                let delta = current.saturating_sub(prev);
+
+                if stage == STAGE_FETCH {
+                    *source_deltas.entry(source).or_default() += delta;
+                }
+                *stage_deltas.entry(stage).or_default() += delta;
+            }
+        }
+
+        for (stage, delta) in stage_deltas {
+            update_events_rate(&stage, delta as f64 / secs);
+        }
+        for (source, delta) in source_deltas {
+            update_source_events_rate(&source, delta as f64 / secs);
+        }
+    }
+}
+
 /// Periodically reports metrics summary to logs
 pub struct MetricsReporter {
     interval: std::time::Duration,
@@ -360,23 +889,15 @@ impl MetricsReporter {
         let running = self.running.clone();
 
         tokio::spawn(async move {
-            let mut prev_counts: std::collections::HashMap<String, u64> = std::collections::HashMap::new();
+            let mut rates = EventRates::default();
+            let mut last_tick = std::time::Instant::now();
 
             while running.load(std::sync::atomic::Ordering::Relaxed) {
                 tokio::time::sleep(interval).await;
 
                 // Calculate rates
-                for stage in ALL_STAGES {
-                    let metric = EVENTS_PROCESSED.with_label_values(&[stage, "all"]);
-                    let current = metric.get();
-                    let key = stage.to_string();
-                    
-                    let prev = prev_counts.get(&key).copied().unwrap_or(0);
-                    let rate = (current - prev) as f64 / interval.as_secs_f64();
-                    
-                    update_events_rate(stage, rate);
-                    prev_counts.insert(key, current);
-                }
+                rates.tick(last_tick.elapsed());
+                last_tick = std::time::Instant::now();
 
                 // Log summary
                 info!(
@@ -411,6 +932,48 @@ mod tests {
         assert!(metrics.contains("ingestion_errors_total"));
     }
 
+    #[test]
+    fn test_event_rates_per_source() {
+        let mut rates = EventRates::default();
+        record_events_processed(STAGE_FETCH, "rate-a", 3);
+        rates.tick(std::time::Duration::from_secs(1));
+
+        record_events_processed(STAGE_FETCH, "rate-a", 10);
+        record_events_processed(STAGE_FETCH, "rate-b", 4);
+        record_events_processed(STAGE_NORMALIZE, "rate-b", 4);
+        rates.tick(std::time::Duration::from_secs(2));
+
+        assert_eq!(source_events_rate("rate-a"), 5.0);
+        assert_eq!(source_events_rate("rate-b"), 2.0);
+
+        // No new events: rates fall back to zero
+        rates.tick(std::time::Duration::from_secs(2));
+        assert_eq!(source_events_rate("rate-a"), 0.0);
+    }
+
+    #[test]
+    fn test_format_metrics_table() {
+        record_harvest_cycle("table-test");
+        record_harvest_cycle("table-test");
+        record_source_fetch_duration("table-test", 0.2);
+
+        let table = format_metrics_table(&gather_metrics());
+        let row = |series: &str| {
+            table
+                .lines()
+                .find(|line| line.starts_with(series))
+                .map(|line| line[series.len()..].trim().to_string())
+        };
+
+        assert_eq!(row(r#"ingestion_harvest_cycles_total{source="table-test"}"#).as_deref(), Some("2"));
+        assert_eq!(
+            row(r#"ingestion_source_fetch_duration_seconds_count{source="table-test"}"#).as_deref(),
+            Some("1")
+        );
+        assert!(!table.contains("_bucket"));
+        assert!(!table.contains("# HELP"));
+    }
+
     #[test]
     fn test_stage_timer() {
         {
@@ -421,4 +984,57 @@ mod tests {
         let metrics = gather_metrics();
         assert!(metrics.contains("ingestion_stage_latency_seconds"));
     }
+
+    #[test]
+    fn test_metrics_route_sets_prometheus_content_type() {
+        record_event_processed(STAGE_FETCH, "route-test");
+
+        let response = route(&Method::GET, "/metrics", &HeaderMap::new(), None);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/plain; version=0.0.4");
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_route_gzips_when_accepted() {
+        use http_body_util::BodyExt;
+        use std::io::Read;
+
+        record_event_processed(STAGE_FETCH, "gzip-test");
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT_ENCODING, "deflate, gzip;q=0.8".parse().unwrap());
+        let response = route(&Method::GET, "/metrics", &headers, None);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/plain; version=0.0.4");
+        assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let mut metrics = String::new();
+        flate2::read::GzDecoder::new(&body[..])
+            .read_to_string(&mut metrics)
+            .unwrap();
+        assert!(metrics.contains("ingestion_events_processed_total"));
+        assert!(metrics.contains("gzip-test"));
+
+        headers.insert(header::ACCEPT_ENCODING, "gzip;q=0".parse().unwrap());
+        assert!(!accepts_gzip(&headers));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_path_returns_json_404() {
+        use http_body_util::BodyExt;
+
+        let response = route(&Method::GET, "/nope", &HeaderMap::new(), None);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"], "not found");
+
+        // Admin routes don't exist without admin state
+        let response = route(&Method::GET, "/readyz", &HeaderMap::new(), None);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
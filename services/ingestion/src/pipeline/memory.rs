@@ -0,0 +1,137 @@
+//! Soft Memory Limit
+//!
+//! Bounded channels cap how many items are queued, but not how large they
+//! are: big payloads and enrich/embed buffers can still push RSS up. Above a
+//! configured soft limit the pipeline sheds Low-priority submissions until
+//! RSS drops back under it.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+use crate::metrics::{self, MEMORY_TYPE_RSS};
+use crate::schemas::Severity;
+
+/// How long an RSS reading is reused before sampling again
+pub const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+type Sampler = Box<dyn Fn() -> Option<u64> + Send + Sync>;
+
+/// Decides whether submissions are shed under memory pressure
+pub struct MemoryGuard {
+    /// Soft RSS limit in bytes (`None` never sheds)
+    limit: Option<u64>,
+    sample_interval: Duration,
+    sampler: Sampler,
+    /// Last reading and when it was taken
+    last_sample: parking_lot::Mutex<Option<(Instant, Option<u64>)>>,
+    shedding: AtomicBool,
+}
+
+impl MemoryGuard {
+    /// Creates a guard sampling the process RSS
+    pub fn new(limit: Option<u64>) -> Self {
+        Self::with_sampler(limit, DEFAULT_SAMPLE_INTERVAL, read_rss)
+    }
+
+    /// Creates a guard reading RSS from `sampler` at most once per `sample_interval`
+    pub fn with_sampler(
+        limit: Option<u64>,
+        sample_interval: Duration,
+        sampler: impl Fn() -> Option<u64> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            limit,
+            sample_interval,
+            sampler: Box::new(sampler),
+            last_sample: parking_lot::Mutex::new(None),
+            shedding: AtomicBool::new(false),
+        }
+    }
+
+    /// Whether an item of `priority` should be shed right now
+    ///
+    /// Only Low-priority items are shed. Logs once when shedding starts and
+    /// once when RSS drops back under the limit.
+    pub fn should_shed(&self, priority: &Severity) -> bool {
+        let Some(limit) = self.limit else {
+            return false;
+        };
+        if *priority != Severity::Low {
+            return false;
+        }
+
+        let Some(rss) = self.sample() else {
+            return false;
+        };
+        let over = rss > limit;
+        if over != self.shedding.swap(over, Ordering::SeqCst) {
+            if over {
+                warn!(rss_bytes = rss, limit_bytes = limit, "Memory above soft limit, shedding low-priority items");
+            } else {
+                info!(rss_bytes = rss, limit_bytes = limit, "Memory back under soft limit, accepting all items");
+            }
+        }
+        over
+    }
+
+    /// Gets the current RSS, reusing a reading younger than `sample_interval`
+    fn sample(&self) -> Option<u64> {
+        let mut last_sample = self.last_sample.lock();
+        if let Some((taken_at, rss)) = *last_sample {
+            if taken_at.elapsed() < self.sample_interval {
+                return rss;
+            }
+        }
+
+        let rss = (self.sampler)();
+        if let Some(rss) = rss {
+            metrics::set_memory_usage(MEMORY_TYPE_RSS, rss as i64);
+        }
+        *last_sample = Some((Instant::now(), rss));
+        rss
+    }
+}
+
+/// Reads the process resident set size (Linux only; `None` elsewhere)
+fn read_rss() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib: u64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU64;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_sheds_low_priority_until_rss_recovers() {
+        let rss = Arc::new(AtomicU64::new(2048));
+        let reading = rss.clone();
+        let guard = MemoryGuard::with_sampler(Some(1024), Duration::ZERO, move || {
+            Some(reading.load(Ordering::SeqCst))
+        });
+
+        assert!(guard.should_shed(&Severity::Low));
+        assert!(!guard.should_shed(&Severity::Medium));
+        assert!(!guard.should_shed(&Severity::High));
+
+        rss.store(512, Ordering::SeqCst);
+        assert!(!guard.should_shed(&Severity::Low));
+    }
+
+    #[test]
+    fn test_no_limit_never_sheds() {
+        let guard = MemoryGuard::with_sampler(None, Duration::ZERO, || Some(u64::MAX));
+        assert!(!guard.should_shed(&Severity::Low));
+    }
+}
@@ -10,28 +10,138 @@
 //! - Bounded channels for backpressure
 //! - Configurable worker pools per stage
 //! - Prometheus metrics per stage
-//! - Graceful shutdown support
+//! - Graceful shutdown support with a deadline (hung stages are aborted)
+//! - Optional per-source pipelines isolating slow sources (`SourcePipelines`)
 
+pub mod memory;
 pub mod stages;
 pub mod worker;
 
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{mpsc, broadcast, Semaphore};
-use tracing::{info, error, warn, debug, Instrument};
+use tokio::sync::{mpsc, broadcast, Mutex};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, error, warn, Instrument};
 
+use crate::append_log::{LogEntry, LogEntryType};
 use crate::config::Config;
 use crate::metrics::{self, STAGE_FETCH, STAGE_NORMALIZE, STAGE_ENRICH, STAGE_EMBED, STAGE_PUBLISH};
 use crate::schemas::IngestionEvent;
-use crate::message_bus::{MessageBus, ResilientPublisher};
+use crate::message_bus::{MessageBus, MessageConsumer, ResilientPublisher};
 
-use stages::{FetchStage, NormalizeStage, EnrichStage, EmbedStage, PublishStage};
-use worker::WorkerPool;
+use memory::MemoryGuard;
+use stages::{NormalizeStage, EnrichStage, EmbedStage, PayloadFilters, PublishStage};
+use worker::{BatchWorker, DeadLetterQueue, ErrorPolicy, InFlight, WorkerPool};
 
 // ============================================
 // PIPELINE CONFIGURATION
 // ============================================
 
+/// Default payload size limit (1 MiB)
+pub const DEFAULT_MAX_PAYLOAD_BYTES: u64 = 1024 * 1024;
+
+/// Events read per batch by `Pipeline::resubmit_from`
+const RESUBMIT_BATCH_SIZE: usize = 100;
+
+/// How long `Pipeline::resubmit_from` waits for a batch before stopping
+const RESUBMIT_READ_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Batches queued per `SourcePipelines` route before new ones have to wait
+const ROUTE_QUEUE_BATCHES: usize = 2;
+
+/// `SourcePipelines` route for sources without their own pipeline
+pub const SHARED_ROUTE: &str = "shared";
+
+/// Submit rejection action for batches dropped by a backed-up route
+pub const SKIP_ACTION: &str = "skip";
+
+/// Submit rejection action for Low-priority items shed above the memory limit
+pub const SHED_ACTION: &str = "shed";
+
+/// Payload flag marking events replayed with their original timestamps
+pub const REPLAYED_FLAG: &str = "replayed";
+
+/// What `Pipeline::submit` does once the fetch queue has stayed full for
+/// `submit_backpressure_timeout`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnFull {
+    /// Keep waiting for capacity
+    #[default]
+    Block,
+    /// Discard the item
+    Drop,
+    /// Return an error to the caller
+    Error,
+}
+
+impl OnFull {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OnFull::Block => "block",
+            OnFull::Drop => "drop",
+            OnFull::Error => "error",
+        }
+    }
+}
+
+/// Why an item couldn't be submitted to the pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum SubmitError {
+    /// The fetch queue is full (`try_submit` doesn't wait)
+    #[error("pipeline fetch stage is full")]
+    Full,
+    /// The pipeline has shut down and no longer accepts items
+    #[error("pipeline is closed")]
+    Closed,
+    /// The fetch queue stayed full for `submit_backpressure_timeout` under
+    /// `OnFull::Error`
+    #[error("pipeline fetch stage stayed full past the backpressure timeout")]
+    Timeout,
+}
+
+impl SubmitError {
+    /// Whether submitting again later may succeed (`Closed` is final)
+    pub fn is_retryable(self) -> bool {
+        !matches!(self, SubmitError::Closed)
+    }
+}
+
+/// Stream `OnError::DeadLetter` publishes failed items to by default
+pub const DEFAULT_DEAD_LETTER_STREAM: &str = "neuro:dead_letter";
+
+/// In-worker retries under `OnError::Retry` by default
+pub const DEFAULT_STAGE_MAX_RETRIES: u32 = 3;
+
+/// Longest a partial publish batch waits by default
+pub const DEFAULT_PUBLISH_FLUSH_INTERVAL_MS: u64 = 100;
+
+/// What a stage worker does with an item whose `Stage::process` failed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnError {
+    /// Log and discard the item
+    #[default]
+    Drop,
+    /// Retry in the worker up to `stage_max_retries` times, then discard
+    Retry,
+    /// Publish the item and its error to the dead-letter stream
+    DeadLetter,
+}
+
+impl OnError {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OnError::Drop => "drop",
+            OnError::Retry => "retry",
+            OnError::DeadLetter => "dead_letter",
+        }
+    }
+}
+
 /// Configuration for pipeline stages
 #[derive(Debug, Clone)]
 pub struct PipelineConfig {
@@ -50,14 +160,48 @@ pub struct PipelineConfig {
     pub normalize_batch_size: usize,
     pub enrich_batch_size: usize,
     pub embed_batch_size: usize,
+
+    /// Events per `publish_batch` call (above 1, a single batching worker
+    /// replaces the publish workers), and the longest a partial batch waits
     pub publish_batch_size: usize,
+    pub publish_flush_interval: Duration,
     
-    /// Timeouts
+    /// Longest a stage may spend on one item before it counts as failed,
+    /// with per-stage overrides
     pub stage_timeout: Duration,
+    pub stage_timeouts: HashMap<String, Duration>,
+    
+    /// Time to wait for stages to finish on shutdown before aborting them
+    pub shutdown_deadline: Duration,
+    
+    /// Payloads above this size are truncated in normalize
+    pub max_payload_bytes: u64,
+    
+    /// Payload keys kept per source in normalize
+    pub payload_filters: PayloadFilters,
+    
+    /// How long a submission waits on a full fetch queue before `on_full` applies
+    pub submit_backpressure_timeout: Duration,
+    pub on_full: OnFull,
+    
+    /// Soft RSS limit (bytes) above which Low-priority submissions are shed
+    pub max_memory_bytes: Option<u64>,
     
     /// Enable/disable stages
     pub enable_enrich: bool,
     pub enable_embed: bool,
+    
+    /// Embedding model name, and the dimension its embeddings must have
+    pub embedding_model: Option<String>,
+    pub embedding_dim: Option<usize>,
+    
+    /// Events whose data is older than this are tagged stale in enrich
+    pub stale_after: Option<Duration>,
+    
+    /// Error handling per stage name (stages not listed drop failed items)
+    pub on_error: HashMap<String, OnError>,
+    pub stage_max_retries: u32,
+    pub dead_letter_stream: String,
 }
 
 impl Default for PipelineConfig {
@@ -73,15 +217,34 @@ impl Default for PipelineConfig {
             normalize_batch_size: 50,
             enrich_batch_size: 10,
             embed_batch_size: 10,
-            publish_batch_size: 100,
+            publish_batch_size: 1,
+            publish_flush_interval: Duration::from_millis(DEFAULT_PUBLISH_FLUSH_INTERVAL_MS),
             stage_timeout: Duration::from_secs(30),
+            stage_timeouts: HashMap::new(),
+            shutdown_deadline: Duration::from_secs(30),
+            max_payload_bytes: DEFAULT_MAX_PAYLOAD_BYTES,
+            payload_filters: PayloadFilters::default(),
+            submit_backpressure_timeout: Duration::from_millis(100),
+            on_full: OnFull::Block,
+            max_memory_bytes: None,
             enable_enrich: true,
             enable_embed: false, // Disabled by default (requires embedding service)
+            embedding_model: None,
+            embedding_dim: None,
+            stale_after: None,
+            on_error: HashMap::new(),
+            stage_max_retries: DEFAULT_STAGE_MAX_RETRIES,
+            dead_letter_stream: DEFAULT_DEAD_LETTER_STREAM.to_string(),
         }
     }
 }
 
 impl PipelineConfig {
+    /// Gets the per-item timeout for a stage
+    pub fn stage_timeout_for(&self, stage_name: &str) -> Duration {
+        self.stage_timeouts.get(stage_name).copied().unwrap_or(self.stage_timeout)
+    }
+
     pub fn from_config(config: &Config) -> Self {
         Self {
             channel_capacity: config.pipeline_channel_capacity.unwrap_or(1000),
@@ -94,10 +257,34 @@ impl PipelineConfig {
             normalize_batch_size: 50,
             enrich_batch_size: 10,
             embed_batch_size: 10,
-            publish_batch_size: 100,
-            stage_timeout: Duration::from_secs(30),
+            publish_batch_size: config.pipeline_publish_batch_size.unwrap_or(1).max(1),
+            publish_flush_interval: Duration::from_millis(
+                config
+                    .pipeline_publish_flush_interval_ms
+                    .unwrap_or(DEFAULT_PUBLISH_FLUSH_INTERVAL_MS)
+                    .max(1),
+            ),
+            stage_timeout: Duration::from_secs(config.pipeline_stage_timeout_secs.unwrap_or(30)),
+            stage_timeouts: config.stage_timeouts().unwrap_or_default(),
+            shutdown_deadline: Duration::from_secs(config.pipeline_shutdown_deadline_secs.unwrap_or(30)),
+            max_payload_bytes: config.pipeline_max_payload_bytes.unwrap_or(DEFAULT_MAX_PAYLOAD_BYTES),
+            payload_filters: config.payload_filters().unwrap_or_default(),
+            submit_backpressure_timeout: Duration::from_millis(
+                config.pipeline_submit_backpressure_timeout_ms.unwrap_or(100),
+            ),
+            on_full: config.pipeline_on_full.unwrap_or_default(),
+            max_memory_bytes: config.pipeline_max_memory_bytes,
             enable_enrich: config.pipeline_enable_enrich.unwrap_or(true),
             enable_embed: config.pipeline_enable_embed.unwrap_or(false),
+            embedding_model: config.pipeline_embedding_model.clone(),
+            embedding_dim: config.pipeline_embedding_dim,
+            stale_after: config.pipeline_stale_after_secs.map(Duration::from_secs),
+            on_error: config.stage_on_error().unwrap_or_default(),
+            stage_max_retries: config.pipeline_stage_max_retries.unwrap_or(DEFAULT_STAGE_MAX_RETRIES),
+            dead_letter_stream: config
+                .pipeline_dead_letter_stream
+                .clone()
+                .unwrap_or_else(|| DEFAULT_DEAD_LETTER_STREAM.to_string()),
         }
     }
 }
@@ -126,6 +313,9 @@ pub struct PipelineItem {
     
     /// Embedding vector (added by embed stage)
     pub embedding: Option<Vec<f32>>,
+    
+    /// Replayed with its original timestamps (see `PipelineItem::replayed`)
+    pub replayed: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -135,10 +325,16 @@ pub struct EnrichmentData {
     pub related_tickers: Vec<String>,
     pub language: Option<String>,
     pub category: Option<String>,
+    /// Seconds between `data_timestamp` and enrichment (`None` if the
+    /// timestamp is missing or unparseable)
+    pub age_seconds: Option<i64>,
+    /// Older than the configured staleness threshold
+    pub stale: bool,
 }
 
 impl PipelineItem {
-    pub fn new(event: IngestionEvent, correlation_id: &str, source: &str) -> Self {
+    pub fn new(mut event: IngestionEvent, correlation_id: &str, source: &str) -> Self {
+        event.correlation_id.get_or_insert_with(|| correlation_id.to_string());
         Self {
             event,
             correlation_id: correlation_id.to_string(),
@@ -146,9 +342,47 @@ impl PipelineItem {
             entered_at: std::time::Instant::now(),
             enrichment: None,
             embedding: None,
+            replayed: false,
         }
     }
 
+    /// Creates an item replaying an already-ingested event
+    ///
+    /// The event keeps its `ingested_at` and `data_timestamp`, stages don't
+    /// stamp processing times on it, and its payload is flagged
+    /// `replayed: true` so downstream consumers can tell it from a live event.
+    pub fn replayed(mut event: IngestionEvent, correlation_id: &str, source: &str) -> Self {
+        event.payload.insert(REPLAYED_FLAG.to_string(), serde_json::json!(true));
+        Self {
+            replayed: true,
+            ..Self::new(event, correlation_id, source)
+        }
+    }
+
+    /// Rebuilds an item from a `NormalizedEvent` append-log entry, replaying
+    /// it with its original timestamps when `preserve_timestamps` is set
+    ///
+    /// Returns `None` for other entry types and unreadable events.
+    pub fn from_log_entry(entry: &LogEntry, correlation_id: &str, preserve_timestamps: bool) -> Option<Self> {
+        if !matches!(entry.entry_type, LogEntryType::NormalizedEvent) {
+            return None;
+        }
+        let event: IngestionEvent = match serde_json::from_value(entry.payload.clone()) {
+            Ok(event) => event,
+            Err(e) => {
+                warn!(entry_id = %entry.id, error = %e, "Skipping unreadable logged event");
+                metrics::record_unreadable_log_entry(&entry.source_id);
+                return None;
+            }
+        };
+        
+        Some(if preserve_timestamps {
+            Self::replayed(event, correlation_id, &entry.source_id)
+        } else {
+            Self::new(event, correlation_id, &entry.source_id)
+        })
+    }
+
     /// Gets pipeline latency so far
     pub fn latency(&self) -> Duration {
         self.entered_at.elapsed()
@@ -173,18 +407,30 @@ pub struct Pipeline {
     // Shutdown signal
     shutdown_tx: broadcast::Sender<()>,
     
-    // Worker handles
-    worker_handles: Vec<tokio::task::JoinHandle<()>>,
+    // Worker handles, keyed by stage name
+    worker_handles: Mutex<Vec<(&'static str, JoinHandle<()>)>>,
     
     // Publisher
     publisher: Arc<ResilientPublisher>,
+    
+    // Publisher for the high-priority (Critical) stream
+    priority_publisher: Option<Arc<ResilientPublisher>>,
+    
+    // Items submitted but not yet published or failed
+    in_flight: InFlight,
+    
+    // Sheds Low-priority submissions above the soft memory limit
+    memory_guard: MemoryGuard,
 }
 
 impl Pipeline {
     /// Creates a new pipeline
+    ///
+    /// Critical events are additionally published to `priority_bus` when provided.
     pub async fn new(
         config: PipelineConfig,
         message_bus: Box<dyn MessageBus>,
+        priority_bus: Option<Box<dyn MessageBus>>,
     ) -> anyhow::Result<Self> {
         // Create bounded channels
         let (fetch_tx, fetch_rx) = mpsc::channel(config.channel_capacity);
@@ -202,6 +448,9 @@ impl Pipeline {
             3,
             Duration::from_millis(100),
         ));
+        let priority_publisher = priority_bus.map(|bus| {
+            Arc::new(ResilientPublisher::new(bus, 3, Duration::from_millis(100)))
+        });
         
         // Set initial metrics
         metrics::set_queue_capacity(STAGE_FETCH, config.channel_capacity as i64);
@@ -214,7 +463,10 @@ impl Pipeline {
         metrics::set_worker_count(STAGE_NORMALIZE, config.normalize_workers as i64);
         metrics::set_worker_count(STAGE_ENRICH, config.enrich_workers as i64);
         metrics::set_worker_count(STAGE_EMBED, config.embed_workers as i64);
-        metrics::set_worker_count(STAGE_PUBLISH, config.publish_workers as i64);
+        let publish_workers = if config.publish_batch_size > 1 { 1 } else { config.publish_workers };
+        metrics::set_worker_count(STAGE_PUBLISH, publish_workers as i64);
+        
+        let memory_guard = MemoryGuard::new(config.max_memory_bytes);
         
         let mut pipeline = Self {
             config,
@@ -224,8 +476,11 @@ impl Pipeline {
             embed_tx: embed_tx.clone(),
             publish_tx: publish_tx.clone(),
             shutdown_tx,
-            worker_handles: Vec::new(),
+            worker_handles: Mutex::new(Vec::new()),
             publisher,
+            priority_publisher,
+            in_flight: InFlight::default(),
+            memory_guard,
         };
         
         // Spawn workers for each stage
@@ -245,6 +500,7 @@ impl Pipeline {
     }
 
     /// Spawns worker pools for each stage
+    #[allow(clippy::too_many_arguments)]
     async fn spawn_workers(
         &mut self,
         fetch_rx: mpsc::Receiver<PipelineItem>,
@@ -263,9 +519,13 @@ impl Pipeline {
             self.config.normalize_workers,
             fetch_rx,
             normalize_tx.clone(),
-            Box::new(NormalizeStage::new()),
+            Box::new(
+                NormalizeStage::new()
+                    .with_max_payload_bytes(self.config.max_payload_bytes)
+                    .with_payload_filters(self.config.payload_filters.clone()),
+            ),
         );
-        self.worker_handles.push(handle);
+        self.worker_handles.get_mut().push((STAGE_NORMALIZE, handle));
         
         // Determine next stage after normalize
         let next_after_normalize = if self.config.enable_enrich {
@@ -277,8 +537,8 @@ impl Pipeline {
         };
         
         // Connect normalize output to next stage
-        let handle = self.spawn_router(normalize_rx, next_after_normalize);
-        self.worker_handles.push(handle);
+        let handle = self.spawn_router(normalize_rx, vec![next_after_normalize]);
+        self.worker_handles.get_mut().push(("router", handle));
         
         // Enrich stage (if enabled)
         if self.config.enable_enrich {
@@ -293,9 +553,9 @@ impl Pipeline {
                 self.config.enrich_workers,
                 enrich_rx,
                 next_after_enrich,
-                Box::new(EnrichStage::new()),
+                Box::new(EnrichStage::new().with_stale_after(self.config.stale_after)),
             );
-            self.worker_handles.push(handle);
+            self.worker_handles.get_mut().push((STAGE_ENRICH, handle));
         }
         
         // Embed stage (if enabled)
@@ -305,19 +565,25 @@ impl Pipeline {
                 self.config.embed_workers,
                 embed_rx,
                 publish_tx.clone(),
-                Box::new(EmbedStage::new(None)),
+                Box::new(
+                    EmbedStage::new(None)
+                        .with_model(self.config.embedding_model.clone())
+                        .with_expected_dim(self.config.embedding_dim),
+                ),
             );
-            self.worker_handles.push(handle);
+            self.worker_handles.get_mut().push((STAGE_EMBED, handle));
         }
         
         // Publish stage
         let publisher = self.publisher.clone();
+        let priority_publisher = self.priority_publisher.clone();
         let handle = self.spawn_publish_workers(
             self.config.publish_workers,
             publish_rx,
             publisher,
+            priority_publisher,
         );
-        self.worker_handles.push(handle);
+        self.worker_handles.get_mut().push((STAGE_PUBLISH, handle));
         
         info!(
             normalize_workers = self.config.normalize_workers,
@@ -338,9 +604,11 @@ impl Pipeline {
         rx: mpsc::Receiver<PipelineItem>,
         tx: mpsc::Sender<PipelineItem>,
         stage: Box<dyn stages::Stage>,
-    ) -> tokio::task::JoinHandle<()> {
+    ) -> JoinHandle<()> {
         let shutdown_rx = self.shutdown_tx.subscribe();
-        let stage = Arc::new(stage);
+        let policy = self.error_policy(stage_name);
+        let in_flight = self.in_flight.clone();
+        let stage_timeout = self.config.stage_timeout_for(stage_name);
         
         tokio::spawn(async move {
             let pool = WorkerPool::new(
@@ -350,35 +618,36 @@ impl Pipeline {
                 tx,
                 stage,
                 shutdown_rx,
-            );
+            )
+            .with_error_policy(policy)
+            .with_in_flight(in_flight)
+            .with_stage_timeout(stage_timeout);
             
             pool.run().await;
         }.instrument(tracing::info_span!("stage_workers", stage = stage_name)))
     }
 
-    /// Spawns a router that forwards items between channels
+    /// Gets the configured error handling for a stage
+    fn error_policy(&self, stage_name: &str) -> ErrorPolicy {
+        ErrorPolicy {
+            on_error: self.config.on_error.get(stage_name).copied().unwrap_or_default(),
+            max_retries: self.config.stage_max_retries,
+            dead_letter: Some(DeadLetterQueue::new(
+                self.publisher.clone(),
+                &self.config.dead_letter_stream,
+            )),
+        }
+    }
+
+    /// Spawns a router that forwards items from one channel to one or more
+    /// downstream channels (see `run_router`)
     fn spawn_router(
         &self,
-        mut rx: mpsc::Receiver<PipelineItem>,
-        tx: mpsc::Sender<PipelineItem>,
-    ) -> tokio::task::JoinHandle<()> {
-        let mut shutdown_rx = self.shutdown_tx.subscribe();
-        
-        tokio::spawn(async move {
-            loop {
-                tokio::select! {
-                    Some(item) = rx.recv() => {
-                        if let Err(e) = tx.send(item).await {
-                            warn!(error = %e, "Router failed to forward item");
-                        }
-                    }
-                    _ = shutdown_rx.recv() => {
-                        info!("Router shutting down");
-                        break;
-                    }
-                }
-            }
-        })
+        rx: mpsc::Receiver<PipelineItem>,
+        txs: Vec<mpsc::Sender<PipelineItem>>,
+    ) -> JoinHandle<()> {
+        let shutdown_rx = self.shutdown_tx.subscribe();
+        tokio::spawn(run_router(rx, txs, self.in_flight.clone(), shutdown_rx))
     }
 
     /// Spawns publish workers
@@ -387,11 +656,39 @@ impl Pipeline {
         worker_count: usize,
         rx: mpsc::Receiver<PipelineItem>,
         publisher: Arc<ResilientPublisher>,
-    ) -> tokio::task::JoinHandle<()> {
+        priority_publisher: Option<Arc<ResilientPublisher>>,
+    ) -> JoinHandle<()> {
         let shutdown_rx = self.shutdown_tx.subscribe();
+        let embedding_model = self.config.embedding_model.clone();
+        let policy = self.error_policy(STAGE_PUBLISH);
+        let in_flight = self.in_flight.clone();
+        let stage_timeout = self.config.stage_timeout_for(STAGE_PUBLISH);
+        let batch_size = self.config.publish_batch_size;
+        let flush_interval = self.config.publish_flush_interval;
         
         tokio::spawn(async move {
-            let stage = PublishStage::new(publisher);
+            let stage = PublishStage::new(publisher)
+                .with_priority_publisher(priority_publisher)
+                .with_embedding_model(embedding_model);
+
+            if batch_size > 1 {
+                let worker = BatchWorker::new(
+                    STAGE_PUBLISH,
+                    batch_size,
+                    flush_interval,
+                    rx,
+                    mpsc::channel(1).0,
+                    Box::new(stage),
+                    shutdown_rx,
+                )
+                .with_error_policy(policy)
+                .with_in_flight(in_flight)
+                .with_stage_timeout(stage_timeout);
+
+                worker.run().await;
+                return;
+            }
+
             let pool = WorkerPool::new(
                 STAGE_PUBLISH,
                 worker_count,
@@ -400,50 +697,205 @@ impl Pipeline {
                 mpsc::channel(1).0, // Dummy sender that will never be used
                 Box::new(stage),
                 shutdown_rx,
-            );
+            )
+            .with_error_policy(policy)
+            .with_in_flight(in_flight)
+            .with_stage_timeout(stage_timeout);
             
             pool.run().await;
         }.instrument(tracing::info_span!("publish_workers")))
     }
 
     /// Submits an item to the pipeline (with backpressure)
-    pub async fn submit(&self, item: PipelineItem) -> anyhow::Result<()> {
+    ///
+    /// If the fetch queue stays full for `submit_backpressure_timeout`, the
+    /// item is handled according to `on_full`. Low-priority items are
+    /// discarded while RSS is above `max_memory_bytes`.
+    pub async fn submit(&self, item: PipelineItem) -> Result<(), SubmitError> {
         // Update queue depth metric
         let depth = self.config.channel_capacity - self.fetch_tx.capacity();
         metrics::set_queue_depth(STAGE_FETCH, depth as i64);
         
-        // Try to send with timeout to detect backpressure
-        match tokio::time::timeout(
-            Duration::from_millis(100),
-            self.fetch_tx.send(item.clone()),
-        ).await {
-            Ok(Ok(_)) => {
-                metrics::record_event_processed(STAGE_FETCH, &item.source);
-                Ok(())
-            }
-            Ok(Err(e)) => {
-                error!(error = %e, "Failed to submit to pipeline");
-                anyhow::bail!("Pipeline submission failed: {}", e)
+        let source = item.source.clone();
+        if self.send_to_fetch(item).await? {
+            metrics::record_event_processed(STAGE_FETCH, &source);
+        }
+        Ok(())
+    }
+
+    /// Submits an item only if the fetch queue has room right now
+    ///
+    /// Unlike `submit`, never waits and ignores `on_full`. Low-priority items
+    /// are discarded while RSS is above `max_memory_bytes`, like `submit`.
+    pub fn try_submit(&self, item: PipelineItem) -> Result<(), SubmitError> {
+        if self.shed(&item) {
+            return Ok(());
+        }
+        
+        let permit = self.fetch_tx.try_reserve().map_err(|e| match e {
+            mpsc::error::TrySendError::Full(()) => {
+                metrics::record_backpressure(STAGE_FETCH);
+                SubmitError::Full
             }
+            mpsc::error::TrySendError::Closed(()) => SubmitError::Closed,
+        })?;
+        
+        metrics::record_event_processed(STAGE_FETCH, &item.source);
+        self.in_flight.add();
+        permit.send(item);
+        Ok(())
+    }
+
+    /// Sends an item to the fetch stage, applying `on_full` under backpressure
+    ///
+    /// Returns `false` if the item was dropped or shed.
+    async fn send_to_fetch(&self, item: PipelineItem) -> Result<bool, SubmitError> {
+        if self.shed(&item) {
+            return Ok(false);
+        }
+        
+        let closed = |e| {
+            error!(error = %e, "Failed to submit to pipeline");
+            SubmitError::Closed
+        };
+        
+        // Wait for capacity with timeout to detect backpressure
+        let permit = match tokio::time::timeout(
+            self.config.submit_backpressure_timeout,
+            self.fetch_tx.reserve(),
+        ).await {
+            Ok(permit) => permit.map_err(closed)?,
             Err(_) => {
                 // Timeout - backpressure is active
                 metrics::record_backpressure(STAGE_FETCH);
-                warn!("Backpressure active on fetch stage, waiting...");
-                
-                // Wait for capacity
-                self.fetch_tx.send(item.clone()).await?;
-                metrics::record_event_processed(STAGE_FETCH, &item.source);
-                Ok(())
+                match self.config.on_full {
+                    OnFull::Block => {
+                        warn!("Backpressure active on fetch stage, waiting...");
+                        self.fetch_tx.reserve().await.map_err(closed)?
+                    }
+                    OnFull::Drop => {
+                        warn!(event_id = %item.event.id, "Fetch stage full, dropping item");
+                        metrics::record_submit_rejection(&item.source, OnFull::Drop.as_str());
+                        return Ok(false);
+                    }
+                    OnFull::Error => {
+                        metrics::record_submit_rejection(&item.source, OnFull::Error.as_str());
+                        return Err(SubmitError::Timeout);
+                    }
+                }
             }
+        };
+        
+        self.in_flight.add();
+        permit.send(item);
+        Ok(true)
+    }
+
+    /// Whether `item` is shed under memory pressure (counted as a rejection)
+    fn shed(&self, item: &PipelineItem) -> bool {
+        if !self.memory_guard.should_shed(&item.event.priority) {
+            return false;
         }
+        debug!(event_id = %item.event.id, "Memory above soft limit, shedding item");
+        metrics::record_submit_rejection(&item.source, SHED_ACTION);
+        true
     }
 
     /// Submits multiple items (with backpressure)
-    pub async fn submit_batch(&self, items: Vec<PipelineItem>) -> anyhow::Result<()> {
-        for item in items {
-            self.submit(item).await?;
+    ///
+    /// Each item is sent like `submit` (so `on_full` applies per item) and the
+    /// fetch counter is updated once per source rather than per item. Once an
+    /// item times out under `OnFull::Error`, the rest are only submitted if the
+    /// queue has room right away; each one that doesn't fit is counted as an
+    /// `error` rejection and `SubmitError::Timeout` is returned.
+    pub async fn submit_batch(&self, items: Vec<PipelineItem>) -> Result<(), SubmitError> {
+        if items.is_empty() {
+            return Ok(());
         }
-        Ok(())
+        
+        // Update queue depth metric
+        let available = self.fetch_tx.capacity();
+        let depth = self.config.channel_capacity - available;
+        metrics::set_queue_depth(STAGE_FETCH, depth as i64);
+        
+        if available < items.len() {
+            warn!(
+                batch_size = items.len(),
+                available,
+                "Backpressure active on fetch stage, waiting..."
+            );
+        }
+        
+        let mut counts: HashMap<String, u64> = HashMap::new();
+        let mut result = Ok(());
+        let mut items = items.into_iter();
+        for item in items.by_ref() {
+            let source = item.source.clone();
+            match self.send_to_fetch(item).await {
+                Ok(true) => *counts.entry(source).or_default() += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+        
+        if result == Err(SubmitError::Timeout) {
+            for item in items {
+                let source = item.source.clone();
+                match self.try_submit(item) {
+                    Ok(()) => {}
+                    Err(SubmitError::Closed) => {
+                        result = Err(SubmitError::Closed);
+                        break;
+                    }
+                    Err(_) => metrics::record_submit_rejection(&source, OnFull::Error.as_str()),
+                }
+            }
+        }
+        
+        for (source, count) in counts {
+            metrics::record_events_processed(STAGE_FETCH, &source, count);
+        }
+        result
+    }
+
+    /// Resubmits events read from `consumer` (e.g. a bus replay) until it
+    /// returns an empty batch or `limit` events have been submitted
+    ///
+    /// Returns the number of events submitted.
+    pub async fn resubmit_from(
+        &self,
+        consumer: &mut dyn MessageConsumer,
+        limit: Option<usize>,
+        correlation_id: &str,
+    ) -> anyhow::Result<usize> {
+        let mut submitted = 0;
+
+        loop {
+            let remaining = limit.map_or(RESUBMIT_BATCH_SIZE, |limit| limit - submitted);
+            if remaining == 0 {
+                break;
+            }
+
+            let messages = consumer
+                .read(remaining.min(RESUBMIT_BATCH_SIZE), RESUBMIT_READ_TIMEOUT)
+                .await?;
+            if messages.is_empty() {
+                break;
+            }
+
+            submitted += messages.len();
+            let items = messages
+                .into_iter()
+                .map(|message| PipelineItem::new(message.payload, correlation_id, "reprocess"))
+                .collect();
+            self.submit_batch(items).await?;
+        }
+
+        info!(submitted, "Resubmitted events to pipeline");
+        Ok(submitted)
     }
 
     /// Gets current pipeline stats
@@ -459,35 +911,354 @@ impl Pipeline {
     }
 
     /// Initiates graceful shutdown
+    ///
+    /// Stages still running after `shutdown_deadline` are aborted.
     pub async fn shutdown(&self) {
         info!("Initiating pipeline shutdown...");
         let _ = self.shutdown_tx.send(());
         
         // Wait for workers to finish
-        // Note: In a real implementation, we'd join the handles
-        tokio::time::sleep(Duration::from_secs(1)).await;
+        let handles = std::mem::take(&mut *self.worker_handles.lock().await);
+        let aborted = join_with_deadline(handles, self.config.shutdown_deadline).await;
         
-        info!("Pipeline shutdown complete");
+        if aborted.is_empty() {
+            info!("Pipeline shutdown complete");
+        } else {
+            warn!(aborted = ?aborted, "Pipeline shutdown complete with aborted stages");
+        }
     }
 
     /// Waits for all in-flight items to be processed
-    pub async fn drain(&self) {
-        info!("Draining pipeline...");
+    ///
+    /// An item counts until it is published or its failure handled, so items
+    /// a worker is still processing are waited for too. Logs the remaining
+    /// depth per stage every second and gives up with `DrainTimeout` if the
+    /// pipeline isn't empty within `timeout`.
+    pub async fn drain(&self, timeout: Duration) -> Result<(), DrainTimeout> {
+        info!(timeout_ms = timeout.as_millis() as u64, "Draining pipeline...");
         
-        // Wait until all queues are empty
-        let mut empty = false;
-        while !empty {
+        let started = tokio::time::Instant::now();
+        let mut last_progress = started;
+        
+        // Wait until all queues are empty and no item is being processed
+        loop {
+            let remaining = self.stats().non_empty_stages();
+            let in_flight = self.in_flight.get();
+            if remaining.is_empty() && in_flight == 0 {
+                break;
+            }
+            
+            if started.elapsed() >= timeout {
+                warn!(remaining = ?remaining, in_flight, "Pipeline drain timed out");
+                return Err(DrainTimeout { timeout, remaining, in_flight });
+            }
+            
+            if last_progress.elapsed() >= Duration::from_secs(1) {
+                info!(remaining = ?remaining, in_flight, "Draining pipeline, queues not yet empty");
+                last_progress = tokio::time::Instant::now();
+            }
+            
             tokio::time::sleep(Duration::from_millis(100)).await;
-            let stats = self.stats();
-            empty = stats.fetch_queue_depth == 0
-                && stats.normalize_queue_depth == 0
-                && stats.enrich_queue_depth == 0
-                && stats.embed_queue_depth == 0
-                && stats.publish_queue_depth == 0;
         }
         
         info!("Pipeline drained");
+        Ok(())
+    }
+}
+
+// ============================================
+// PER-SOURCE ISOLATION
+// ============================================
+
+/// Routes submissions to per-source pipelines so one stalled source (e.g. a
+/// slow publisher) doesn't block the rest
+///
+/// Without isolated sources every batch goes to the shared pipeline inline,
+/// like `Pipeline::submit_batch`. Otherwise each pipeline is fed by its own
+/// submit task. A batch for a route whose queue is full waits up to the
+/// shared pipeline's `submit_backpressure_timeout` (all routes wait at once),
+/// then the route's pipeline `on_full` applies: `Block` keeps waiting, `Drop`
+/// drops the batch (each item counted as a `skip` rejection) and `Error`
+/// returns `SubmitError::Timeout`. A submit task's own failure is returned by
+/// the next `submit_batch`.
+pub struct SourcePipelines {
+    shared: Arc<Pipeline>,
+    isolated: HashMap<String, Arc<Pipeline>>,
+    submitters: parking_lot::Mutex<HashMap<String, mpsc::Sender<Vec<PipelineItem>>>>,
+    submit_tasks: Mutex<Vec<JoinHandle<()>>>,
+    /// First submit task failure not yet returned by `submit_batch`
+    failure: Arc<parking_lot::Mutex<Option<SubmitError>>>,
+    closed: AtomicBool,
+}
+
+impl SourcePipelines {
+    /// Creates the router; `isolated` maps source ids to their own pipelines
+    pub fn new(shared: Arc<Pipeline>, isolated: HashMap<String, Arc<Pipeline>>) -> Self {
+        let mut submitters = HashMap::new();
+        let mut submit_tasks = Vec::new();
+        let failure = Arc::new(parking_lot::Mutex::new(None));
+        if !isolated.is_empty() {
+            let routes = isolated
+                .iter()
+                .map(|(source, pipeline)| (source.as_str(), pipeline))
+                .chain([(SHARED_ROUTE, &shared)]);
+            for (route, pipeline) in routes {
+                let (tx, handle) = spawn_submitter(route, pipeline.clone(), failure.clone());
+                submitters.insert(route.to_string(), tx);
+                submit_tasks.push(handle);
+            }
+        }
+        
+        Self {
+            shared,
+            isolated,
+            submitters: parking_lot::Mutex::new(submitters),
+            submit_tasks: Mutex::new(submit_tasks),
+            failure,
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Gets the pipeline a route submits to
+    fn route_pipeline(&self, route: &str) -> &Arc<Pipeline> {
+        self.isolated.get(route).unwrap_or(&self.shared)
+    }
+
+    /// Submits items, each to its source's pipeline (or the shared one)
+    ///
+    /// Fails with `SubmitError::Closed` once `close` has been called, and
+    /// with an earlier batch's error if a submit task failed to hand it to
+    /// its pipeline.
+    pub async fn submit_batch(&self, items: Vec<PipelineItem>) -> Result<(), SubmitError> {
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(SubmitError::Closed);
+        }
+        if self.isolated.is_empty() {
+            return self.shared.submit_batch(items).await;
+        }
+        
+        let mut batches: HashMap<&str, Vec<PipelineItem>> = HashMap::new();
+        for item in items {
+            let route = self
+                .isolated
+                .get_key_value(&item.event.source_id)
+                .map_or(SHARED_ROUTE, |(source, _)| source.as_str());
+            batches.entry(route).or_default().push(item);
+        }
+        
+        let sends = {
+            let submitters = self.submitters.lock();
+            let mut sends = Vec::with_capacity(batches.len());
+            for (route, batch) in batches {
+                let Some(submitter) = submitters.get(route) else {
+                    return Err(SubmitError::Closed);
+                };
+                let on_full = self.route_pipeline(route).config.on_full;
+                sends.push((route, submitter.clone(), batch, on_full));
+            }
+            sends
+        };
+        
+        let timeout = self.shared.config.submit_backpressure_timeout;
+        let closed = |route: &str| {
+            error!(route, "Submit task stopped");
+            SubmitError::Closed
+        };
+        let results = futures::future::join_all(sends.into_iter().map(|(route, submitter, batch, on_full)| async move {
+            let batch = match submitter.send_timeout(batch, timeout).await {
+                Ok(()) => return Ok(()),
+                Err(mpsc::error::SendTimeoutError::Timeout(batch)) => batch,
+                Err(mpsc::error::SendTimeoutError::Closed(_)) => return Err(closed(route)),
+            };
+            match on_full {
+                OnFull::Block => {
+                    warn!(route, "Source pipeline backed up, waiting...");
+                    submitter.send(batch).await.map_err(|_| closed(route))
+                }
+                OnFull::Drop => {
+                    warn!(route, dropped = batch.len(), "Source pipeline backed up, dropping batch");
+                    for item in &batch {
+                        metrics::record_submit_rejection(&item.event.source_id, SKIP_ACTION);
+                    }
+                    Ok(())
+                }
+                OnFull::Error => {
+                    for item in &batch {
+                        metrics::record_submit_rejection(&item.event.source_id, OnFull::Error.as_str());
+                    }
+                    Err(SubmitError::Timeout)
+                }
+            }
+        }))
+        .await;
+        results.into_iter().collect::<Result<(), _>>()?;
+        
+        match self.failure.lock().take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Gets stats per route, shared pipeline first
+    pub fn stats(&self) -> Vec<(&str, PipelineStats)> {
+        std::iter::once((SHARED_ROUTE, self.shared.stats()))
+            .chain(self.isolated.iter().map(|(source, pipeline)| (source.as_str(), pipeline.stats())))
+            .collect()
+    }
+
+    /// Stops accepting items and waits for the submit tasks to hand every
+    /// batch they hold to its pipeline
+    pub async fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.submitters.lock().clear();
+        
+        let tasks = std::mem::take(&mut *self.submit_tasks.lock().await);
+        for task in tasks {
+            if let Err(e) = task.await {
+                warn!(error = %e, "Submit task failed");
+            }
+        }
+    }
+
+    /// Drains every pipeline, sharing one `timeout`
+    ///
+    /// Keeps draining the others when one times out, returning the first
+    /// timeout.
+    pub async fn drain(&self, timeout: Duration) -> Result<(), DrainTimeout> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut result = Ok(());
+        
+        for pipeline in std::iter::once(&self.shared).chain(self.isolated.values()) {
+            let left = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if let Err(e) = pipeline.drain(left).await {
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+
+    /// Shuts down every pipeline's stage workers
+    pub async fn shutdown(&self) {
+        self.shared.shutdown().await;
+        for pipeline in self.isolated.values() {
+            pipeline.shutdown().await;
+        }
+    }
+}
+
+/// Spawns a task submitting batches to `pipeline` in order, until the
+/// returned sender is dropped and every queued batch is submitted
+///
+/// The first failure is kept in `failure` until `submit_batch` returns it.
+fn spawn_submitter(
+    route: &str,
+    pipeline: Arc<Pipeline>,
+    failure: Arc<parking_lot::Mutex<Option<SubmitError>>>,
+) -> (mpsc::Sender<Vec<PipelineItem>>, JoinHandle<()>) {
+    let (tx, mut rx) = mpsc::channel::<Vec<PipelineItem>>(ROUTE_QUEUE_BATCHES);
+    let span = tracing::info_span!("submitter", route = %route);
+    let handle = tokio::spawn(async move {
+        while let Some(batch) = rx.recv().await {
+            if let Err(e) = pipeline.submit_batch(batch).await {
+                error!(error = %e, "Failed to submit to pipeline");
+                failure.lock().get_or_insert(e);
+            }
+        }
+    }.instrument(span));
+    (tx, handle)
+}
+
+/// Forwards items to `txs` round-robin, skipping ahead to whichever
+/// downstream has the most free capacity so a slow sub-pool doesn't stall
+/// the others
+///
+/// Items that can't be forwarded leave the pipeline here, so they're
+/// counted out of `in_flight`.
+async fn run_router(
+    mut rx: mpsc::Receiver<PipelineItem>,
+    txs: Vec<mpsc::Sender<PipelineItem>>,
+    in_flight: InFlight,
+    mut shutdown_rx: broadcast::Receiver<()>,
+) {
+    let mut next = 0;
+    
+    loop {
+        tokio::select! {
+            Some(item) = rx.recv() => {
+                let Some(index) = pick_downstream(&txs, next) else {
+                    warn!("Router has no downstream, dropping item");
+                    in_flight.done();
+                    continue;
+                };
+                next = index + 1;
+                if let Err(e) = txs[index].send(item).await {
+                    warn!(error = %e, downstream = index, "Router failed to forward item");
+                    in_flight.done();
+                }
+            }
+            _ = shutdown_rx.recv() => {
+                info!("Router shutting down");
+                break;
+            }
+        }
+    }
+}
+
+/// Picks the downstream with the most free capacity, preferring the first
+/// one at or after `start` on ties (`None` if there are none)
+fn pick_downstream(txs: &[mpsc::Sender<PipelineItem>], start: usize) -> Option<usize> {
+    if txs.is_empty() {
+        return None;
+    }
+    let mut best = start % txs.len();
+    for offset in 1..txs.len() {
+        let index = (start + offset) % txs.len();
+        if txs[index].capacity() > txs[best].capacity() {
+            best = index;
+        }
+    }
+    Some(best)
+}
+
+/// Joins stage handles, aborting any that are still running at the deadline
+///
+/// Returns the names of the aborted stages.
+pub async fn join_with_deadline(
+    handles: Vec<(&'static str, JoinHandle<()>)>,
+    deadline: Duration,
+) -> Vec<&'static str> {
+    let deadline_at = tokio::time::Instant::now() + deadline;
+    let mut aborted = Vec::new();
+    
+    for (stage, mut handle) in handles {
+        match tokio::time::timeout_at(deadline_at, &mut handle).await {
+            Ok(Err(e)) if e.is_panic() => {
+                error!(stage, error = %e, "Stage task panicked");
+            }
+            Ok(_) => {}
+            Err(_) => {
+                handle.abort();
+                metrics::record_forced_abort(stage);
+                warn!(stage, deadline_ms = deadline.as_millis() as u64, "Stage did not finish before shutdown deadline, aborted");
+                aborted.push(stage);
+            }
+        }
     }
+    
+    aborted
+}
+
+/// Returned when the pipeline doesn't drain within the timeout
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("pipeline drain timed out after {timeout:?}, remaining: {remaining:?}, in flight: {in_flight}")]
+pub struct DrainTimeout {
+    pub timeout: Duration,
+    /// Stages with items still queued, and their depths
+    pub remaining: Vec<(&'static str, usize)>,
+    /// Items queued or being processed
+    pub in_flight: usize,
 }
 
 // ============================================
@@ -515,6 +1286,20 @@ impl PipelineStats {
             || self.publish_queue_depth > threshold
     }
 
+    /// Returns the stages with queued items, in pipeline order
+    pub fn non_empty_stages(&self) -> Vec<(&'static str, usize)> {
+        [
+            (STAGE_FETCH, self.fetch_queue_depth),
+            (STAGE_NORMALIZE, self.normalize_queue_depth),
+            (STAGE_ENRICH, self.enrich_queue_depth),
+            (STAGE_EMBED, self.embed_queue_depth),
+            (STAGE_PUBLISH, self.publish_queue_depth),
+        ]
+        .into_iter()
+        .filter(|(_, depth)| *depth > 0)
+        .collect()
+    }
+
     /// Returns the most congested stage
     pub fn bottleneck(&self) -> &'static str {
         let depths = [
@@ -532,6 +1317,61 @@ impl PipelineStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::message_bus::MockMessageBus;
+    use crate::schemas::{IngestionDataType, IngestionSourceType, Severity};
+    use async_trait::async_trait;
+
+    /// Pipeline with single-slot queues publishing to a gated bus that
+    /// hasn't been opened yet
+    async fn gated_pipeline(on_full: OnFull) -> (Pipeline, MockMessageBus) {
+        gated_pipeline_with_timeout(on_full, Duration::from_millis(50)).await
+    }
+
+    async fn gated_pipeline_with_timeout(
+        on_full: OnFull,
+        submit_backpressure_timeout: Duration,
+    ) -> (Pipeline, MockMessageBus) {
+        let bus = MockMessageBus::gated();
+        let config = PipelineConfig {
+            channel_capacity: 1,
+            fetch_workers: 1,
+            normalize_workers: 1,
+            publish_workers: 1,
+            enable_enrich: false,
+            submit_backpressure_timeout,
+            on_full,
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let pipeline = Pipeline::new(config, Box::new(bus.clone()), None).await.unwrap();
+        (pipeline, bus)
+    }
+
+    fn create_test_item(source: &str) -> PipelineItem {
+        let event = IngestionEvent::new(
+            IngestionSourceType::NewsApi,
+            "test".to_string(),
+            "Test".to_string(),
+            IngestionDataType::News,
+            HashMap::new(),
+        );
+        PipelineItem::new(event, "test-corr", source)
+    }
+
+    /// Stage that never finishes within a test's lifetime
+    struct HangingStage;
+
+    #[async_trait]
+    impl stages::Stage for HangingStage {
+        async fn process(&self, item: PipelineItem) -> anyhow::Result<PipelineItem> {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(item)
+        }
+
+        fn name(&self) -> &'static str {
+            "hanging"
+        }
+    }
 
     #[test]
     fn test_pipeline_config_default() {
@@ -554,4 +1394,678 @@ mod tests {
         assert!(stats.has_backpressure());
         assert_eq!(stats.bottleneck(), STAGE_FETCH);
     }
+
+    #[tokio::test]
+    async fn test_shutdown_aborts_stages_past_deadline() {
+        let (tx_in, rx_in) = mpsc::channel(10);
+        let (tx_out, _rx_out) = mpsc::channel(10);
+        let (shutdown_tx, _) = broadcast::channel(1);
+
+        let pool = WorkerPool::new(
+            "hanging",
+            1,
+            rx_in,
+            tx_out,
+            Box::new(HangingStage),
+            shutdown_tx.subscribe(),
+        );
+        let hanging = tokio::spawn(pool.run());
+
+        let mut shutdown_rx = shutdown_tx.subscribe();
+        let healthy = tokio::spawn(async move {
+            let _ = shutdown_rx.recv().await;
+        });
+
+        tx_in.send(create_test_item("test")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let aborts_before = metrics::forced_aborts_total("hanging");
+        shutdown_tx.send(()).unwrap();
+
+        let deadline = Duration::from_millis(200);
+        let started = std::time::Instant::now();
+        let aborted = join_with_deadline(
+            vec![("healthy", healthy), ("hanging", hanging)],
+            deadline,
+        ).await;
+
+        assert!(started.elapsed() < deadline + Duration::from_millis(300));
+        assert_eq!(aborted, vec!["hanging"]);
+        assert_eq!(metrics::forced_aborts_total("hanging"), aborts_before + 1);
+        assert_eq!(metrics::forced_aborts_total("healthy"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_records_fetch_count_once() {
+        let config = PipelineConfig {
+            channel_capacity: 200,
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let pipeline = Pipeline::new(config, Box::new(MockMessageBus::new()), None).await.unwrap();
+
+        let before = metrics::events_processed_total(STAGE_FETCH, "batch-test");
+        let items = (0..100).map(|_| create_test_item("batch-test")).collect();
+        pipeline.submit_batch(items).await.unwrap();
+
+        assert_eq!(metrics::events_processed_total(STAGE_FETCH, "batch-test"), before + 100);
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_drain_times_out_with_remaining_depths() {
+        let config = PipelineConfig {
+            channel_capacity: 10,
+            publish_workers: 1,
+            enable_enrich: false,
+            shutdown_deadline: Duration::from_millis(200),
+            ..PipelineConfig::default()
+        };
+        let pipeline = Pipeline::new(config, Box::new(MockMessageBus::gated()), None).await.unwrap();
+
+        // One item stalls in publish, the other four stay queued
+        let items = (0..5).map(|_| create_test_item("drain-test")).collect();
+        pipeline.submit_batch(items).await.unwrap();
+
+        let started = std::time::Instant::now();
+        let err = pipeline.drain(Duration::from_millis(500)).await.unwrap_err();
+
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(err.timeout, Duration::from_millis(500));
+        assert_eq!(err.remaining, vec![(STAGE_PUBLISH, 4)]);
+        assert_eq!(err.in_flight, 5);
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_publishes_to_mock_bus() {
+        let bus = MockMessageBus::new();
+        let config = PipelineConfig {
+            channel_capacity: 10,
+            enable_enrich: false,
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let pipeline = Pipeline::new(config, Box::new(bus.clone()), None).await.unwrap();
+
+        let items: Vec<PipelineItem> = (0..3).map(|_| create_test_item("mock-test")).collect();
+        let mut ids: Vec<String> = items.iter().map(|item| item.event.id.clone()).collect();
+        pipeline.submit_batch(items).await.unwrap();
+
+        let started = std::time::Instant::now();
+        while bus.published().len() < 3 && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let mut consumer = bus.subscribe("test-group", "test-consumer").await.unwrap();
+        let messages = consumer.read(10, Duration::from_millis(100)).await.unwrap();
+        let mut published: Vec<String> = messages.iter().map(|m| m.payload.id.clone()).collect();
+        ids.sort();
+        published.sort();
+        assert_eq!(published, ids);
+        assert!(messages.iter().all(|m| m.payload.correlation_id.as_deref() == Some("test-corr")));
+        assert_eq!(consumer.position().await.unwrap().as_deref(), Some("2"));
+
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_log_replay_preserves_original_timestamps() {
+        let bus = MockMessageBus::new();
+        let config = PipelineConfig {
+            channel_capacity: 10,
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let pipeline = Pipeline::new(config, Box::new(bus.clone()), None).await.unwrap();
+
+        let mut event = create_test_item("newsapi").event;
+        event.source_id = "newsapi".to_string();
+        event.payload.insert("title".to_string(), serde_json::json!("BTC rallies"));
+        event.payload.insert("publishedAt".to_string(), serde_json::json!("2024-01-01T10:00:00+02:00"));
+        event.ingested_at = "2024-01-01T08:05:00Z".to_string();
+        // As logged by the source adapter, before normalize reformats it
+        event.data_timestamp = Some("2024-01-01T08:00:00+00:00".to_string());
+        let mut entry = crate::append_log::LogEntry::raw_response("newsapi", "corr-1", "sess-1", serde_json::json!({}));
+        entry.entry_type = LogEntryType::NormalizedEvent;
+        entry.payload = serde_json::to_value(&event).unwrap();
+
+        let item = PipelineItem::from_log_entry(&entry, "replay-corr", true).unwrap();
+        pipeline.submit(item).await.unwrap();
+        pipeline.drain(Duration::from_secs(5)).await.unwrap();
+
+        let published = bus.published();
+        assert_eq!(published.len(), 1);
+        let replayed = &published[0];
+        assert_eq!(replayed.ingested_at, "2024-01-01T08:05:00Z");
+        assert_eq!(replayed.data_timestamp.as_deref(), Some("2024-01-01T08:00:00+00:00"));
+        assert_eq!(replayed.processing_started_at, None);
+        assert_eq!(replayed.processing_completed_at, None);
+        assert_eq!(replayed.payload.get(REPLAYED_FLAG), Some(&serde_json::json!(true)));
+        // Aged as of capture, not as of the replay
+        assert_eq!(replayed.payload["enrichment"]["age_seconds"], serde_json::json!(300));
+
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_resubmit_from_replays_into_second_stream() {
+        let source = MockMessageBus::new();
+        let items: Vec<PipelineItem> = (0..3).map(|_| create_test_item("original")).collect();
+        for item in &items {
+            source.publish(&item.event).await.unwrap();
+        }
+
+        let target = MockMessageBus::new();
+        let config = PipelineConfig {
+            channel_capacity: 10,
+            enable_enrich: false,
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let pipeline = Pipeline::new(config, Box::new(target.clone()), None).await.unwrap();
+
+        // Replay from the second event, then only one event from the start
+        let mut replay = source.replay("1").await.unwrap();
+        let submitted = pipeline.resubmit_from(replay.as_mut(), None, "reprocess-corr").await.unwrap();
+        assert_eq!(submitted, 2);
+        let mut replay = source.replay("0").await.unwrap();
+        let submitted = pipeline.resubmit_from(replay.as_mut(), Some(1), "reprocess-corr").await.unwrap();
+        assert_eq!(submitted, 1);
+
+        let started = std::time::Instant::now();
+        while target.published().len() < 3 && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let mut republished: Vec<String> = target.published().iter().map(|e| e.id.clone()).collect();
+        let mut expected = vec![items[1].event.id.clone(), items[2].event.id.clone(), items[0].event.id.clone()];
+        republished.sort();
+        expected.sort();
+        assert_eq!(republished, expected);
+        assert_eq!(source.published().len(), 3);
+
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_on_full_block_waits_for_capacity() {
+        let (pipeline, bus) = gated_pipeline(OnFull::Block).await;
+
+        // Fill every stage; a blocked submit outlives the backpressure timeout
+        let mut blocked = false;
+        for _ in 0..20 {
+            let submit = pipeline.submit(create_test_item("on-full-block"));
+            if tokio::time::timeout(Duration::from_millis(300), submit).await.is_err() {
+                blocked = true;
+                break;
+            }
+        }
+        assert!(blocked, "submit never waited on a full queue");
+        assert_eq!(metrics::submit_rejections_total("on-full-block", "drop"), 0);
+
+        bus.open_gate();
+        tokio::time::timeout(Duration::from_secs(5), pipeline.submit(create_test_item("on-full-block")))
+            .await
+            .expect("submit still blocked after capacity freed")
+            .unwrap();
+
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_timeout_counts_remaining_items() {
+        let (pipeline, bus) = gated_pipeline(OnFull::Error).await;
+
+        let items = (0..20).map(|_| create_test_item("batch-timeout")).collect();
+        assert_eq!(pipeline.submit_batch(items).await, Err(SubmitError::Timeout));
+
+        // Every item either entered the pipeline or was counted as rejected
+        let rejected = metrics::submit_rejections_total("batch-timeout", "error");
+        bus.open_gate();
+        pipeline.drain(Duration::from_secs(5)).await.unwrap();
+        let published = bus.published().len() as u64;
+        assert!(rejected > 1, "only the timed-out item was counted");
+        assert_eq!(published + rejected, 20);
+
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_on_full_drop_discards_and_counts() {
+        let (pipeline, bus) = gated_pipeline(OnFull::Drop).await;
+
+        let submitted_before = metrics::events_processed_total(STAGE_FETCH, "on-full-drop");
+        for _ in 0..20 {
+            pipeline.submit(create_test_item("on-full-drop")).await.unwrap();
+        }
+
+        let dropped = metrics::submit_rejections_total("on-full-drop", "drop");
+        let submitted = metrics::events_processed_total(STAGE_FETCH, "on-full-drop") - submitted_before;
+        assert!(dropped > 0, "no items were dropped");
+        assert_eq!(submitted + dropped, 20);
+
+        bus.open_gate();
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_on_full_error_returns_error() {
+        let (pipeline, bus) = gated_pipeline(OnFull::Error).await;
+
+        let mut error = None;
+        for _ in 0..20 {
+            if let Err(e) = pipeline.submit(create_test_item("on-full-error")).await {
+                error = Some(e);
+                break;
+            }
+        }
+
+        let error = error.expect("submit never failed on a full queue");
+        assert_eq!(error, SubmitError::Timeout);
+        assert!(error.is_retryable());
+        assert_eq!(metrics::submit_rejections_total("on-full-error", "error"), 1);
+
+        bus.open_gate();
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_router_distributes_across_downstreams() {
+        let (tx, rx) = mpsc::channel(10);
+        let (a_tx, a_rx) = mpsc::channel(100);
+        let (b_tx, mut b_rx) = mpsc::channel(100);
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let router = tokio::spawn(run_router(rx, vec![a_tx, b_tx], InFlight::default(), shutdown_rx));
+
+        for _ in 0..100 {
+            tx.send(create_test_item("router-test")).await.unwrap();
+        }
+        let started = std::time::Instant::now();
+        while a_rx.len() + b_rx.len() < 100 && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!((a_rx.len(), b_rx.len()), (50, 50));
+
+        // A downstream with less free capacity is skipped until it catches up
+        while b_rx.try_recv().is_ok() {}
+        for _ in 0..10 {
+            tx.send(create_test_item("router-test")).await.unwrap();
+        }
+        while b_rx.len() < 10 && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!((a_rx.len(), b_rx.len()), (50, 10));
+
+        shutdown_tx.send(()).unwrap();
+        router.await.unwrap();
+    }
+
+    #[test]
+    fn test_pick_downstream_without_downstreams() {
+        assert_eq!(pick_downstream(&[], 3), None);
+    }
+
+    #[tokio::test]
+    async fn test_router_counts_unforwarded_item_out_of_in_flight() {
+        let (tx, rx) = mpsc::channel(10);
+        let (closed_tx, closed_rx) = mpsc::channel(10);
+        drop(closed_rx);
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let in_flight = InFlight::default();
+        let router = tokio::spawn(run_router(rx, vec![closed_tx], in_flight.clone(), shutdown_rx));
+
+        in_flight.add();
+        tx.send(create_test_item("router-closed-test")).await.unwrap();
+        let started = std::time::Instant::now();
+        while in_flight.get() > 0 && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(in_flight.get(), 0);
+
+        shutdown_tx.send(()).unwrap();
+        router.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_embedded_item_publishes_embedding_record() {
+        use crate::message_bus::EMBEDDING_STREAM;
+        use crate::schemas::{EmbeddingModel, EmbeddingRecord, EmbeddingSourceType};
+
+        let bus = MockMessageBus::new();
+        let config = PipelineConfig {
+            channel_capacity: 10,
+            enable_enrich: false,
+            enable_embed: true,
+            embedding_model: Some("text-embedding-3-small".to_string()),
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let pipeline = Pipeline::new(config, Box::new(bus.clone()), None).await.unwrap();
+
+        let mut item = create_test_item("embed-test");
+        item.event.payload.insert("title".to_string(), serde_json::json!("Monad mainnet launch"));
+        let event_id = item.event.id.clone();
+        pipeline.submit(item).await.unwrap();
+
+        let started = std::time::Instant::now();
+        while bus.published_records(EMBEDDING_STREAM).is_empty() && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let records = bus.published_records(EMBEDDING_STREAM);
+        assert_eq!(records.len(), 1);
+        let record: EmbeddingRecord = serde_json::from_value(records[0].clone()).unwrap();
+        assert_eq!(record.source_id, event_id);
+        assert_eq!(record.source_type, EmbeddingSourceType::NewsItem);
+        assert_eq!(record.embedding_model, EmbeddingModel::TextEmbedding3Small);
+        assert_eq!(record.content, "Monad mainnet launch");
+        assert_eq!(record.embedding.len(), 16);
+        assert_eq!(record.embedding_dimension, 16);
+        assert!(chrono::DateTime::parse_from_rfc3339(&record.created_at).is_ok());
+        assert_eq!(bus.published().len(), 1);
+
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_stalled_isolated_source_does_not_block_others() {
+        let (stalled, stalled_bus) = gated_pipeline_with_timeout(OnFull::Drop, Duration::from_secs(5)).await;
+        let bus = MockMessageBus::new();
+        let config = PipelineConfig {
+            channel_capacity: 10,
+            enable_enrich: false,
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let shared = Pipeline::new(config, Box::new(bus.clone()), None).await.unwrap();
+        let pipelines = SourcePipelines::new(
+            Arc::new(shared),
+            HashMap::from([("newsapi".to_string(), Arc::new(stalled))]),
+        );
+
+        let item = |source_id: &str| {
+            let mut item = create_test_item("harvester");
+            item.event.source_id = source_id.to_string();
+            item
+        };
+
+        // newsapi never publishes; every round still returns promptly
+        for _ in 0..10 {
+            let batch = vec![item("newsapi"), item("newsapi"), item("cryptopanic")];
+            tokio::time::timeout(Duration::from_millis(500), pipelines.submit_batch(batch))
+                .await
+                .expect("submit blocked by the stalled source")
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        let started = std::time::Instant::now();
+        while bus.published().len() < 10 && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let published = bus.published();
+        assert_eq!(published.len(), 10);
+        assert!(published.iter().all(|event| event.source_id == "cryptopanic"));
+        assert!(metrics::submit_rejections_total("newsapi", SKIP_ACTION) > 0);
+
+        stalled_bus.open_gate();
+        pipelines.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_saturated_route_counts_every_dropped_item() {
+        let (stalled, bus) = gated_pipeline_with_timeout(OnFull::Drop, Duration::from_secs(5)).await;
+        let (shared, _) = gated_pipeline(OnFull::Block).await;
+        let pipelines = SourcePipelines::new(
+            Arc::new(shared),
+            HashMap::from([("saturated-route".to_string(), Arc::new(stalled))]),
+        );
+        let rejected_before = metrics::submit_rejections_total("saturated-route", SKIP_ACTION);
+
+        for _ in 0..10 {
+            let batch: Vec<_> = (0..3)
+                .map(|_| {
+                    let mut item = create_test_item("harvester");
+                    item.event.source_id = "saturated-route".to_string();
+                    item
+                })
+                .collect();
+            tokio::time::timeout(Duration::from_millis(500), pipelines.submit_batch(batch))
+                .await
+                .expect("submit waited past the route deadline")
+                .unwrap();
+        }
+
+        let dropped = metrics::submit_rejections_total("saturated-route", SKIP_ACTION) - rejected_before;
+        assert!(dropped > 0);
+        assert_eq!(dropped % 3, 0, "only whole batches are dropped");
+
+        // Everything not counted as dropped is still delivered
+        bus.open_gate();
+        pipelines.close().await;
+        pipelines.drain(Duration::from_secs(5)).await.unwrap();
+        let published = bus.published().len() as u64;
+        assert_eq!(published + dropped, 30);
+
+        pipelines.shutdown().await;
+    }
+
+    fn route_item(source_id: &str) -> PipelineItem {
+        let mut item = create_test_item("harvester");
+        item.event.source_id = source_id.to_string();
+        item
+    }
+
+    #[tokio::test]
+    async fn test_backed_up_route_waits_under_block() {
+        let (stalled, bus) = gated_pipeline_with_timeout(OnFull::Block, Duration::from_secs(5)).await;
+        let (shared, _) = gated_pipeline(OnFull::Block).await;
+        let pipelines = SourcePipelines::new(
+            Arc::new(shared),
+            HashMap::from([("blocking-route".to_string(), Arc::new(stalled))]),
+        );
+
+        let mut submitted = 0;
+        let mut pending = None;
+        for _ in 0..20 {
+            let mut submit = Box::pin(pipelines.submit_batch(vec![route_item("blocking-route")]));
+            submitted += 1;
+            if tokio::time::timeout(Duration::from_millis(300), &mut submit).await.is_err() {
+                pending = Some(submit);
+                break;
+            }
+        }
+        let submit = pending.expect("route never backed up");
+
+        // The waiting batch goes through once the route frees up
+        bus.open_gate();
+        tokio::time::timeout(Duration::from_secs(5), submit)
+            .await
+            .expect("submit still blocked after the route freed up")
+            .unwrap();
+        pipelines.close().await;
+        pipelines.drain(Duration::from_secs(5)).await.unwrap();
+        assert_eq!(bus.published().len(), submitted);
+        assert_eq!(metrics::submit_rejections_total("blocking-route", SKIP_ACTION), 0);
+
+        pipelines.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_backed_up_route_errors_under_error() {
+        let (stalled, bus) = gated_pipeline_with_timeout(OnFull::Error, Duration::from_secs(5)).await;
+        let (shared, _) = gated_pipeline(OnFull::Block).await;
+        let pipelines = SourcePipelines::new(
+            Arc::new(shared),
+            HashMap::from([("erroring-route".to_string(), Arc::new(stalled))]),
+        );
+        let rejected_before = metrics::submit_rejections_total("erroring-route", OnFull::Error.as_str());
+
+        let mut error = None;
+        for _ in 0..20 {
+            let batch = vec![route_item("erroring-route"), route_item("erroring-route")];
+            let result = tokio::time::timeout(Duration::from_millis(500), pipelines.submit_batch(batch))
+                .await
+                .expect("submit waited past the route deadline");
+            if let Err(e) = result {
+                error = Some(e);
+                break;
+            }
+        }
+
+        assert_eq!(error, Some(SubmitError::Timeout));
+        assert_eq!(
+            metrics::submit_rejections_total("erroring-route", OnFull::Error.as_str()) - rejected_before,
+            2
+        );
+
+        bus.open_gate();
+        pipelines.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_submit_task_failure_is_returned_by_next_submit() {
+        let (stalled, bus) = gated_pipeline(OnFull::Error).await;
+        let config = PipelineConfig {
+            channel_capacity: 10,
+            enable_enrich: false,
+            submit_backpressure_timeout: Duration::from_secs(5),
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let shared = Pipeline::new(config, Box::new(MockMessageBus::new()), None).await.unwrap();
+        let pipelines = SourcePipelines::new(
+            Arc::new(shared),
+            HashMap::from([("failing-route".to_string(), Arc::new(stalled))]),
+        );
+
+        // The route itself never times out; the error comes from its submit task
+        let mut error = None;
+        for _ in 0..20 {
+            let result = tokio::time::timeout(
+                Duration::from_secs(1),
+                pipelines.submit_batch(vec![route_item("failing-route")]),
+            )
+            .await
+            .expect("submit waited past the route deadline");
+            if let Err(e) = result {
+                error = Some(e);
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(60)).await;
+        }
+        assert_eq!(error, Some(SubmitError::Timeout));
+
+        bus.open_gate();
+        pipelines.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_low_priority_items_shed_above_memory_limit() {
+        let bus = MockMessageBus::new();
+        let config = PipelineConfig {
+            channel_capacity: 10,
+            enable_enrich: false,
+            shutdown_deadline: Duration::from_secs(1),
+            max_memory_bytes: Some(1),
+            ..PipelineConfig::default()
+        };
+        let mut pipeline = Pipeline::new(config, Box::new(bus.clone()), None).await.unwrap();
+        pipeline.memory_guard = MemoryGuard::with_sampler(Some(1), Duration::ZERO, || Some(1 << 30));
+
+        let item = |priority| {
+            let mut item = create_test_item("memory-shed");
+            item.event.priority = priority;
+            item
+        };
+        pipeline.submit(item(Severity::Low)).await.unwrap();
+        pipeline.try_submit(item(Severity::Low)).unwrap();
+        let high = item(Severity::High);
+        let high_id = high.event.id.clone();
+        pipeline.submit(high).await.unwrap();
+
+        let started = std::time::Instant::now();
+        while bus.published().is_empty() && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        pipeline.drain(Duration::from_secs(1)).await.unwrap();
+
+        let published: Vec<String> = bus.published().iter().map(|event| event.id.clone()).collect();
+        assert_eq!(published, vec![high_id]);
+        assert_eq!(metrics::submit_rejections_total("memory-shed", SHED_ACTION), 2);
+
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_submit_to_closed_pipeline_returns_closed() {
+        let (pipeline, _bus) = gated_pipeline(OnFull::Error).await;
+        pipeline.shutdown().await;
+
+        assert_eq!(pipeline.submit(create_test_item("closed-test")).await, Err(SubmitError::Closed));
+        assert_eq!(pipeline.try_submit(create_test_item("closed-test")), Err(SubmitError::Closed));
+        assert!(!SubmitError::Closed.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_try_submit_returns_full_without_waiting() {
+        let (pipeline, bus) = gated_pipeline(OnFull::Block).await;
+
+        let mut error = None;
+        for _ in 0..20 {
+            if let Err(e) = pipeline.try_submit(create_test_item("try-submit-test")) {
+                error = Some(e);
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(error, Some(SubmitError::Full));
+
+        bus.open_gate();
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_publish_batches_up_to_batch_size() {
+        let bus = MockMessageBus::new();
+        let config = PipelineConfig {
+            channel_capacity: 200,
+            enable_enrich: false,
+            publish_batch_size: 50,
+            // Long enough that only full batches are sent
+            publish_flush_interval: Duration::from_secs(60),
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let pipeline = Pipeline::new(config, Box::new(bus.clone()), None).await.unwrap();
+
+        let items = (0..100).map(|_| create_test_item("publish-batch-test")).collect();
+        pipeline.submit_batch(items).await.unwrap();
+        pipeline.drain(Duration::from_secs(5)).await.unwrap();
+
+        assert_eq!(bus.batch_sizes(), vec![50, 50]);
+        pipeline.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_partial_publish_batch_flushed_after_interval() {
+        let bus = MockMessageBus::new();
+        let config = PipelineConfig {
+            channel_capacity: 10,
+            enable_enrich: false,
+            publish_batch_size: 50,
+            publish_flush_interval: Duration::from_millis(50),
+            shutdown_deadline: Duration::from_secs(1),
+            ..PipelineConfig::default()
+        };
+        let pipeline = Pipeline::new(config, Box::new(bus.clone()), None).await.unwrap();
+
+        let items = (0..3).map(|_| create_test_item("publish-flush-test")).collect();
+        pipeline.submit_batch(items).await.unwrap();
+        pipeline.drain(Duration::from_secs(5)).await.unwrap();
+
+        assert_eq!(bus.batch_sizes().iter().sum::<usize>(), 3);
+        pipeline.shutdown().await;
+    }
 }
@@ -4,12 +4,17 @@
 //! Stages are composable and can be enabled/disabled via config.
 
 use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use std::time::Instant;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, warn};
 
+use crate::dedup::{canonical_payload_json, payload_hash};
 use crate::metrics::{self, StageTimer};
-use crate::schemas::{IngestionEvent, Status};
+use crate::schemas::{
+    EmbeddingModel, EmbeddingRecord, EmbeddingSourceType, IngestionDataType, IngestionEvent, Severity,
+    Status,
+};
 use crate::message_bus::ResilientPublisher;
 use super::{PipelineItem, EnrichmentData};
 
@@ -22,6 +27,18 @@ pub trait Stage: Send + Sync {
     /// Process a single item
     async fn process(&self, item: PipelineItem) -> anyhow::Result<PipelineItem>;
     
+    /// Process several items, returning a result per item in order
+    ///
+    /// Stages with a bulk path (publish) override this; by default items are
+    /// processed one at a time.
+    async fn process_batch(&self, items: Vec<PipelineItem>) -> Vec<anyhow::Result<PipelineItem>> {
+        let mut results = Vec::with_capacity(items.len());
+        for item in items {
+            results.push(self.process(item).await);
+        }
+        results
+    }
+
     /// Stage name for metrics
     fn name(&self) -> &'static str;
     
@@ -39,6 +56,12 @@ pub trait Stage: Send + Sync {
 /// (This stage is actually external - items are submitted directly)
 pub struct FetchStage;
 
+impl Default for FetchStage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl FetchStage {
     pub fn new() -> Self {
         Self
@@ -61,38 +84,177 @@ impl Stage for FetchStage {
 // NORMALIZE STAGE
 // ============================================
 
+/// Per-source payload key allow/deny lists, keyed by source id
+///
+/// A source with an allow-list keeps only those keys; denied keys are
+/// always dropped. Sources without either keep their payload as is.
+#[derive(Debug, Clone, Default)]
+pub struct PayloadFilters {
+    allow: HashMap<String, HashSet<String>>,
+    deny: HashMap<String, HashSet<String>>,
+}
+
+impl PayloadFilters {
+    pub fn new(allow: HashMap<String, HashSet<String>>, deny: HashMap<String, HashSet<String>>) -> Self {
+        Self { allow, deny }
+    }
+
+    /// Checks if no source has a field list
+    pub fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+
+    /// Strips the payload keys the event's source doesn't keep, refreshing
+    /// `payload_size` and `payload_hash`. Returns the number of keys removed.
+    pub fn apply(&self, event: &mut IngestionEvent) -> usize {
+        let allow = self.allow.get(&event.source_id);
+        let deny = self.deny.get(&event.source_id);
+        if allow.is_none() && deny.is_none() {
+            return 0;
+        }
+
+        let before = event.payload.len();
+        event.payload.retain(|key, _| {
+            allow.is_none_or(|allow| allow.contains(key)) && !deny.is_some_and(|deny| deny.contains(key))
+        });
+        let removed = before - event.payload.len();
+        if removed > 0 {
+            event.payload_size = canonical_payload_json(&event.payload).len() as u64;
+            event.payload_hash = Some(payload_hash(&event.payload));
+        }
+        removed
+    }
+}
+
 /// Normalize stage - standardizes data format and validates
 pub struct NormalizeStage {
-    // Add any normalization rules here
+    /// Payload field holding the source timestamp, per source id
+    timestamp_fields: HashMap<String, String>,
+    /// Payload keys kept per source
+    payload_filters: PayloadFilters,
+    /// Payloads above this size are truncated
+    max_payload_bytes: u64,
+}
+
+/// Bulky payload fields dropped (in order) from oversized payloads
+const BULKY_PAYLOAD_FIELDS: &[&str] = &["raw", "content"];
+
+impl Default for NormalizeStage {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl NormalizeStage {
     pub fn new() -> Self {
-        Self {}
+        let timestamp_fields = [
+            ("newsapi", "publishedAt"),
+            ("cryptopanic", "publishedAt"),
+            ("x_api", "createdAt"),
+        ]
+        .into_iter()
+        .map(|(source, field)| (source.to_string(), field.to_string()))
+        .collect();
+
+        Self {
+            timestamp_fields,
+            payload_filters: PayloadFilters::default(),
+            max_payload_bytes: super::DEFAULT_MAX_PAYLOAD_BYTES,
+        }
+    }
+
+    /// Sets the per-source payload keys to keep
+    pub fn with_payload_filters(mut self, payload_filters: PayloadFilters) -> Self {
+        self.payload_filters = payload_filters;
+        self
+    }
+
+    /// Sets the payload size above which bulky fields are dropped
+    pub fn with_max_payload_bytes(mut self, max_payload_bytes: u64) -> Self {
+        self.max_payload_bytes = max_payload_bytes;
+        self
+    }
+
+    /// Drops bulky fields from an oversized payload and flags it `truncated`
+    fn truncate_payload(&self, event: &mut IngestionEvent) {
+        if event.payload_size <= self.max_payload_bytes {
+            return;
+        }
+
+        let original_size = event.payload_size;
+        event.payload.insert("truncated".to_string(), serde_json::json!(true));
+        for field in BULKY_PAYLOAD_FIELDS {
+            if event.payload.remove(*field).is_some() {
+                event.payload_size = canonical_payload_json(&event.payload).len() as u64;
+                if event.payload_size <= self.max_payload_bytes {
+                    break;
+                }
+            }
+        }
+        event.payload_size = canonical_payload_json(&event.payload).len() as u64;
+        event.payload_hash = Some(payload_hash(&event.payload));
+
+        metrics::record_truncated_payload(&event.source_id);
+        warn!(
+            event_id = %event.id,
+            source = %event.source_id,
+            original_size,
+            truncated_size = event.payload_size,
+            limit = self.max_payload_bytes,
+            "Truncated oversized payload"
+        );
+    }
+
+    /// Maps a source's timestamp payload field, used to fill `data_timestamp`
+    pub fn with_timestamp_field(mut self, source_id: impl Into<String>, field: impl Into<String>) -> Self {
+        self.timestamp_fields.insert(source_id.into(), field.into());
+        self
     }
     
-    fn normalize_event(&self, event: &mut IngestionEvent) {
-        // Ensure required fields are present
+    /// Sets `data_timestamp` (RFC3339, UTC) from the source's mapped payload field.
+    /// Returns a validation error if the field can't be parsed.
+    fn normalize_timestamp(&self, event: &mut IngestionEvent) -> Option<String> {
+        let field = self.timestamp_fields.get(&event.source_id)?;
+        let value = event.payload.get(field)?;
+
+        match parse_timestamp(value) {
+            Some(timestamp) => {
+                event.data_timestamp = Some(timestamp.to_rfc3339_opts(SecondsFormat::Secs, true));
+                None
+            }
+            None => Some(format!("Unparseable timestamp in {}: {}", field, value)),
+        }
+    }
+
+    fn normalize_event(&self, event: &mut IngestionEvent, replayed: bool) -> Vec<String> {
+        let mut errors = Vec::new();
+        // Replays keep the data timestamp they were captured with
+        if !(replayed && event.data_timestamp.is_some()) {
+            errors.extend(self.normalize_timestamp(event));
+        }
+
+        // Strip unwanted payload keys (after the timestamp field is read)
+        self.payload_filters.apply(event);
+        
+        // Backfill the canonical content hash (see `dedup::payload_hash`)
         if event.payload_hash.is_none() {
-            use sha2::{Sha256, Digest};
-            let payload_json = serde_json::to_string(&event.payload).unwrap_or_default();
-            let hash = Sha256::digest(payload_json.as_bytes());
-            event.payload_hash = Some(format!("sha256:{}", hex::encode(hash)));
+            event.payload_hash = Some(payload_hash(&event.payload));
         }
         
-        // Normalize status
-        if event.status == Status::Pending {
+        // Normalize status (replays aren't stamped with processing times)
+        if event.status == Status::Pending && !replayed {
             event.status = Status::Processing;
             event.processing_started_at = Some(chrono::Utc::now().to_rfc3339());
         }
         
         // Calculate payload size if not set
         if event.payload_size == 0 {
-            let size = serde_json::to_string(&event.payload)
-                .map(|s| s.len() as u64)
-                .unwrap_or(0);
-            event.payload_size = size;
+            event.payload_size = canonical_payload_json(&event.payload).len() as u64;
         }
+
+        self.truncate_payload(event);
+
+        errors
     }
     
     fn validate_event(&self, event: &IngestionEvent) -> Vec<String> {
@@ -120,10 +282,10 @@ impl Stage for NormalizeStage {
         let _timer = StageTimer::new(self.name());
         
         // Normalize the event
-        self.normalize_event(&mut item.event);
+        let mut errors = self.normalize_event(&mut item.event, item.replayed);
         
         // Validate
-        let errors = self.validate_event(&item.event);
+        errors.extend(self.validate_event(&item.event));
         if !errors.is_empty() {
             item.event.validation_errors = errors.clone();
             item.event.is_valid = false;
@@ -148,6 +310,30 @@ impl Stage for NormalizeStage {
     }
 }
 
+/// Parses a source timestamp: RFC3339 (`publishedAt`), RFC2822 or the legacy
+/// Twitter format (`createdAt`), or Unix seconds/milliseconds (`created_utc`)
+fn parse_timestamp(value: &serde_json::Value) -> Option<DateTime<Utc>> {
+    let epoch = |n: f64| {
+        // Values past ~2286 in seconds are milliseconds
+        let millis = if n.abs() >= 1e10 { n } else { n * 1000.0 };
+        DateTime::from_timestamp_millis(millis as i64)
+    };
+
+    match value {
+        serde_json::Value::Number(n) => n.as_f64().and_then(epoch),
+        serde_json::Value::String(s) => {
+            let s = s.trim();
+            DateTime::parse_from_rfc3339(s)
+                .or_else(|_| DateTime::parse_from_rfc2822(s))
+                .or_else(|_| DateTime::parse_from_str(s, "%a %b %d %H:%M:%S %z %Y"))
+                .map(|dt| dt.with_timezone(&Utc))
+                .ok()
+                .or_else(|| s.parse::<f64>().ok().and_then(epoch))
+        }
+        _ => None,
+    }
+}
+
 // ============================================
 // ENRICH STAGE
 // ============================================
@@ -155,11 +341,45 @@ impl Stage for NormalizeStage {
 /// Enrich stage - adds metadata, sentiment, entity extraction
 pub struct EnrichStage {
     // Add enrichment services here (e.g., NLP, entity extraction)
+    /// Events whose data is older than this are tagged stale
+    stale_after: Option<std::time::Duration>,
+}
+
+impl Default for EnrichStage {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EnrichStage {
     pub fn new() -> Self {
-        Self {}
+        Self { stale_after: None }
+    }
+
+    /// Sets the age above which events are tagged stale
+    pub fn with_stale_after(mut self, stale_after: Option<std::time::Duration>) -> Self {
+        self.stale_after = stale_after;
+        self
+    }
+
+    /// Gets how old the event's data is (`None` without a parseable
+    /// `data_timestamp`; future timestamps count as zero)
+    fn age_seconds(&self, event: &IngestionEvent, now: DateTime<Utc>) -> Option<i64> {
+        let timestamp = event.data_timestamp.as_deref()?;
+        match DateTime::parse_from_rfc3339(timestamp) {
+            Ok(timestamp) => Some((now - timestamp.with_timezone(&Utc)).num_seconds().max(0)),
+            Err(e) => {
+                debug!(event_id = %event.id, timestamp, error = %e, "Unparseable data_timestamp");
+                None
+            }
+        }
+    }
+
+    fn is_stale(&self, age_seconds: Option<i64>) -> bool {
+        match (self.stale_after, age_seconds) {
+            (Some(stale_after), Some(age)) => age as u64 > stale_after.as_secs(),
+            _ => false,
+        }
     }
     
     fn extract_tickers(&self, text: &str) -> Vec<String> {
@@ -232,15 +452,34 @@ impl Stage for EnrichStage {
             .or_else(|| item.event.payload.get("title"))
             .or_else(|| item.event.payload.get("description"))
             .and_then(|v| v.as_str())
-            .unwrap_or("");
+            .unwrap_or("")
+            .to_string();
+        let text = text.as_str();
         
+        // Stale events keep their category with a `:stale` suffix
+        // Replays are aged as of their original capture
+        let now = if item.replayed {
+            DateTime::parse_from_rfc3339(&item.event.ingested_at)
+                .map_or_else(|_| Utc::now(), |ingested_at| ingested_at.with_timezone(&Utc))
+        } else {
+            Utc::now()
+        };
+        let age_seconds = self.age_seconds(&item.event, now);
+        let stale = self.is_stale(age_seconds);
+        let mut category = self.categorize(&item.event);
+        if stale {
+            category.push_str(":stale");
+        }
+
         // Enrich with extracted data
         let enrichment = EnrichmentData {
             sentiment_score: Some(self.simple_sentiment(text)),
             entity_tags: vec![],
             related_tickers: self.extract_tickers(text),
             language: Some(self.detect_language(text)),
-            category: Some(self.categorize(&item.event)),
+            category: Some(category),
+            age_seconds,
+            stale,
         };
         
         // Store enrichment data
@@ -254,6 +493,8 @@ impl Stage for EnrichStage {
                 "tickers": enrichment.related_tickers,
                 "language": enrichment.language,
                 "category": enrichment.category,
+                "age_seconds": enrichment.age_seconds,
+                "stale": enrichment.stale,
             }),
         );
         
@@ -288,12 +529,51 @@ impl Stage for EnrichStage {
 
 /// Embed stage - generates vector embeddings
 pub struct EmbedStage {
+    #[allow(dead_code)]
     embedding_service_url: Option<String>,
+    /// Model requested from the embedding service
+    model: Option<String>,
+    /// Embeddings of any other length are dropped
+    expected_dim: Option<usize>,
 }
 
 impl EmbedStage {
     pub fn new(embedding_service_url: Option<String>) -> Self {
-        Self { embedding_service_url }
+        Self {
+            embedding_service_url,
+            model: None,
+            expected_dim: None,
+        }
+    }
+
+    /// Sets the embedding model name
+    pub fn with_model(mut self, model: Option<String>) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Sets the dimension every embedding must have
+    pub fn with_expected_dim(mut self, expected_dim: Option<usize>) -> Self {
+        self.expected_dim = expected_dim;
+        self
+    }
+
+    /// Returns the embedding if it has the expected dimension
+    fn check_dim(&self, event: &IngestionEvent, embedding: Vec<f32>) -> Option<Vec<f32>> {
+        match self.expected_dim {
+            Some(expected) if embedding.len() != expected => {
+                warn!(
+                    event_id = %event.id,
+                    model = ?self.model,
+                    expected,
+                    actual = embedding.len(),
+                    "Dropping embedding with wrong dimension"
+                );
+                metrics::record_embedding_dim_mismatch(&event.source_id);
+                None
+            }
+            _ => Some(embedding),
+        }
     }
     
     async fn generate_embedding(&self, text: &str) -> anyhow::Result<Vec<f32>> {
@@ -317,12 +597,14 @@ impl Stage for EmbedStage {
     async fn process(&self, mut item: PipelineItem) -> anyhow::Result<PipelineItem> {
         let _timer = StageTimer::new(self.name());
         
+        // Embeddings attached upstream are checked like generated ones
+        if let Some(embedding) = item.embedding.take() {
+            item.embedding = self.check_dim(&item.event, embedding);
+            return Ok(item);
+        }
+        
         // Extract text for embedding
-        let text = item.event.payload
-            .get("content")
-            .or_else(|| item.event.payload.get("title"))
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let text = embedding_text(&item.event);
         
         if text.is_empty() {
             debug!(event_id = %item.event.id, "Skipping embedding - no text content");
@@ -332,8 +614,10 @@ impl Stage for EmbedStage {
         // Generate embedding
         match self.generate_embedding(text).await {
             Ok(embedding) => {
-                item.embedding = Some(embedding);
-                debug!(event_id = %item.event.id, "Generated embedding");
+                item.embedding = self.check_dim(&item.event, embedding);
+                if item.embedding.is_some() {
+                    debug!(event_id = %item.event.id, model = ?self.model, "Generated embedding");
+                }
             }
             Err(e) => {
                 warn!(
@@ -352,6 +636,47 @@ impl Stage for EmbedStage {
     }
 }
 
+/// Text an event is embedded from (`content`, else `title`; empty if neither)
+fn embedding_text(event: &IngestionEvent) -> &str {
+    event.payload
+        .get("content")
+        .or_else(|| event.payload.get("title"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+}
+
+/// Builds the `EmbeddingRecord` for an embedded item (`None` without an embedding)
+///
+/// `model` names the embedding model; names outside `EmbeddingModel` are
+/// recorded as `custom` with the name kept in `metadata.model`.
+pub fn embedding_record(item: &PipelineItem, model: Option<&str>) -> Option<EmbeddingRecord> {
+    let embedding = item.embedding.as_ref()?;
+    let event = &item.event;
+
+    let source_type = match event.data_type {
+        IngestionDataType::News => EmbeddingSourceType::NewsItem,
+        IngestionDataType::Social => EmbeddingSourceType::SocialSignal,
+        _ => EmbeddingSourceType::Custom,
+    };
+    let embedding_model = model
+        .and_then(|name| serde_json::from_value(serde_json::json!(name)).ok())
+        .unwrap_or(EmbeddingModel::Custom);
+
+    let mut record = EmbeddingRecord::new(
+        source_type,
+        event.id.clone(),
+        embedding_text(event).to_string(),
+        embedding.iter().map(|&v| v as f64).collect(),
+        embedding_model,
+    );
+    record.metadata.insert("sourceId".to_string(), serde_json::json!(event.source_id));
+    record.metadata.insert("dataType".to_string(), serde_json::json!(event.data_type));
+    if let (EmbeddingModel::Custom, Some(name)) = (&record.embedding_model, model) {
+        record.metadata.insert("model".to_string(), serde_json::json!(name));
+    }
+    Some(record)
+}
+
 // ============================================
 // PUBLISH STAGE
 // ============================================
@@ -359,49 +684,170 @@ impl Stage for EmbedStage {
 /// Publish stage - sends events to message bus
 pub struct PublishStage {
     publisher: Arc<ResilientPublisher>,
+    /// Dedicated publisher for Critical events (alerting consumers)
+    priority_publisher: Option<Arc<ResilientPublisher>>,
+    /// Model named on published `EmbeddingRecord`s
+    embedding_model: Option<String>,
 }
 
 impl PublishStage {
     pub fn new(publisher: Arc<ResilientPublisher>) -> Self {
-        Self { publisher }
+        Self {
+            publisher,
+            priority_publisher: None,
+            embedding_model: None,
+        }
     }
-}
 
-#[async_trait]
-impl Stage for PublishStage {
-    async fn process(&self, mut item: PipelineItem) -> anyhow::Result<PipelineItem> {
-        let _timer = StageTimer::new(self.name());
-        
-        // Mark as completed
-        item.event.status = Status::Completed;
-        item.event.processing_completed_at = Some(chrono::Utc::now().to_rfc3339());
-        item.event.processing_duration_ms = Some(item.latency().as_millis() as u64);
-        
-        // Publish to message bus
-        match self.publisher.publish(&item.event).await {
+    /// Sets the embedding model named on published `EmbeddingRecord`s
+    pub fn with_embedding_model(mut self, model: Option<String>) -> Self {
+        self.embedding_model = model;
+        self
+    }
+
+    /// Publishes the item's embedding as an `EmbeddingRecord` to the
+    /// embeddings stream, for vector stores
+    async fn publish_embedding(&self, item: &PipelineItem) {
+        let Some(record) = embedding_record(item, self.embedding_model.as_deref()) else {
+            return;
+        };
+
+        match self.publisher.publish_record(&record).await {
             Ok(result) => {
                 debug!(
                     event_id = %item.event.id,
+                    record_id = %record.id,
                     stream_id = ?result.stream_id,
-                    latency_ms = item.latency().as_millis(),
-                    "Published event"
+                    "Published embedding record"
                 );
             }
             Err(e) => {
                 error!(
                     event_id = %item.event.id,
                     error = %e,
-                    "Failed to publish event"
+                    "Failed to publish embedding record"
                 );
-                item.event.status = Status::Failed;
-                item.event.error_message = Some(e.to_string());
-                metrics::record_error(self.name(), "publish_failed");
+                metrics::record_error(self.name(), "embedding_publish_failed");
             }
         }
+    }
+
+    /// Routes Critical events to a separate high-priority stream
+    pub fn with_priority_publisher(mut self, publisher: Option<Arc<ResilientPublisher>>) -> Self {
+        self.priority_publisher = publisher;
+        self
+    }
+
+    /// Publishes Critical events to the priority stream (in addition to the main stream)
+    async fn publish_priority(&self, item: &PipelineItem) {
+        let Some(ref priority_publisher) = self.priority_publisher else {
+            return;
+        };
+        if item.event.priority != Severity::Critical {
+            return;
+        }
+
+        match priority_publisher.publish(&item.event).await {
+            Ok(result) => {
+                debug!(
+                    event_id = %item.event.id,
+                    stream_id = ?result.stream_id,
+                    "Published critical event to priority stream"
+                );
+            }
+            Err(e) => {
+                error!(
+                    event_id = %item.event.id,
+                    error = %e,
+                    "Failed to publish critical event to priority stream"
+                );
+                metrics::record_error(self.name(), "priority_publish_failed");
+            }
+        }
+    }
+}
+
+/// Marks an item completed before it is published (replays aren't stamped
+/// with processing times)
+fn mark_completed(item: &mut PipelineItem) {
+    item.event.status = Status::Completed;
+    if item.replayed {
+        return;
+    }
+    item.event.processing_completed_at = Some(chrono::Utc::now().to_rfc3339());
+    item.event.processing_duration_ms = Some(item.latency().as_millis() as u64);
+}
+
+/// Counts a failed publish and turns it into the item's stage error, so the
+/// publish pool's `ErrorPolicy` decides whether it is retried or dead-lettered
+fn publish_failed(item: &PipelineItem, error: &str) -> anyhow::Error {
+    metrics::record_error(metrics::STAGE_PUBLISH, "publish_failed");
+    anyhow::anyhow!("Failed to publish event {}: {}", item.event.id, error)
+}
+
+#[async_trait]
+impl Stage for PublishStage {
+    async fn process(&self, mut item: PipelineItem) -> anyhow::Result<PipelineItem> {
+        let _timer = StageTimer::new(self.name());
+        
+        mark_completed(&mut item);
+        
+        // Publish to message bus
+        let result = self.publisher.publish(&item.event).await
+            .map_err(|e| publish_failed(&item, &e.to_string()))?;
+        debug!(
+            event_id = %item.event.id,
+            stream_id = ?result.stream_id,
+            latency_ms = item.latency().as_millis(),
+            "Published event"
+        );
+        
+        self.publish_priority(&item).await;
+        self.publish_embedding(&item).await;
         
         Ok(item)
     }
     
+    /// Publishes the whole batch with one `publish_batch` call
+    ///
+    /// Items that failed to publish come back as errors.
+    async fn process_batch(&self, mut items: Vec<PipelineItem>) -> Vec<anyhow::Result<PipelineItem>> {
+        let _timer = StageTimer::new(self.name());
+
+        for item in &mut items {
+            mark_completed(item);
+        }
+
+        let events: Vec<IngestionEvent> = items.iter().map(|item| item.event.clone()).collect();
+        let results: Vec<anyhow::Result<PipelineItem>> = match self.publisher.publish_batch(&events).await {
+            Ok(results) => {
+                debug!(batch_size = items.len(), "Published event batch");
+                items
+                    .into_iter()
+                    .zip(results)
+                    .map(|(item, result)| {
+                        if result.success {
+                            return Ok(item);
+                        }
+                        let error = result.error.unwrap_or_else(|| "publish failed".to_string());
+                        Err(publish_failed(&item, &error))
+                    })
+                    .collect()
+            }
+            Err(e) => items
+                .iter()
+                .map(|item| Err(publish_failed(item, &e.to_string())))
+                .collect(),
+        };
+
+        for item in results.iter().flatten() {
+            self.publish_priority(item).await;
+            self.publish_embedding(item).await;
+        }
+
+        results
+    }
+
     fn name(&self) -> &'static str {
         metrics::STAGE_PUBLISH
     }
@@ -414,8 +860,84 @@ impl Stage for PublishStage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::message_bus::{MessageBus, MessageConsumer, PublishResult};
     use std::collections::HashMap;
 
+    /// Message bus that records published events as they appear on the wire
+    struct RecordingBus {
+        published: Arc<parking_lot::Mutex<Vec<serde_json::Value>>>,
+        /// Publishes fail (and record nothing) when set
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl MessageBus for RecordingBus {
+        async fn publish(&self, event: &IngestionEvent) -> anyhow::Result<PublishResult> {
+            if self.fail {
+                anyhow::bail!("bus unavailable");
+            }
+            self.published.lock().push(serde_json::to_value(event)?);
+            Ok(PublishResult {
+                message_id: event.id.clone(),
+                stream_id: None,
+                success: true,
+                error: None,
+            })
+        }
+
+        async fn publish_batch(&self, events: &[IngestionEvent]) -> anyhow::Result<Vec<PublishResult>> {
+            let mut results = Vec::with_capacity(events.len());
+            for event in events {
+                results.push(self.publish(event).await?);
+            }
+            Ok(results)
+        }
+
+        async fn subscribe(&self, _group: &str, _name: &str) -> anyhow::Result<Box<dyn MessageConsumer>> {
+            anyhow::bail!("RecordingBus does not support consumers")
+        }
+
+        async fn is_healthy(&self) -> bool {
+            true
+        }
+
+        fn bus_type(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn close(&self) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    type Published = Arc<parking_lot::Mutex<Vec<serde_json::Value>>>;
+
+    fn recording_publisher() -> (Arc<ResilientPublisher>, Published) {
+        publisher_to(RecordingBus { published: Arc::default(), fail: false })
+    }
+
+    fn failing_publisher() -> (Arc<ResilientPublisher>, Published) {
+        publisher_to(RecordingBus { published: Arc::default(), fail: true })
+    }
+
+    fn publisher_to(bus: RecordingBus) -> (Arc<ResilientPublisher>, Published) {
+        let published = bus.published.clone();
+        let publisher = Arc::new(ResilientPublisher::new(
+            Box::new(bus),
+            0,
+            std::time::Duration::from_millis(1),
+        ));
+        (publisher, published)
+    }
+
+    fn published_ids(published: &Published) -> Vec<String> {
+        published
+            .lock()
+            .iter()
+            .map(|event| event["id"].as_str().unwrap_or_default().to_string())
+            .collect()
+    }
+
     fn create_test_event() -> IngestionEvent {
         IngestionEvent::new(
             crate::schemas::IngestionSourceType::NewsApi,
@@ -444,6 +966,148 @@ mod tests {
         assert!(result.event.validation_errors.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_normalize_stage_canonical_payload_hash() {
+        let stage = NormalizeStage::new();
+        let mut payload = HashMap::new();
+        payload.insert("n".to_string(), serde_json::json!(1));
+        payload.insert("key".to_string(), serde_json::json!("value"));
+        let mut event = create_test_event();
+        event.payload = payload;
+        event.payload_size = 0;
+
+        let result = stage.process(PipelineItem::new(event, "test-corr", "test")).await.unwrap();
+
+        // sha256 of {"key":"value","n":1}
+        assert_eq!(
+            result.event.payload_hash.as_deref(),
+            Some("sha256:8b683e1a3b1605e6d900890b9f64f4e17e542c75f34b30ae1a676907c1564016")
+        );
+        assert_eq!(result.event.payload_size, r#"{"key":"value","n":1}"#.len() as u64);
+    }
+
+    #[tokio::test]
+    async fn test_normalize_stage_timestamp_formats() {
+        let stage = NormalizeStage::new()
+            .with_timestamp_field("reddit", "created_utc");
+
+        let cases = [
+            ("newsapi", "publishedAt", serde_json::json!("2024-01-15T12:00:00+02:00")),
+            ("x_api", "createdAt", serde_json::json!("Mon Jan 15 10:00:00 +0000 2024")),
+            ("reddit", "created_utc", serde_json::json!(1705312800.0)),
+        ];
+        for (source_id, field, value) in cases {
+            let mut event = create_test_event();
+            event.source_id = source_id.to_string();
+            event.payload.insert(field.to_string(), value);
+
+            let result = stage.process(PipelineItem::new(event, "test-corr", "test")).await.unwrap();
+            assert_eq!(result.event.data_timestamp.as_deref(), Some("2024-01-15T10:00:00Z"), "{}", source_id);
+            assert!(result.event.validation_errors.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_normalize_stage_unparseable_timestamp() {
+        let stage = NormalizeStage::new();
+        let mut event = create_test_event();
+        event.source_id = "newsapi".to_string();
+        event.payload.insert("publishedAt".to_string(), serde_json::json!("yesterday"));
+
+        let result = stage.process(PipelineItem::new(event, "test-corr", "test")).await.unwrap();
+        assert!(result.event.data_timestamp.is_none());
+        assert!(!result.event.is_valid);
+        assert_eq!(
+            result.event.validation_errors,
+            vec![r#"Unparseable timestamp in publishedAt: "yesterday""#.to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_normalize_stage_truncates_oversized_payload() {
+        let stage = NormalizeStage::new().with_max_payload_bytes(1000);
+        let mut event = create_test_event();
+        event.source_id = "truncate-test".to_string();
+        event.payload.insert("title".to_string(), serde_json::json!("Monad mainnet"));
+        event.payload.insert("raw".to_string(), serde_json::json!({"blob": "x".repeat(5000)}));
+        event.payload_size = canonical_payload_json(&event.payload).len() as u64;
+
+        let before = metrics::truncated_payloads_total("truncate-test");
+        let result = stage.process(PipelineItem::new(event, "test-corr", "test")).await.unwrap();
+
+        assert!(result.event.payload_size <= 1000);
+        assert_eq!(result.event.payload["truncated"], serde_json::json!(true));
+        assert!(!result.event.payload.contains_key("raw"));
+        assert_eq!(result.event.payload["title"], serde_json::json!("Monad mainnet"));
+        assert_eq!(result.event.payload_hash, Some(payload_hash(&result.event.payload)));
+        assert_eq!(metrics::truncated_payloads_total("truncate-test"), before + 1);
+
+        // Payloads within the limit are left alone
+        let result = stage.process(PipelineItem::new(create_test_event(), "test-corr", "test")).await.unwrap();
+        assert!(!result.event.payload.contains_key("truncated"));
+    }
+
+    #[tokio::test]
+    async fn test_normalize_stage_filters_payload_fields() {
+        let fields = |source: &str, keys: &[&str]| {
+            HashMap::from([(source.to_string(), keys.iter().map(|k| k.to_string()).collect())])
+        };
+        let stage = NormalizeStage::new().with_payload_filters(PayloadFilters::new(
+            fields("newsapi", &["title", "url", "publishedAt"]),
+            fields("x_api", &["authorEmail", "raw"]),
+        ));
+
+        let mut event = create_test_event();
+        event.source_id = "x_api".to_string();
+        event.payload.insert("text".to_string(), serde_json::json!("gm"));
+        event.payload.insert("authorEmail".to_string(), serde_json::json!("user@example.com"));
+        event.payload.insert("raw".to_string(), serde_json::json!({"id": "1"}));
+        event.payload_hash = Some(payload_hash(&event.payload));
+
+        let result = stage.process(PipelineItem::new(event, "test-corr", "test")).await.unwrap();
+        let payload = &result.event.payload;
+        assert!(!payload.contains_key("authorEmail"));
+        assert!(!payload.contains_key("raw"));
+        assert_eq!(payload["text"], serde_json::json!("gm"));
+        assert!(payload.contains_key("content"));
+        assert_eq!(result.event.payload_hash, Some(payload_hash(payload)));
+        assert_eq!(result.event.payload_size, canonical_payload_json(payload).len() as u64);
+
+        // An allow-list keeps only its keys
+        let mut event = create_test_event();
+        event.source_id = "newsapi".to_string();
+        event.payload.insert("title".to_string(), serde_json::json!("Monad mainnet"));
+        let result = stage.process(PipelineItem::new(event, "test-corr", "test")).await.unwrap();
+        assert_eq!(result.event.payload.keys().collect::<Vec<_>>(), vec!["title"]);
+    }
+
+    #[tokio::test]
+    async fn test_embed_stage_drops_wrong_dimension() {
+        let stage = EmbedStage::new(None)
+            .with_model(Some("test-model".to_string()))
+            .with_expected_dim(Some(16));
+        let mut event = create_test_event();
+        event.source_id = "embed-dim-test".to_string();
+
+        // Generated embeddings of the right length pass
+        let result = stage.process(PipelineItem::new(event.clone(), "test-corr", "test")).await.unwrap();
+        assert_eq!(result.embedding.map(|e| e.len()), Some(16));
+
+        let before = metrics::embedding_dim_mismatches_total("embed-dim-test");
+        let mut item = PipelineItem::new(event, "test-corr", "test");
+        item.embedding = Some(vec![0.5; 3]);
+        let result = stage.process(item).await.unwrap();
+
+        assert_eq!(result.embedding, None);
+        assert_eq!(result.event.source_id, "embed-dim-test");
+        assert_eq!(metrics::embedding_dim_mismatches_total("embed-dim-test"), before + 1);
+
+        // Generated embeddings are checked too
+        let stage = EmbedStage::new(None).with_expected_dim(Some(768));
+        let result = stage.process(PipelineItem::new(create_test_event(), "test-corr", "test")).await.unwrap();
+        assert_eq!(result.embedding, None);
+    }
+
     #[tokio::test]
     async fn test_enrich_stage() {
         let stage = EnrichStage::new();
@@ -458,6 +1122,37 @@ mod tests {
         assert!(enrichment.sentiment_score.unwrap() > 0.0); // "pumping", "great" are positive
     }
 
+    #[tokio::test]
+    async fn test_enrich_stage_tags_stale_events() {
+        let stage = EnrichStage::new().with_stale_after(Some(std::time::Duration::from_secs(30 * 60)));
+
+        let mut event = create_test_event();
+        event.data_type = crate::schemas::IngestionDataType::News;
+        let hour_ago = Utc::now() - chrono::Duration::hours(1);
+        event.data_timestamp = Some(hour_ago.to_rfc3339_opts(SecondsFormat::Secs, true));
+        let result = stage.process(PipelineItem::new(event, "test-corr", "test")).await.unwrap();
+
+        let enrichment = result.enrichment.unwrap();
+        let age = enrichment.age_seconds.unwrap();
+        assert!((3599..=3605).contains(&age), "age {}", age);
+        assert!(enrichment.stale);
+        assert_eq!(enrichment.category.as_deref(), Some("news:stale"));
+        assert_eq!(result.event.payload["enrichment"]["stale"], serde_json::json!(true));
+
+        // Fresh and undated events aren't stale
+        let mut fresh = create_test_event();
+        fresh.data_timestamp = Some(Utc::now().to_rfc3339());
+        let result = stage.process(PipelineItem::new(fresh, "test-corr", "test")).await.unwrap();
+        assert!(!result.enrichment.unwrap().stale);
+
+        let mut undated = create_test_event();
+        undated.data_timestamp = Some("yesterday".to_string());
+        let result = stage.process(PipelineItem::new(undated, "test-corr", "test")).await.unwrap();
+        let enrichment = result.enrichment.unwrap();
+        assert_eq!(enrichment.age_seconds, None);
+        assert!(!enrichment.stale);
+    }
+
     #[test]
     fn test_ticker_extraction() {
         let stage = EnrichStage::new();
@@ -468,4 +1163,65 @@ mod tests {
         let empty = stage.extract_tickers("No tickers here");
         assert!(empty.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_publish_stage_routes_critical_to_priority_stream() {
+        let (main_publisher, main_published) = recording_publisher();
+        let (priority_publisher, priority_published) = recording_publisher();
+        let stage = PublishStage::new(main_publisher)
+            .with_priority_publisher(Some(priority_publisher));
+
+        let mut critical = create_test_event();
+        critical.priority = Severity::Critical;
+        let critical_id = critical.id.clone();
+        let mut low = create_test_event();
+        low.priority = Severity::Low;
+        let low_id = low.id.clone();
+
+        stage.process(PipelineItem::new(critical, "test-corr", "test")).await.unwrap();
+        stage.process(PipelineItem::new(low, "test-corr", "test")).await.unwrap();
+
+        assert_eq!(published_ids(&priority_published), vec![critical_id.clone()]);
+        assert_eq!(published_ids(&main_published), vec![critical_id, low_id]);
+    }
+
+    #[tokio::test]
+    async fn test_publish_stage_fails_items_it_cannot_publish() {
+        let (publisher, _) = failing_publisher();
+        let (priority_publisher, priority_published) = recording_publisher();
+        let stage = PublishStage::new(publisher)
+            .with_priority_publisher(Some(priority_publisher));
+        let critical = || {
+            let mut event = create_test_event();
+            event.priority = Severity::Critical;
+            PipelineItem::new(event, "test-corr", "test")
+        };
+
+        assert!(stage.process(critical()).await.is_err());
+        let results = stage.process_batch(vec![critical(), critical()]).await;
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|result| result.is_err()));
+
+        // Nothing reaches the priority stream ahead of the main one
+        assert!(priority_published.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_published_event_carries_lineage() {
+        let (publisher, published) = recording_publisher();
+        let stage = PublishStage::new(publisher);
+
+        let mut event = create_test_event();
+        event.stamp_lineage("session-1", "corr-1");
+        stage.process(PipelineItem::new(event, "corr-2", "test")).await.unwrap();
+
+        let published = published.lock();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0]["sessionId"], "session-1");
+        assert_eq!(published[0]["correlationId"], "corr-1");
+
+        let event: IngestionEvent = serde_json::from_value(published[0].clone()).unwrap();
+        assert_eq!(event.session_id.as_deref(), Some("session-1"));
+        assert_eq!(event.correlation_id.as_deref(), Some("corr-1"));
+    }
 }
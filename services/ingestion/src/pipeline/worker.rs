@@ -1,16 +1,162 @@
 //! Worker Pool Implementation
 //!
 //! Manages a pool of workers that process items from a channel.
-//! Supports graceful shutdown and metrics collection.
+//! Supports graceful shutdown, metrics collection, and a per-stage policy
+//! for items the stage fails on (drop, retry, or dead-letter).
 
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::{mpsc, broadcast, Semaphore};
+use std::time::Duration;
+use tokio::sync::{mpsc, broadcast, watch, Mutex};
+use tokio::task::JoinSet;
 use tracing::{debug, error, info, warn, Instrument};
 
+use crate::message_bus::ResilientPublisher;
 use crate::metrics;
-use super::PipelineItem;
+use crate::schemas::IngestionEvent;
+use super::{OnError, PipelineItem};
 use super::stages::Stage;
 
+/// Creates the span for processing one item, carrying the harvest
+/// correlation id and event id so traces can be joined across stages
+fn item_span(stage_name: &'static str, item: &PipelineItem) -> tracing::Span {
+    tracing::debug_span!(
+        "worker",
+        stage = stage_name,
+        correlation_id = %item.correlation_id,
+        event_id = %item.event.id,
+    )
+}
+
+// ============================================
+// ERROR POLICY
+// ============================================
+
+/// How a worker pool handles items its stage fails on
+#[derive(Clone, Default)]
+pub struct ErrorPolicy {
+    pub on_error: OnError,
+    /// Retries after the first attempt under `OnError::Retry`
+    pub max_retries: u32,
+    /// Where `OnError::DeadLetter` sends items (dropped when unset)
+    pub dead_letter: Option<DeadLetterQueue>,
+}
+
+impl ErrorPolicy {
+    /// Total attempts per item
+    fn max_attempts(&self) -> u32 {
+        match self.on_error {
+            OnError::Retry => self.max_retries + 1,
+            OnError::Drop | OnError::DeadLetter => 1,
+        }
+    }
+
+    /// Handles an item that failed its final attempt
+    async fn handle_failure(&self, stage_name: &'static str, item: &PipelineItem, error: &anyhow::Error, attempts: u32) {
+        match (self.on_error, &self.dead_letter) {
+            (OnError::DeadLetter, Some(dead_letter)) => {
+                dead_letter.send(stage_name, item, error, attempts).await;
+            }
+            (OnError::DeadLetter, None) => {
+                warn!(stage = stage_name, event_id = %item.event.id, "No dead-letter queue; dropping item");
+            }
+            (OnError::Retry, _) => {
+                warn!(stage = stage_name, event_id = %item.event.id, attempts, "Retries exhausted; dropping item");
+                metrics::record_error(stage_name, "retries_exhausted");
+            }
+            (OnError::Drop, _) => {}
+        }
+    }
+}
+
+/// Item a stage failed on, as published to the dead-letter stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadLetter {
+    pub stage: String,
+    pub error: String,
+    pub attempts: u32,
+    pub failed_at: String,
+    pub correlation_id: String,
+    pub event: IngestionEvent,
+}
+
+/// Dead-letter stream for items a stage couldn't process
+#[derive(Clone)]
+pub struct DeadLetterQueue {
+    publisher: Arc<ResilientPublisher>,
+    stream: String,
+}
+
+impl DeadLetterQueue {
+    pub fn new(publisher: Arc<ResilientPublisher>, stream: &str) -> Self {
+        Self {
+            publisher,
+            stream: stream.to_string(),
+        }
+    }
+
+    /// Publishes a failed item (errors are logged, the item is then lost)
+    async fn send(&self, stage_name: &'static str, item: &PipelineItem, error: &anyhow::Error, attempts: u32) {
+        self.send_event(stage_name, &item.event, &item.correlation_id, error, attempts).await;
+    }
+
+    /// Publishes an event that failed outside a pipeline stage, like the
+    /// bus-to-storage consumer (errors are logged, the event is then lost)
+    pub async fn send_event(
+        &self,
+        stage_name: &str,
+        event: &IngestionEvent,
+        correlation_id: &str,
+        error: &anyhow::Error,
+        attempts: u32,
+    ) {
+        let entry = DeadLetter {
+            stage: stage_name.to_string(),
+            error: error.to_string(),
+            attempts,
+            failed_at: chrono::Utc::now().to_rfc3339(),
+            correlation_id: correlation_id.to_string(),
+            event: event.clone(),
+        };
+
+        match self.publisher.publish_serialized(&self.stream, "DeadLetter", &event.id, &entry).await {
+            Ok(_) => {
+                debug!(stage = stage_name, event_id = %event.id, stream = %self.stream, "Dead-lettered item");
+                metrics::record_error(stage_name, "dead_lettered");
+            }
+            Err(e) => {
+                error!(stage = stage_name, event_id = %event.id, error = %e, "Failed to dead-letter item");
+                metrics::record_error(stage_name, "dead_letter_failed");
+            }
+        }
+    }
+}
+
+// ============================================
+// IN-FLIGHT TRACKING
+// ============================================
+
+/// Count of items submitted to a pipeline that haven't left it yet
+/// (published, or failed and handled by the error policy)
+#[derive(Clone, Default)]
+pub struct InFlight(Arc<AtomicUsize>);
+
+impl InFlight {
+    pub fn add(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn done(&self) {
+        let _ = self.0.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+    }
+
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
 // ============================================
 // WORKER POOL
 // ============================================
@@ -22,6 +168,9 @@ pub struct WorkerPool {
     tx: mpsc::Sender<PipelineItem>,
     stage: Arc<Box<dyn Stage>>,
     shutdown_rx: broadcast::Receiver<()>,
+    policy: Arc<ErrorPolicy>,
+    in_flight: InFlight,
+    stage_timeout: Option<Duration>,
 }
 
 impl WorkerPool {
@@ -40,10 +189,37 @@ impl WorkerPool {
             tx,
             stage: Arc::new(stage),
             shutdown_rx,
+            policy: Arc::new(ErrorPolicy::default()),
+            in_flight: InFlight::default(),
+            stage_timeout: None,
         }
     }
 
+    /// Fails an item (handled by the error policy) when the stage spends
+    /// longer than `stage_timeout` on it (unbounded by default)
+    pub fn with_stage_timeout(mut self, stage_timeout: Duration) -> Self {
+        self.stage_timeout = Some(stage_timeout);
+        self
+    }
+
+    /// Sets how items the stage fails on are handled (dropped by default)
+    pub fn with_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.policy = Arc::new(policy);
+        self
+    }
+
+    /// Sets the pipeline's in-flight count, decremented for every item that
+    /// leaves the pipeline at this stage
+    pub fn with_in_flight(mut self, in_flight: InFlight) -> Self {
+        self.in_flight = in_flight;
+        self
+    }
+
     /// Runs the worker pool
+    ///
+    /// Spawns `worker_count` long-lived workers sharing the input channel, so
+    /// at most `worker_count` items are in flight however fast items arrive.
+    /// Returns once shutdown is signalled and in-flight items have finished.
     pub async fn run(mut self) {
         info!(
             stage = self.stage_name,
@@ -51,100 +227,174 @@ impl WorkerPool {
             "Starting worker pool"
         );
 
-        // Use semaphore to limit concurrent workers
-        let semaphore = Arc::new(Semaphore::new(self.worker_count));
-        let mut handles = Vec::new();
+        let rx = Arc::new(Mutex::new(self.rx));
+        let (stop_tx, stop_rx) = watch::channel(false);
 
-        loop {
-            tokio::select! {
-                // Check for shutdown
-                _ = self.shutdown_rx.recv() => {
-                    info!(stage = self.stage_name, "Worker pool received shutdown signal");
-                    break;
-                }
-                
-                // Process items
-                Some(item) = self.rx.recv() => {
-                    // Acquire semaphore permit
-                    let permit = semaphore.clone().acquire_owned().await;
-                    
-                    if permit.is_err() {
-                        warn!(stage = self.stage_name, "Failed to acquire worker permit");
-                        continue;
-                    }
-                    
-                    let permit = permit.unwrap();
-                    let stage = self.stage.clone();
-                    let tx = self.tx.clone();
-                    let stage_name = self.stage_name;
-                    
-                    // Update queue depth
-                    metrics::set_queue_depth(stage_name, self.rx.len() as i64);
-                    
-                    // Spawn worker task
-                    let handle = tokio::spawn(async move {
-                        metrics::inc_active_workers(stage_name);
-                        
-                        let result = stage.process(item.clone()).await;
-                        
-                        match result {
-                            Ok(processed) => {
-                                // Send to next stage if stage has output
-                                if stage.has_output() {
-                                    if let Err(e) = tx.send(processed).await {
-                                        warn!(
-                                            stage = stage_name,
-                                            error = %e,
-                                            "Failed to send to next stage"
-                                        );
-                                    }
-                                }
-                                
-                                metrics::record_event_processed(stage_name, &item.source);
-                            }
-                            Err(e) => {
-                                error!(
-                                    stage = stage_name,
-                                    event_id = %item.event.id,
-                                    error = %e,
-                                    "Failed to process item"
-                                );
-                                metrics::record_error(stage_name, "processing_error");
-                            }
-                        }
-                        
-                        metrics::dec_active_workers(stage_name);
-                        drop(permit);
-                    }.instrument(tracing::debug_span!("worker", stage = stage_name)));
-                    
-                    handles.push(handle);
-                    
-                    // Clean up completed handles periodically
-                    handles.retain(|h| !h.is_finished());
-                }
-            }
+        // Dropping the set (e.g. when the pool task is aborted) aborts the workers
+        let mut workers = JoinSet::new();
+        for _ in 0..self.worker_count.max(1) {
+            workers.spawn(worker_loop(
+                self.stage_name,
+                rx.clone(),
+                self.tx.clone(),
+                self.stage.clone(),
+                self.policy.clone(),
+                self.in_flight.clone(),
+                self.stage_timeout,
+                stop_rx.clone(),
+            ));
         }
 
-        // Wait for remaining workers to complete
+        let _ = self.shutdown_rx.recv().await;
+        info!(stage = self.stage_name, "Worker pool received shutdown signal");
+        let _ = stop_tx.send(true);
+
+        // Wait for in-flight items to complete
         info!(
             stage = self.stage_name,
-            pending = handles.len(),
+            workers = workers.len(),
             "Waiting for workers to complete"
         );
-        
-        for handle in handles {
-            let _ = handle.await;
-        }
-        
+        while workers.join_next().await.is_some() {}
+
         info!(stage = self.stage_name, "Worker pool stopped");
     }
 }
 
+/// Receives and processes items one at a time until stopped
+#[allow(clippy::too_many_arguments)]
+async fn worker_loop(
+    stage_name: &'static str,
+    rx: Arc<Mutex<mpsc::Receiver<PipelineItem>>>,
+    tx: mpsc::Sender<PipelineItem>,
+    stage: Arc<Box<dyn Stage>>,
+    policy: Arc<ErrorPolicy>,
+    in_flight: InFlight,
+    stage_timeout: Option<Duration>,
+    mut stop_rx: watch::Receiver<bool>,
+) {
+    loop {
+        let item = tokio::select! {
+            _ = stop_rx.wait_for(|stopped| *stopped) => break,
+            item = async {
+                let mut rx = rx.lock().await;
+                let item = rx.recv().await;
+                // Update queue depth
+                metrics::set_queue_depth(stage_name, rx.len() as i64);
+                item
+            } => match item {
+                Some(item) => item,
+                // Input closed; nothing left to process
+                None => break,
+            },
+        };
+
+        let span = item_span(stage_name, &item);
+        async {
+            metrics::inc_active_workers(stage_name);
+
+            let mut forwarded = false;
+            let processed = process_with_policy(stage_name, stage.as_ref().as_ref(), &item, &policy, stage_timeout).await;
+            if let Some(processed) = processed {
+                // Send to next stage if stage has output
+                if stage.has_output() {
+                    match tx.send(processed).await {
+                        Ok(()) => forwarded = true,
+                        Err(e) => warn!(
+                            stage = stage_name,
+                            error = %e,
+                            "Failed to send to next stage"
+                        ),
+                    }
+                }
+
+                metrics::record_event_processed(stage_name, &item.source);
+            }
+            if !forwarded {
+                in_flight.done();
+            }
+
+            metrics::dec_active_workers(stage_name);
+        }
+        .instrument(span)
+        .await;
+    }
+}
+
+/// Runs the stage on an item, failing it if `stage_timeout` passes first
+async fn process_with_timeout(
+    stage_name: &'static str,
+    stage: &dyn Stage,
+    item: PipelineItem,
+    stage_timeout: Option<Duration>,
+) -> anyhow::Result<PipelineItem> {
+    let Some(limit) = stage_timeout else {
+        return stage.process(item).await;
+    };
+
+    match tokio::time::timeout(limit, stage.process(item)).await {
+        Ok(result) => result,
+        Err(_) => {
+            metrics::record_stage_timeout(stage_name);
+            anyhow::bail!("Stage {} timed out after {:?}", stage_name, limit)
+        }
+    }
+}
+
+/// Processes an item, applying `policy` when the stage fails or times out
+///
+/// Returns `None` if the item failed (and was dropped or dead-lettered).
+async fn process_with_policy(
+    stage_name: &'static str,
+    stage: &dyn Stage,
+    item: &PipelineItem,
+    policy: &ErrorPolicy,
+    stage_timeout: Option<Duration>,
+) -> Option<PipelineItem> {
+    let max_attempts = policy.max_attempts();
+    let mut attempt = 0;
+
+    loop {
+        attempt += 1;
+        match process_with_timeout(stage_name, stage, item.clone(), stage_timeout).await {
+            Ok(processed) => return Some(processed),
+            Err(e) if attempt < max_attempts => {
+                warn!(
+                    stage = stage_name,
+                    event_id = %item.event.id,
+                    attempt,
+                    error = %e,
+                    "Failed to process item, retrying"
+                );
+                metrics::record_error(stage_name, "processing_retry");
+            }
+            Err(e) => {
+                error!(
+                    stage = stage_name,
+                    event_id = %item.event.id,
+                    error = %e,
+                    on_error = policy.on_error.as_str(),
+                    "Failed to process item"
+                );
+                metrics::record_error(stage_name, "processing_error");
+                policy.handle_failure(stage_name, item, &e, attempt).await;
+                return None;
+            }
+        }
+    }
+}
+
 // ============================================
 // BATCH WORKER
 // ============================================
 
 /// Worker that processes items in batches for efficiency
+///
+/// A batch is handed to `Stage::process_batch` once `batch_size` items are
+/// pending or `batch_timeout` passes. Items failing in a batch are processed
+/// again on their own under the error policy. A batch past the stage timeout
+/// isn't processed again, since part of it may already be done (published);
+/// its items go straight to the error policy's failure handling.
 pub struct BatchWorker {
     stage_name: &'static str,
     batch_size: usize,
@@ -153,6 +403,9 @@ pub struct BatchWorker {
     tx: mpsc::Sender<PipelineItem>,
     stage: Arc<Box<dyn Stage>>,
     shutdown_rx: broadcast::Receiver<()>,
+    policy: ErrorPolicy,
+    in_flight: InFlight,
+    stage_timeout: Option<Duration>,
 }
 
 impl BatchWorker {
@@ -173,9 +426,32 @@ impl BatchWorker {
             tx,
             stage: Arc::new(stage),
             shutdown_rx,
+            policy: ErrorPolicy::default(),
+            in_flight: InFlight::default(),
+            stage_timeout: None,
         }
     }
 
+    /// Fails a batch when the stage spends longer than `stage_timeout` on it
+    /// (unbounded by default)
+    pub fn with_stage_timeout(mut self, stage_timeout: Duration) -> Self {
+        self.stage_timeout = Some(stage_timeout);
+        self
+    }
+
+    /// Sets how items the stage fails on are handled (dropped by default)
+    pub fn with_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets the pipeline's in-flight count, decremented for every item that
+    /// leaves the pipeline at this stage
+    pub fn with_in_flight(mut self, in_flight: InFlight) -> Self {
+        self.in_flight = in_flight;
+        self
+    }
+
     /// Runs the batch worker
     pub async fn run(mut self) {
         info!(
@@ -185,7 +461,11 @@ impl BatchWorker {
         );
 
         let mut batch = Vec::with_capacity(self.batch_size);
-        let mut timeout = tokio::time::interval(self.batch_timeout);
+        // The first flush is due one timeout from now, not immediately
+        let mut timeout = tokio::time::interval_at(
+            tokio::time::Instant::now() + self.batch_timeout,
+            self.batch_timeout,
+        );
 
         loop {
             tokio::select! {
@@ -200,10 +480,12 @@ impl BatchWorker {
                 
                 // Collect items into batch
                 Some(item) = self.rx.recv() => {
+                    metrics::set_queue_depth(self.stage_name, self.rx.len() as i64);
                     batch.push(item);
                     
                     if batch.len() >= self.batch_size {
                         self.process_batch(&mut batch).await;
+                        timeout.reset();
                     }
                 }
                 
@@ -225,30 +507,72 @@ impl BatchWorker {
 
         metrics::inc_active_workers(self.stage_name);
 
-        for item in batch.drain(..) {
-            match self.stage.process(item.clone()).await {
-                Ok(processed) => {
+        let items: Vec<PipelineItem> = std::mem::take(batch);
+        let processing = self.stage.process_batch(items.clone());
+        let mut timed_out = false;
+        let results = match self.stage_timeout {
+            None => processing.await,
+            Some(limit) => match tokio::time::timeout(limit, processing).await {
+                Ok(results) => results,
+                Err(_) => {
+                    metrics::record_stage_timeout(self.stage_name);
+                    warn!(stage = self.stage_name, batch_size, timeout = ?limit, "Batch timed out");
+                    timed_out = true;
+                    (0..batch_size)
+                        .map(|_| Err(anyhow::anyhow!("Stage {} timed out after {:?}", self.stage_name, limit)))
+                        .collect()
+                }
+            },
+        };
+
+        for (item, result) in items.into_iter().zip(results) {
+            let span = item_span(self.stage_name, &item);
+            async {
+                let processed = match result {
+                    Ok(processed) => Some(processed),
+                    Err(e) if timed_out => {
+                        metrics::record_error(self.stage_name, "batch_timeout");
+                        self.policy.handle_failure(self.stage_name, &item, &e, 1).await;
+                        None
+                    }
+                    Err(e) => {
+                        warn!(
+                            stage = self.stage_name,
+                            event_id = %item.event.id,
+                            error = %e,
+                            "Failed to process item in batch, processing it alone"
+                        );
+                        metrics::record_error(self.stage_name, "batch_processing_error");
+                        process_with_policy(
+                            self.stage_name,
+                            self.stage.as_ref().as_ref(),
+                            &item,
+                            &self.policy,
+                            self.stage_timeout,
+                        ).await
+                    }
+                };
+
+                let mut forwarded = false;
+                if let Some(processed) = processed {
                     if self.stage.has_output() {
-                        if let Err(e) = self.tx.send(processed).await {
-                            warn!(
+                        match self.tx.send(processed).await {
+                            Ok(()) => forwarded = true,
+                            Err(e) => warn!(
                                 stage = self.stage_name,
                                 error = %e,
                                 "Failed to send to next stage"
-                            );
+                            ),
                         }
                     }
                     metrics::record_event_processed(self.stage_name, &item.source);
                 }
-                Err(e) => {
-                    error!(
-                        stage = self.stage_name,
-                        event_id = %item.event.id,
-                        error = %e,
-                        "Failed to process item in batch"
-                    );
-                    metrics::record_error(self.stage_name, "batch_processing_error");
+                if !forwarded {
+                    self.in_flight.done();
                 }
             }
+            .instrument(span)
+            .await;
         }
 
         metrics::dec_active_workers(self.stage_name);
@@ -261,6 +585,57 @@ mod tests {
     use crate::pipeline::stages::NormalizeStage;
     use crate::schemas::{IngestionEvent, IngestionSourceType, IngestionDataType};
     use std::collections::HashMap;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id};
+    use tracing_subscriber::layer::{Context, Layer};
+    use tracing_subscriber::prelude::*;
+
+    type RecordedSpans = Arc<parking_lot::Mutex<Vec<(String, HashMap<String, String>)>>>;
+
+    /// Layer recording the name and fields of every new span
+    #[derive(Clone, Default)]
+    struct SpanRecorder {
+        spans: RecordedSpans,
+    }
+
+    struct FieldMap(HashMap<String, String>);
+
+    impl Visit for FieldMap {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl<S: tracing::Subscriber> Layer<S> for SpanRecorder {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            let mut fields = FieldMap(HashMap::new());
+            attrs.record(&mut fields);
+            self.spans.lock().push((attrs.metadata().name().to_string(), fields.0));
+        }
+    }
+
+    /// Stage that records how many items it is processing at once
+    #[derive(Default)]
+    struct ConcurrencyStage {
+        active: std::sync::atomic::AtomicUsize,
+        peak: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl Stage for ConcurrencyStage {
+        async fn process(&self, item: PipelineItem) -> anyhow::Result<PipelineItem> {
+            use std::sync::atomic::Ordering;
+            let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(active, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            self.active.fetch_sub(1, Ordering::SeqCst);
+            Ok(item)
+        }
+
+        fn name(&self) -> &'static str {
+            "concurrency"
+        }
+    }
 
     fn create_test_item() -> PipelineItem {
         let event = IngestionEvent::new(
@@ -309,4 +684,275 @@ mod tests {
         shutdown_tx.send(()).unwrap();
         handle.await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_worker_span_carries_correlation_id() {
+        let recorder = SpanRecorder::default();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+        let (tx_in, rx_in) = mpsc::channel(10);
+        let (tx_out, mut rx_out) = mpsc::channel(10);
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let pool = WorkerPool::new("test", 1, rx_in, tx_out, Box::new(NormalizeStage::new()), shutdown_rx);
+        let handle = tokio::spawn(pool.run());
+
+        let item = create_test_item();
+        let event_id = item.event.id.clone();
+        tx_in.send(item).await.unwrap();
+        tokio::time::timeout(std::time::Duration::from_secs(1), rx_out.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        shutdown_tx.send(()).unwrap();
+        handle.await.unwrap();
+
+        let spans = recorder.spans.lock();
+        let (_, fields) = spans.iter().find(|(name, _)| name == "worker").expect("worker span");
+        assert_eq!(fields["stage"], "\"test\"");
+        assert_eq!(fields["correlation_id"], "test-corr");
+        assert_eq!(fields["event_id"], event_id);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_worker_pool_bounds_concurrency() {
+        let (tx_in, rx_in) = mpsc::channel(100);
+        let (tx_out, mut rx_out) = mpsc::channel(100);
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+
+        let stage = ConcurrencyStage::default();
+        let peak = stage.peak.clone();
+        let pool = WorkerPool::new("bounded", 3, rx_in, tx_out, Box::new(stage), shutdown_rx);
+        let handle = tokio::spawn(pool.run());
+
+        // Flood the pool faster than it can process
+        for _ in 0..60 {
+            tx_in.send(create_test_item()).await.unwrap();
+        }
+        for _ in 0..60 {
+            tokio::time::timeout(std::time::Duration::from_secs(5), rx_out.recv())
+                .await
+                .unwrap()
+                .unwrap();
+        }
+
+        let peak = peak.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(peak <= 3, "peak concurrency {} exceeded worker count", peak);
+        assert!(peak > 1, "workers should run in parallel");
+
+        shutdown_tx.send(()).unwrap();
+        handle.await.unwrap();
+    }
+
+    /// Stage that fails its first `failures` calls
+    struct FlakyStage {
+        failures: usize,
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl FlakyStage {
+        fn new(failures: usize) -> (Self, Arc<std::sync::atomic::AtomicUsize>) {
+            let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            (Self { failures, calls: calls.clone() }, calls)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Stage for FlakyStage {
+        async fn process(&self, item: PipelineItem) -> anyhow::Result<PipelineItem> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if call < self.failures {
+                anyhow::bail!("flaky failure {}", call + 1);
+            }
+            Ok(item)
+        }
+
+        fn name(&self) -> &'static str {
+            "flaky"
+        }
+    }
+
+    /// Runs one item through a single-worker pool, returning what came out
+    async fn run_one(stage: FlakyStage, policy: ErrorPolicy) -> Option<PipelineItem> {
+        let (tx_in, rx_in) = mpsc::channel(10);
+        let (tx_out, mut rx_out) = mpsc::channel(10);
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let pool = WorkerPool::new("flaky", 1, rx_in, tx_out, Box::new(stage), shutdown_rx)
+            .with_error_policy(policy);
+        let handle = tokio::spawn(pool.run());
+
+        tx_in.send(create_test_item()).await.unwrap();
+        let output = tokio::time::timeout(std::time::Duration::from_millis(300), rx_out.recv())
+            .await
+            .ok()
+            .flatten();
+
+        shutdown_tx.send(()).unwrap();
+        handle.await.unwrap();
+        output
+    }
+
+    /// Stage that hangs on items from the `slow` source
+    struct HangingStage;
+
+    #[async_trait::async_trait]
+    impl Stage for HangingStage {
+        async fn process(&self, item: PipelineItem) -> anyhow::Result<PipelineItem> {
+            if item.source == "slow" {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+            }
+            Ok(item)
+        }
+
+        fn name(&self) -> &'static str {
+            "hanging"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stage_timeout_fails_item_and_worker_continues() {
+        let (tx_in, rx_in) = mpsc::channel(10);
+        let (tx_out, mut rx_out) = mpsc::channel(10);
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let in_flight = InFlight::default();
+        let pool = WorkerPool::new("timeout_test", 1, rx_in, tx_out, Box::new(HangingStage), shutdown_rx)
+            .with_in_flight(in_flight.clone())
+            .with_stage_timeout(Duration::from_millis(50));
+        let handle = tokio::spawn(pool.run());
+
+        let before = metrics::stage_timeouts_total("timeout_test");
+        let mut slow = create_test_item();
+        slow.source = "slow".to_string();
+        for item in [slow, create_test_item()] {
+            in_flight.add();
+            tx_in.send(item).await.unwrap();
+        }
+
+        // The single worker gives up on the hung item and moves on
+        let processed = tokio::time::timeout(Duration::from_secs(1), rx_out.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(processed.source, "test");
+        assert_eq!(metrics::stage_timeouts_total("timeout_test"), before + 1);
+        assert_eq!(in_flight.get(), 1);
+
+        shutdown_tx.send(()).unwrap();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_on_error_drop_discards_item() {
+        let (stage, calls) = FlakyStage::new(1);
+        let output = run_one(stage, ErrorPolicy { max_retries: 3, ..Default::default() }).await;
+
+        assert!(output.is_none());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_on_error_retry_is_bounded() {
+        let retry = |max_retries| ErrorPolicy {
+            on_error: OnError::Retry,
+            max_retries,
+            dead_letter: None,
+        };
+
+        // Succeeds on the third attempt
+        let (stage, calls) = FlakyStage::new(2);
+        assert!(run_one(stage, retry(3)).await.is_some());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        // Gives up after the first attempt plus two retries
+        let (stage, calls) = FlakyStage::new(usize::MAX);
+        assert!(run_one(stage, retry(2)).await.is_none());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_on_error_dead_letter_publishes_item() {
+        use crate::message_bus::MockMessageBus;
+
+        let bus = MockMessageBus::new();
+        let publisher = Arc::new(ResilientPublisher::new(
+            Box::new(bus.clone()),
+            0,
+            std::time::Duration::from_millis(10),
+        ));
+        let policy = ErrorPolicy {
+            on_error: OnError::DeadLetter,
+            max_retries: 3,
+            dead_letter: Some(DeadLetterQueue::new(publisher, "neuro:dead_letter")),
+        };
+
+        let (stage, calls) = FlakyStage::new(usize::MAX);
+        assert!(run_one(stage, policy).await.is_none());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let records = bus.published_records("neuro:dead_letter");
+        assert_eq!(records.len(), 1);
+        let entry: DeadLetter = serde_json::from_value(records[0].clone()).unwrap();
+        assert_eq!(entry.stage, "flaky");
+        assert_eq!(entry.error, "flaky failure 1");
+        assert_eq!(entry.attempts, 1);
+        assert_eq!(entry.correlation_id, "test-corr");
+        assert!(bus.published().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_batch_is_dead_lettered_not_reprocessed() {
+        use crate::message_bus::MockMessageBus;
+
+        let bus = MockMessageBus::new();
+        let publisher = Arc::new(ResilientPublisher::new(
+            Box::new(bus.clone()),
+            0,
+            std::time::Duration::from_millis(10),
+        ));
+        let policy = ErrorPolicy {
+            on_error: OnError::DeadLetter,
+            max_retries: 0,
+            dead_letter: Some(DeadLetterQueue::new(publisher, "neuro:dead_letter")),
+        };
+
+        let (tx_in, rx_in) = mpsc::channel(10);
+        let (tx_out, mut rx_out) = mpsc::channel(10);
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let in_flight = InFlight::default();
+        let worker = BatchWorker::new(
+            "batch_timeout_test",
+            2,
+            Duration::from_secs(60),
+            rx_in,
+            tx_out,
+            Box::new(HangingStage),
+            shutdown_rx,
+        )
+        .with_error_policy(policy)
+        .with_in_flight(in_flight.clone())
+        .with_stage_timeout(Duration::from_millis(50));
+        let handle = tokio::spawn(worker.run());
+
+        // The first item is done before the batch hangs on the second
+        let mut slow = create_test_item();
+        slow.source = "slow".to_string();
+        for item in [create_test_item(), slow] {
+            in_flight.add();
+            tx_in.send(item).await.unwrap();
+        }
+
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while in_flight.get() > 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        // Neither item is processed again on its own
+        assert!(rx_out.try_recv().is_err());
+        assert_eq!(bus.published_records("neuro:dead_letter").len(), 2);
+
+        shutdown_tx.send(()).unwrap();
+        handle.await.unwrap();
+    }
 }
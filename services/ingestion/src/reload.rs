@@ -0,0 +1,164 @@
+//! SIGHUP Config Reload
+//!
+//! On SIGHUP the configuration is reloaded (environment plus `.env`) and a
+//! subset is applied live, without a restart:
+//! - `LOG_LEVEL` → the tracing filter (left unchanged when unset)
+//! - `NADFUN_RATE_LIMIT_RPM`, `RPC_RATE_LIMIT_RPM`, `NEWSAPI_RATE_LIMIT_RPM`,
+//!   `CRYPTOPANIC_RATE_LIMIT_RPM`, `X_API_RATE_LIMIT_RPM` → source rate
+//!   limiters are rebuilt with the new quota
+//!
+//! `--rate-limit` overrides given on the command line still win over reloaded
+//! values. Every other setting needs a restart to change.
+
+use anyhow::Result;
+use futures::{Stream, StreamExt};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+use crate::audit;
+use crate::config::Config;
+use crate::harvester::Harvester;
+use crate::sources::SourceId;
+
+/// Handle for swapping the active tracing filter
+pub type LogFilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Applies hot-reloadable settings from a freshly loaded config
+pub struct ConfigReloader {
+    log_filter: LogFilterHandle,
+    harvester: Option<Arc<Harvester>>,
+    rate_limit_overrides: HashMap<SourceId, u32>,
+}
+
+impl ConfigReloader {
+    pub fn new(log_filter: LogFilterHandle) -> Self {
+        Self {
+            log_filter,
+            harvester: None,
+            rate_limit_overrides: HashMap::new(),
+        }
+    }
+
+    /// Sets the harvester whose source rate limits are reloaded
+    pub fn with_harvester(mut self, harvester: Arc<Harvester>) -> Self {
+        self.harvester = Some(harvester);
+        self
+    }
+
+    /// Sets `--rate-limit` overrides re-applied on top of every reload
+    pub fn with_rate_limit_overrides(mut self, overrides: HashMap<SourceId, u32>) -> Self {
+        self.rate_limit_overrides = overrides;
+        self
+    }
+
+    /// Applies the hot-reloadable fields of `config`
+    ///
+    /// With a harvester set, the reload is recorded as an audit event.
+    pub fn apply(&self, mut config: Config) -> Result<()> {
+        let mut applied = Vec::new();
+
+        if let Some(log_level) = &config.log_level {
+            let filter = EnvFilter::try_new(log_level)?;
+            self.log_filter.reload(filter)?;
+            info!(log_level = %log_level, "Reloaded log level");
+            applied.push("log_level");
+        }
+
+        if let Some(harvester) = &self.harvester {
+            config.apply_rate_limit_overrides(&self.rate_limit_overrides);
+            harvester.apply_rate_limits(&config);
+            info!("Reloaded source rate limits");
+            applied.push("rate_limits");
+
+            harvester.audit().record_in_background(audit::config_reloaded(&applied));
+        }
+
+        Ok(())
+    }
+
+    /// Reloads `Config` from the environment on every SIGHUP until shutdown
+    ///
+    /// The signal handler is installed before returning, so a SIGHUP sent
+    /// right after this call is not missed.
+    #[cfg(unix)]
+    pub fn spawn(
+        self,
+        shutdown_rx: broadcast::Receiver<()>,
+    ) -> std::io::Result<tokio::task::JoinHandle<()>> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut hangup = signal(SignalKind::hangup())?;
+        let hangups = futures::stream::poll_fn(move |cx| hangup.poll_recv(cx));
+        Ok(self.spawn_with(Config::reload, hangups, shutdown_rx))
+    }
+
+    /// Like `spawn`, loading the config with `load` on every item of
+    /// `hangups` until it ends or shutdown
+    pub fn spawn_with<F, S>(
+        self,
+        load: F,
+        mut hangups: S,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) -> tokio::task::JoinHandle<()>
+    where
+        F: Fn() -> Result<Config> + Send + 'static,
+        S: Stream<Item = ()> + Send + Unpin + 'static,
+    {
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = shutdown_rx.recv() => break,
+                    received = hangups.next() => {
+                        if received.is_none() {
+                            break;
+                        }
+                        info!("Received SIGHUP, reloading configuration...");
+                        if let Err(e) = load().and_then(|config| self.apply(config)) {
+                            warn!(error = %e, "Failed to reload configuration");
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[tokio::test]
+    async fn test_hangup_reloads_log_level() {
+        let (layer, handle) = reload::Layer::new(EnvFilter::new("info"));
+        let _subscriber = tracing_subscriber::registry().with(layer);
+
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let load = || -> Result<Config> {
+            Ok(serde_json::from_value(serde_json::json!({ "log_level": "debug" }))?)
+        };
+        let (hangup_tx, hangup_rx) = mpsc::channel(1);
+        let task = ConfigReloader::new(handle.clone()).spawn_with(load, ReceiverStream::new(hangup_rx), shutdown_rx);
+
+        hangup_tx.send(()).await.unwrap();
+
+        let mut level = String::new();
+        for _ in 0..100 {
+            level = handle.with_current(|filter| filter.to_string()).unwrap();
+            if level == "debug" {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert_eq!(level, "debug");
+
+        shutdown_tx.send(()).unwrap();
+        task.await.unwrap();
+    }
+}
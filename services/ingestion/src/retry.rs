@@ -0,0 +1,86 @@
+//! Jittered Exponential Backoff
+//!
+//! Shared by every retry loop in the service: HTTP requests, bus publishes,
+//! Redis reads and source reconnects. The delay doubles from `base` on each
+//! attempt, is scaled by a random factor between 0.5 and 1.5 so clients that
+//! failed together don't retry together, and never exceeds `max`.
+
+use std::time::Duration;
+
+/// Gets the jittered delay before retry `attempt` (0-based)
+pub fn jittered_delay(base: Duration, max: Duration, attempt: u32) -> Duration {
+    let exp = base.saturating_mul(2u32.saturating_pow(attempt.min(16)));
+
+    // Apply jitter: random factor between 0.5 and 1.5
+    let jitter = 0.5 + rand::random::<f64>();
+    Duration::from_secs_f64(exp.min(max).as_secs_f64() * jitter).min(max)
+}
+
+/// Backoff that counts consecutive failures
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    failures: u32,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self { base, max, failures: 0 }
+    }
+
+    /// Gets the delay before the next attempt and counts the failure
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = jittered_delay(self.base, self.max, self.failures);
+        self.failures = self.failures.saturating_add(1);
+        delay
+    }
+
+    /// Starts over from `base` after a success
+    pub fn reset(&mut self) {
+        self.failures = 0;
+    }
+
+    /// Gets the number of failures since the last reset
+    pub fn failures(&self) -> u32 {
+        self.failures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_doubles_with_jitter() {
+        let base = Duration::from_millis(100);
+        let max = Duration::from_millis(800);
+
+        for (attempt, base_ms) in [(0, 100.0), (1, 200.0), (2, 400.0)] {
+            let delays: Vec<f64> = (0..50)
+                .map(|_| jittered_delay(base, max, attempt).as_secs_f64() * 1000.0)
+                .collect();
+            for &delay in &delays {
+                assert!(delay >= base_ms * 0.5 && delay <= base_ms * 1.5, "attempt {}: {}ms", attempt, delay);
+            }
+            // Jitter spreads out the delays
+            assert!(delays.iter().any(|&d| (d - delays[0]).abs() > 1.0));
+        }
+    }
+
+    #[test]
+    fn test_backoff_is_capped_and_resets() {
+        let mut backoff = Backoff::new(Duration::from_millis(100), Duration::from_millis(400));
+
+        let first = backoff.next_delay();
+        assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(150));
+        for _ in 0..20 {
+            assert!(backoff.next_delay() <= Duration::from_millis(400));
+        }
+        assert_eq!(backoff.failures(), 21);
+
+        backoff.reset();
+        assert_eq!(backoff.failures(), 0);
+        assert!(backoff.next_delay() <= Duration::from_millis(150));
+    }
+}
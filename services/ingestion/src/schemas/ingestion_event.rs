@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use super::common::{Status, Severity, Uuid, Timestamp, SchemaVersion};
+use crate::dedup::{canonical_payload_json, payload_hash};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -20,7 +21,7 @@ pub enum IngestionSourceType {
     Manual,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum IngestionDataType {
     TokenData,
@@ -106,12 +107,28 @@ pub struct IngestionEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub batch_index: Option<u32>,
     
+    // Lineage (the harvest that produced this event)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    
     // Timestamps
     pub ingested_at: Timestamp,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data_timestamp: Option<Timestamp>,
 }
 
+/// Namespace for deterministic (UUIDv5) event ids
+const EVENT_ID_NAMESPACE: uuid::Uuid = uuid::Uuid::from_u128(0x6e657572_6f2d_4000_8000_696e67657374);
+
+/// Derives a stable event id from a deduplication key
+///
+/// The same source item always maps to the same id, so replays stay idempotent.
+pub fn deterministic_event_id(deduplication_key: &str) -> Uuid {
+    uuid::Uuid::new_v5(&EVENT_ID_NAMESPACE, deduplication_key.as_bytes()).to_string()
+}
+
 fn default_max_retries() -> u32 {
     3
 }
@@ -166,10 +183,96 @@ impl IngestionEvent {
             is_duplicate: false,
             batch_id: None,
             batch_index: None,
+            session_id: None,
+            correlation_id: None,
             ingested_at: now,
             data_timestamp: None,
         }
     }
+
+    /// Records the harvest session and correlation id that produced this event
+    ///
+    /// Lineage already present (e.g. on replay) is kept.
+    pub fn stamp_lineage(&mut self, session_id: &str, correlation_id: &str) {
+        self.session_id.get_or_insert_with(|| session_id.to_string());
+        self.correlation_id.get_or_insert_with(|| correlation_id.to_string());
+    }
+
+    /// Starts building an event for a source; unset fields keep the `new` defaults
+    pub fn builder(
+        source_type: IngestionSourceType,
+        source_id: impl Into<String>,
+        source_name: impl Into<String>,
+        data_type: IngestionDataType,
+    ) -> IngestionEventBuilder {
+        IngestionEventBuilder {
+            event: Self::new(
+                source_type,
+                source_id.into(),
+                source_name.into(),
+                data_type,
+                HashMap::new(),
+            ),
+            deterministic_id: false,
+        }
+    }
+}
+
+/// Builder for source-produced `IngestionEvent`s
+#[derive(Debug, Clone)]
+pub struct IngestionEventBuilder {
+    event: IngestionEvent,
+    deterministic_id: bool,
+}
+
+impl IngestionEventBuilder {
+    /// Sets the payload, along with its canonical size and hash
+    pub fn payload(mut self, payload: HashMap<String, serde_json::Value>) -> Self {
+        self.event.payload_size = canonical_payload_json(&payload).len() as u64;
+        self.event.payload_hash = Some(payload_hash(&payload));
+        self.event.payload = payload;
+        self
+    }
+
+    pub fn source_url(mut self, source_url: impl Into<String>) -> Self {
+        self.event.source_url = Some(source_url.into());
+        self
+    }
+
+    pub fn data_subtype(mut self, data_subtype: impl Into<String>) -> Self {
+        self.event.data_subtype = Some(data_subtype.into());
+        self
+    }
+
+    pub fn priority(mut self, priority: Severity) -> Self {
+        self.event.priority = priority;
+        self
+    }
+
+    pub fn deduplication_key(mut self, deduplication_key: impl Into<String>) -> Self {
+        self.event.deduplication_key = Some(deduplication_key.into());
+        self
+    }
+
+    pub fn data_timestamp(mut self, data_timestamp: impl Into<String>) -> Self {
+        self.event.data_timestamp = Some(data_timestamp.into());
+        self
+    }
+
+    /// Derives the id from the deduplication key instead of a random UUID
+    pub fn deterministic_id(mut self, enabled: bool) -> Self {
+        self.deterministic_id = enabled;
+        self
+    }
+
+    pub fn build(mut self) -> IngestionEvent {
+        if self.deterministic_id {
+            if let Some(ref key) = self.event.deduplication_key {
+                self.event.id = deterministic_event_id(key);
+            }
+        }
+        self.event
+    }
 }
 
 #[cfg(test)]
@@ -195,4 +298,75 @@ mod tests {
         assert!(json.contains("sourceType"));
         assert!(json.contains("dataType"));
     }
+
+    #[test]
+    fn test_deterministic_event_id() {
+        let id = deterministic_event_id("newsapi:abc");
+        assert_eq!(id, deterministic_event_id("newsapi:abc"));
+        assert_ne!(id, deterministic_event_id("newsapi:abd"));
+        assert_eq!(uuid::Uuid::parse_str(&id).unwrap().get_version_num(), 5);
+    }
+
+    #[test]
+    fn test_builder_matches_manual_construction() {
+        let mut payload = HashMap::new();
+        payload.insert("title".to_string(), serde_json::json!("Monad mainnet launches"));
+        payload.insert("url".to_string(), serde_json::json!("https://example.com/monad"));
+
+        let built = IngestionEvent::builder(
+            IngestionSourceType::NewsApi,
+            "newsapi",
+            "NewsAPI",
+            IngestionDataType::News,
+        )
+        .payload(payload.clone())
+        .source_url("https://example.com/monad")
+        .data_subtype("crypto_news")
+        .priority(Severity::High)
+        .deduplication_key("newsapi:abc")
+        .data_timestamp("2024-01-01T00:00:00Z")
+        .build();
+
+        let now = built.created_at.clone();
+        let manual = IngestionEvent {
+            schema_version: crate::schemas::CURRENT_SCHEMA_VERSION.to_string(),
+            id: built.id.clone(),
+            created_at: now.clone(),
+            updated_at: None,
+            source_type: IngestionSourceType::NewsApi,
+            source_id: "newsapi".to_string(),
+            source_name: "NewsAPI".to_string(),
+            source_url: Some("https://example.com/monad".to_string()),
+            data_type: IngestionDataType::News,
+            data_subtype: Some("crypto_news".to_string()),
+            payload_size: canonical_payload_json(&payload).len() as u64,
+            payload_hash: Some(payload_hash(&payload)),
+            payload,
+            status: Status::Pending,
+            processing_started_at: None,
+            processing_completed_at: None,
+            processing_duration_ms: None,
+            error_message: None,
+            error_code: None,
+            retry_count: 0,
+            max_retries: 3,
+            data_quality_score: None,
+            is_valid: true,
+            validation_errors: vec![],
+            priority: Severity::High,
+            deduplication_key: Some("newsapi:abc".to_string()),
+            is_duplicate: false,
+            batch_id: None,
+            batch_index: None,
+            session_id: None,
+            correlation_id: None,
+            ingested_at: now,
+            data_timestamp: Some("2024-01-01T00:00:00Z".to_string()),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&built).unwrap(),
+            serde_json::to_value(&manual).unwrap()
+        );
+    }
 }
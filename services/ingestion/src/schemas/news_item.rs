@@ -146,7 +146,7 @@ mod tests {
 
     #[test]
     fn test_news_item_serialization() {
-        let item = NewsItem::new(
+        let mut item = NewsItem::new(
             "Test Title".to_string(),
             "Test content".to_string(),
             NewsSource::Twitter,
@@ -154,6 +154,7 @@ mod tests {
             "2024-01-15T10:00:00Z".to_string(),
             "2024-01-15T10:30:00Z".to_string(),
         );
+        item.source_url = Some("https://example.com/article".to_string());
 
         let json = serde_json::to_string_pretty(&item).unwrap();
         println!("{}", json);
@@ -161,7 +162,7 @@ mod tests {
         // Verify camelCase
         assert!(json.contains("schemaVersion"));
         assert!(json.contains("createdAt"));
-        assert!(json.contains("sourceUrl")); // Should not appear if None, but field name is camelCase
+        assert!(json.contains("sourceUrl")); // Skipped when None, so set above to check the field name
         
         // Deserialize back
         let parsed: NewsItem = serde_json::from_str(&json).unwrap();
@@ -4,28 +4,29 @@
 //! https://cryptopanic.com/developers/api/
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, warn, info};
 
-use super::{Source, SourceMetadata, FetchOptions, FetchResult};
+use super::{parse_records, published_before, PriorityConfig, Source, SourceMetadata, FetchOptions, FetchResult, DEFAULT_CLOCK_SKEW_TOLERANCE};
 use crate::circuit_breaker::CircuitBreaker;
 use crate::dedup::news_dedup_key;
 use crate::error::{IngestionError, Result};
 use crate::http_client::{ResilientHttpClient, SourceHttpClient};
-use crate::schemas::{IngestionEvent, IngestionSourceType, IngestionDataType, Status, Severity, CURRENT_SCHEMA_VERSION};
+use crate::schemas::{IngestionEvent, IngestionSourceType, IngestionDataType, Severity};
 
 const CRYPTOPANIC_BASE_URL: &str = "https://cryptopanic.com/api/v1";
 
 /// CryptoPanic API response
 #[derive(Debug, Deserialize)]
+#[allow(dead_code)]
 struct CryptoPanicResponse {
     count: Option<u32>,
     next: Option<String>,
     previous: Option<String>,
-    results: Option<Vec<CryptoPanicPost>>,
+    /// Parsed one by one (see `parse_records`)
+    results: Option<Vec<serde_json::Value>>,
 }
 
 /// A single post from CryptoPanic
@@ -85,6 +86,12 @@ pub struct CryptoPanicSource {
     client: SourceHttpClient,
     api_key: String,
     metadata: SourceMetadata,
+    /// Derive event ids from dedup keys
+    deterministic_ids: bool,
+    /// Publisher clock skew allowed when comparing `published_at` to `since`
+    clock_skew_tolerance: chrono::Duration,
+    /// Vote thresholds for post priority
+    priorities: PriorityConfig,
 }
 
 impl CryptoPanicSource {
@@ -109,15 +116,44 @@ impl CryptoPanicSource {
             default_rate_limit: rate_limit_rpm,
             supports_pagination: true,
             supports_since: true,
+            supports_language: true,
+            estimated_cost: 1,
         };
 
         Self {
             client,
             api_key,
             metadata,
+            deterministic_ids: false,
+            clock_skew_tolerance: DEFAULT_CLOCK_SKEW_TOLERANCE,
+            priorities: PriorityConfig::default(),
         }
     }
 
+    /// Overrides the User-Agent sent with this source's requests
+    pub fn with_user_agent(mut self, user_agent: Option<String>) -> Self {
+        self.client = self.client.with_user_agent(user_agent);
+        self
+    }
+
+    /// Derives event ids from dedup keys (stable across replays)
+    pub fn with_deterministic_ids(mut self, enabled: bool) -> Self {
+        self.deterministic_ids = enabled;
+        self
+    }
+
+    /// Overrides the clock skew allowed when filtering by `since`
+    pub fn with_clock_skew_tolerance(mut self, tolerance: chrono::Duration) -> Self {
+        self.clock_skew_tolerance = tolerance;
+        self
+    }
+
+    /// Overrides the vote thresholds used for post priority
+    pub fn with_priorities(mut self, priorities: PriorityConfig) -> Self {
+        self.priorities = priorities;
+        self
+    }
+
     /// Builds the API URL with parameters
     fn build_url(&self, options: &FetchOptions) -> String {
         let mut params = vec![
@@ -125,11 +161,19 @@ impl CryptoPanicSource {
             ("public", "true".to_string()),
         ];
 
-        // Filter by currencies if specified
-        if let Some(ref query) = options.query {
+        // Filter by currencies if specified (shared filter, then legacy query)
+        let currencies = options.currency_symbols();
+        if !currencies.is_empty() {
+            params.push(("currencies", currencies.join(",")));
+        } else if let Some(ref query) = options.query {
             params.push(("currencies", query.clone()));
         }
 
+        // CryptoPanic regions are language codes (en, de, tr, ...)
+        if let Some(language) = options.language_code() {
+            params.push(("regions", language));
+        }
+
         // Pagination cursor
         if let Some(ref cursor) = options.cursor {
             return cursor.clone(); // CryptoPanic provides full URL for next page
@@ -156,22 +200,18 @@ impl CryptoPanicSource {
 
         let response = self.client.get(&url).await?;
         let text = response.text().await
-            .map_err(|e| IngestionError::HttpError(e))?;
+            .map_err(IngestionError::HttpError)?;
 
         let api_response: CryptoPanicResponse = serde_json::from_str(&text)
-            .map_err(|e| IngestionError::JsonError(e))?;
+            .map_err(IngestionError::JsonError)?;
 
-        let posts = api_response.results.unwrap_or_default();
+        let posts: Vec<CryptoPanicPost> = parse_records("cryptopanic", api_response.results.unwrap_or_default());
         let next_cursor = api_response.next;
 
         // Filter by since timestamp if provided
         let filtered_posts: Vec<CryptoPanicPost> = if let Some(since) = options.since {
             posts.into_iter()
-                .filter(|p| {
-                    DateTime::parse_from_rfc3339(&p.published_at)
-                        .map(|dt| dt.with_timezone(&Utc) >= since)
-                        .unwrap_or(true)
-                })
+                .filter(|p| !published_before(&p.published_at, since, self.clock_skew_tolerance))
                 .collect()
         } else {
             posts
@@ -226,10 +266,6 @@ impl CryptoPanicSource {
             }
         }
 
-        let payload_json = serde_json::to_string(&payload).unwrap_or_default();
-        let payload_size = payload_json.len() as u64;
-        let now = Utc::now().to_rfc3339();
-
         // Create dedup key
         let dedup_key = news_dedup_key(
             "cryptopanic",
@@ -237,55 +273,28 @@ impl CryptoPanicSource {
             Some(&post.url),
             Some(&post.published_at),
         );
-        let content_hash = dedup_key.content_hash.clone();
         let combined_key = dedup_key.combined_key();
 
         // Determine priority based on votes
-        let priority = if let Some(ref votes) = post.votes {
-            if votes.important > 10 || votes.positive > 50 {
-                Severity::High
-            } else if votes.important > 5 || votes.positive > 20 {
-                Severity::Medium
-            } else {
-                Severity::Low
-            }
-        } else {
-            Severity::Medium
+        let priority = match post.votes {
+            Some(ref votes) => self.priorities.news_vote_priority(votes.important, votes.positive),
+            None => Severity::Medium,
         };
 
-        IngestionEvent {
-            schema_version: CURRENT_SCHEMA_VERSION.to_string(),
-            id: uuid::Uuid::new_v4().to_string(),
-            created_at: now.clone(),
-            updated_at: None,
-            source_type: IngestionSourceType::NewsApi,
-            source_id: "cryptopanic".to_string(),
-            source_name: "CryptoPanic".to_string(),
-            source_url: Some(post.url.clone()),
-            data_type: IngestionDataType::News,
-            data_subtype: Some(post.kind.clone()),
-            payload,
-            payload_size,
-            payload_hash: Some(content_hash),
-            status: Status::Pending,
-            processing_started_at: None,
-            processing_completed_at: None,
-            processing_duration_ms: None,
-            error_message: None,
-            error_code: None,
-            retry_count: 0,
-            max_retries: 3,
-            data_quality_score: None,
-            is_valid: true,
-            validation_errors: vec![],
-            priority,
-            deduplication_key: Some(combined_key),
-            is_duplicate: false,
-            batch_id: None,
-            batch_index: None,
-            ingested_at: now,
-            data_timestamp: Some(post.published_at.clone()),
-        }
+        IngestionEvent::builder(
+            IngestionSourceType::NewsApi,
+            "cryptopanic",
+            "CryptoPanic",
+            IngestionDataType::News,
+        )
+        .payload(payload)
+        .source_url(post.url.clone())
+        .data_subtype(post.kind.clone())
+        .priority(priority)
+        .deduplication_key(combined_key)
+        .data_timestamp(post.published_at.clone())
+        .deterministic_id(self.deterministic_ids)
+        .build()
     }
 }
 
@@ -340,6 +349,10 @@ impl Source for CryptoPanicSource {
             }
         }
     }
+
+    fn set_rate_limit(&self, rate_limit_rpm: u32) {
+        self.client.set_rate_limit(rate_limit_rpm);
+    }
 }
 
 #[cfg(test)]
@@ -384,4 +397,36 @@ mod tests {
         assert_eq!(post.currencies[0].code, "BTC");
         assert!(post.votes.is_some());
     }
+
+    #[test]
+    fn test_currencies_filter_maps_to_param() {
+        let source = CryptoPanicSource::new(
+            Arc::new(ResilientHttpClient::with_defaults().unwrap()),
+            "test-key".to_string(),
+            60,
+            Arc::new(CircuitBreaker::with_defaults("cryptopanic")),
+        );
+
+        let url = source.build_url(&FetchOptions::new().currencies(["BTC", "eth", "MON"]));
+        assert!(url.ends_with("&currencies=BTC,ETH,MON"), "{}", url);
+
+        let url = source.build_url(&FetchOptions::new());
+        assert!(!url.contains("currencies="), "{}", url);
+    }
+
+    #[test]
+    fn test_language_filter_maps_to_regions() {
+        let source = CryptoPanicSource::new(
+            Arc::new(ResilientHttpClient::with_defaults().unwrap()),
+            "test-key".to_string(),
+            60,
+            Arc::new(CircuitBreaker::with_defaults("cryptopanic")),
+        );
+
+        let url = source.build_url(&FetchOptions::new().language("TR"));
+        assert!(url.ends_with("&regions=tr"), "{}", url);
+
+        let url = source.build_url(&FetchOptions::new());
+        assert!(!url.contains("regions="), "{}", url);
+    }
 }
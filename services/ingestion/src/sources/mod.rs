@@ -7,13 +7,165 @@ pub mod monad;
 pub mod newsapi;
 pub mod cryptopanic;
 pub mod x_api;
+pub mod reconnect;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::warn;
 
-use crate::error::Result;
-use crate::schemas::IngestionEvent;
+use crate::config::Config;
+use crate::error::{IngestionError, Result};
+use crate::metrics;
+use crate::schemas::{IngestionEvent, Severity};
+
+/// Identifier for every known data source
+///
+/// Strings are only parsed at the CLI/config boundary; internally sources
+/// are always keyed by this enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SourceId {
+    NadFun,
+    Monad,
+    NewsApi,
+    CryptoPanic,
+    XApi,
+}
+
+impl SourceId {
+    /// All known sources
+    pub const ALL: [SourceId; 5] = [
+        SourceId::NadFun,
+        SourceId::Monad,
+        SourceId::NewsApi,
+        SourceId::CryptoPanic,
+        SourceId::XApi,
+    ];
+
+    /// Gets the canonical string id (used in checkpoints, logs and metrics)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SourceId::NadFun => "nadfun",
+            SourceId::Monad => "monad",
+            SourceId::NewsApi => "newsapi",
+            SourceId::CryptoPanic => "cryptopanic",
+            SourceId::XApi => "x_api",
+        }
+    }
+}
+
+impl fmt::Display for SourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SourceId {
+    type Err = IngestionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        SourceId::ALL
+            .into_iter()
+            .find(|id| id.as_str() == s)
+            .ok_or_else(|| IngestionError::UnknownSource(s.to_string()))
+    }
+}
+
+/// Runtime enable/disable flags per source (all enabled by default)
+///
+/// Clones share state, so the admin endpoint and harvester loops see the
+/// same flags.
+#[derive(Debug, Clone, Default)]
+pub struct SourceSwitches {
+    paused: Arc<RwLock<HashSet<SourceId>>>,
+}
+
+impl SourceSwitches {
+    /// Pauses a source; harvester loops skip it until resumed
+    pub fn pause(&self, source_id: SourceId) {
+        self.paused.write().insert(source_id);
+    }
+
+    /// Resumes a paused source
+    pub fn resume(&self, source_id: SourceId) {
+        self.paused.write().remove(&source_id);
+    }
+
+    /// Checks if a source is enabled
+    pub fn is_enabled(&self, source_id: SourceId) -> bool {
+        !self.paused.read().contains(&source_id)
+    }
+}
+
+/// Latest health check result per source (updated by the harvester sweep),
+/// and when each polled source last returned events
+///
+/// Clones share state, like `SourceSwitches`.
+#[derive(Debug, Clone, Default)]
+pub struct SourceHealth {
+    healthy: Arc<RwLock<HashMap<SourceId, bool>>>,
+    last_event: Arc<RwLock<HashMap<SourceId, Instant>>>,
+}
+
+impl SourceHealth {
+    /// Records a health check result
+    pub fn set(&self, source_id: SourceId, healthy: bool) {
+        self.healthy.write().insert(source_id, healthy);
+    }
+
+    /// Gets the last result for a source (`None` if never checked)
+    pub fn get(&self, source_id: SourceId) -> Option<bool> {
+        self.healthy.read().get(&source_id).copied()
+    }
+
+    /// Gets the last result for every checked source
+    pub fn snapshot(&self) -> HashMap<SourceId, bool> {
+        self.healthy.read().clone()
+    }
+
+    /// Checks that no checked source is unhealthy
+    pub fn is_ready(&self) -> bool {
+        self.healthy.read().values().all(|healthy| *healthy)
+    }
+
+    /// Starts tracking when a source returns events (its age counts from now
+    /// until it first does)
+    pub fn watch_events(&self, source_id: SourceId) {
+        self.last_event.write().entry(source_id).or_insert_with(Instant::now);
+    }
+
+    /// Records that a source just returned events
+    pub fn record_events(&self, source_id: SourceId) {
+        self.last_event.write().insert(source_id, Instant::now());
+    }
+
+    /// Gets the time since each tracked source last returned events
+    pub fn event_ages(&self) -> HashMap<SourceId, Duration> {
+        self.last_event
+            .read()
+            .iter()
+            .map(|(source_id, at)| (*source_id, at.elapsed()))
+            .collect()
+    }
+
+    /// Gets the tracked sources without events for longer than `max_age`
+    pub fn stale_sources(&self, max_age: Duration) -> Vec<SourceId> {
+        let mut stale: Vec<SourceId> = self
+            .event_ages()
+            .into_iter()
+            .filter(|(_, age)| *age > max_age)
+            .map(|(source_id, _)| source_id)
+            .collect();
+        stale.sort();
+        stale
+    }
+}
 
 /// Metadata about a source
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +182,16 @@ pub struct SourceMetadata {
     pub supports_pagination: bool,
     /// Whether the source supports --since parameter
     pub supports_since: bool,
+    /// Whether the source filters by language itself (see [`LANGUAGE_FILTER`])
+    #[serde(default)]
+    pub supports_language: bool,
+    /// Quota units (requests, or paid API units) one fetch uses
+    #[serde(default = "default_estimated_cost")]
+    pub estimated_cost: u32,
+}
+
+fn default_estimated_cost() -> u32 {
+    1
 }
 
 /// Result of a fetch operation
@@ -67,6 +229,153 @@ impl FetchResult {
     }
 }
 
+/// Filter key for restricting a fetch to specific coins
+///
+/// The value is a comma-separated list of ticker symbols
+/// (`filters["currencies"] = "BTC,ETH,MON"`), mapped by each source to its
+/// own API: CryptoPanic's `currencies=` parameter, X cashtags
+/// (`$BTC OR $ETH`), and CoinGecko coin ids.
+pub const CURRENCIES_FILTER: &str = "currencies";
+
+/// Filter key for restricting a fetch to one language
+///
+/// The value is an ISO 639-1 code (`filters["language"] = "en"`), mapped by
+/// each source to its own API: NewsAPI's `language=` parameter, X's `lang:`
+/// query operator, and CryptoPanic's `regions=`. Sources without
+/// `supports_language` are filtered after the fetch on the events' payload
+/// `language` (see [`retain_language`]).
+pub const LANGUAGE_FILTER: &str = "language";
+
+/// Engagement thresholds that map source items to a `Severity`
+///
+/// Each threshold must be exceeded (strictly) to reach its level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityConfig {
+    /// Author followers for a social post to be High (verified authors always are)
+    pub social_followers_high: u64,
+    /// Likes for a social post to be Medium
+    pub social_likes_medium: u64,
+    /// Reposts for a social post to be Medium
+    pub social_reposts_medium: u64,
+    /// `important` votes for a news post to be Critical (with `positive` too)
+    pub news_important_critical: u32,
+    /// `positive` votes for a news post to be Critical (with `important` too)
+    pub news_positive_critical: u32,
+    /// `important` votes for a news post to be High
+    pub news_important_high: u32,
+    /// `positive` votes for a news post to be High
+    pub news_positive_high: u32,
+    /// `important` votes for a news post to be Medium
+    pub news_important_medium: u32,
+    /// `positive` votes for a news post to be Medium
+    pub news_positive_medium: u32,
+}
+
+impl Default for PriorityConfig {
+    fn default() -> Self {
+        Self {
+            social_followers_high: 100_000,
+            social_likes_medium: 100,
+            social_reposts_medium: 50,
+            news_important_critical: 50,
+            news_positive_critical: 100,
+            news_important_high: 10,
+            news_positive_high: 50,
+            news_important_medium: 5,
+            news_positive_medium: 20,
+        }
+    }
+}
+
+impl PriorityConfig {
+    /// Creates thresholds from the service configuration, keeping the
+    /// defaults for any that aren't set
+    pub fn from_config(config: &Config) -> Self {
+        let defaults = Self::default();
+        Self {
+            social_followers_high: config.priority_social_followers_high.unwrap_or(defaults.social_followers_high),
+            social_likes_medium: config.priority_social_likes_medium.unwrap_or(defaults.social_likes_medium),
+            social_reposts_medium: config.priority_social_reposts_medium.unwrap_or(defaults.social_reposts_medium),
+            news_important_critical: config.priority_news_important_critical.unwrap_or(defaults.news_important_critical),
+            news_positive_critical: config.priority_news_positive_critical.unwrap_or(defaults.news_positive_critical),
+            news_important_high: config.priority_news_important_high.unwrap_or(defaults.news_important_high),
+            news_positive_high: config.priority_news_positive_high.unwrap_or(defaults.news_positive_high),
+            news_important_medium: config.priority_news_important_medium.unwrap_or(defaults.news_important_medium),
+            news_positive_medium: config.priority_news_positive_medium.unwrap_or(defaults.news_positive_medium),
+        }
+    }
+
+    /// Gets the priority of a social post from its author and engagement
+    pub fn social_priority(&self, verified: bool, followers: u64, likes: u64, reposts: u64) -> Severity {
+        if verified || followers > self.social_followers_high {
+            Severity::High
+        } else if likes > self.social_likes_medium || reposts > self.social_reposts_medium {
+            Severity::Medium
+        } else {
+            Severity::Low
+        }
+    }
+
+    /// Gets the priority of a news post from its community votes
+    pub fn news_vote_priority(&self, important: u32, positive: u32) -> Severity {
+        if important > self.news_important_critical && positive > self.news_positive_critical {
+            Severity::Critical
+        } else if important > self.news_important_high || positive > self.news_positive_high {
+            Severity::High
+        } else if important > self.news_important_medium || positive > self.news_positive_medium {
+            Severity::Medium
+        } else {
+            Severity::Low
+        }
+    }
+}
+
+/// Deserializes each record of a response array on its own, so one
+/// malformed record doesn't fail the whole batch
+///
+/// Records that don't match `T` are skipped and counted in
+/// `ingestion_parse_errors_total{source}`.
+pub fn parse_records<T: serde::de::DeserializeOwned>(source: &str, records: Vec<serde_json::Value>) -> Vec<T> {
+    records
+        .into_iter()
+        .filter_map(|record| match serde_json::from_value(record) {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                metrics::record_parse_error(source);
+                warn!(source = source, error = %e, "Skipping malformed record");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Default allowance for publisher clock skew when comparing item timestamps
+/// against `since`
+pub const DEFAULT_CLOCK_SKEW_TOLERANCE: chrono::Duration = chrono::Duration::seconds(30);
+
+/// Whether an RFC 3339 `timestamp` falls before `since`, allowing
+/// `tolerance` of publisher clock skew
+///
+/// An item stamped up to `tolerance` before `since` is kept, since the
+/// publisher's clock may lag ours; unparseable timestamps are never before.
+pub fn published_before(timestamp: &str, since: DateTime<Utc>, tolerance: chrono::Duration) -> bool {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|dt| dt.with_timezone(&Utc) + tolerance < since)
+        .unwrap_or(false)
+}
+
+/// Keeps the events whose payload `language` (or `lang`) matches `language`
+///
+/// Events that carry no language are kept, since nothing says they differ.
+pub fn retain_language(events: &mut Vec<IngestionEvent>, language: &str) {
+    events.retain(|event| {
+        ["language", "lang"]
+            .iter()
+            .find_map(|key| event.payload.get(*key).and_then(|value| value.as_str()))
+            .is_none_or(|value| value.trim().eq_ignore_ascii_case(language))
+    });
+}
+
 /// Options for fetching data
 #[derive(Debug, Clone, Default)]
 pub struct FetchOptions {
@@ -78,7 +387,8 @@ pub struct FetchOptions {
     pub cursor: Option<String>,
     /// Query/search term
     pub query: Option<String>,
-    /// Additional filters as key-value pairs
+    /// Additional filters as key-value pairs (see [`CURRENCIES_FILTER`],
+    /// [`LANGUAGE_FILTER`])
     pub filters: std::collections::HashMap<String, String>,
 }
 
@@ -97,15 +407,69 @@ impl FetchOptions {
         self
     }
 
+    #[allow(dead_code)]
     pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
         self.cursor = Some(cursor.into());
         self
     }
 
+    #[allow(dead_code)]
     pub fn query(mut self, query: impl Into<String>) -> Self {
         self.query = Some(query.into());
         self
     }
+
+    pub fn filter(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.filters.insert(key.into(), value.into());
+        self
+    }
+
+    /// Restricts the fetch to the given ticker symbols
+    #[allow(dead_code)]
+    pub fn currencies<I, S>(self, symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let symbols: Vec<String> = symbols.into_iter().map(|s| s.as_ref().to_string()).collect();
+        self.filter(CURRENCIES_FILTER, symbols.join(","))
+    }
+
+    /// Restricts the fetch to one language (ISO 639-1 code, like `en`)
+    pub fn language(self, code: impl Into<String>) -> Self {
+        self.filter(LANGUAGE_FILTER, code)
+    }
+
+    /// Restricts the fetch to `code` (when given) unless a language is
+    /// already set
+    pub fn default_language(self, code: Option<&str>) -> Self {
+        match code {
+            Some(code) if self.language_code().is_none() => self.language(code),
+            _ => self,
+        }
+    }
+
+    /// Gets the requested language code (lowercased, `None` if unfiltered)
+    pub fn language_code(&self) -> Option<String> {
+        self.filters
+            .get(LANGUAGE_FILTER)
+            .map(|value| value.trim().to_lowercase())
+            .filter(|code| !code.is_empty())
+    }
+
+    /// Gets the requested ticker symbols (uppercased, empty if unfiltered)
+    pub fn currency_symbols(&self) -> Vec<String> {
+        self.filters
+            .get(CURRENCIES_FILTER)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|symbol| symbol.trim().trim_start_matches('$').to_uppercase())
+                    .filter(|symbol| !symbol.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 }
 
 /// Trait for all data sources
@@ -120,20 +484,125 @@ pub trait Source: Send + Sync {
     /// Checks if the source is healthy/available
     async fn health_check(&self) -> Result<bool>;
 
+    /// Applies a new requests-per-minute limit (sources without a rate
+    /// limiter ignore it)
+    fn set_rate_limit(&self, _rate_limit_rpm: u32) {}
+
+    /// Gets the quota units one scheduled fetch is expected to use, for
+    /// budget-aware scheduling
+    fn estimated_cost(&self) -> u32 {
+        self.metadata().estimated_cost
+    }
+
     /// Gets the source ID
+    #[allow(dead_code)]
     fn id(&self) -> &str {
         &self.metadata().id
     }
 
     /// Gets the source name
+    #[allow(dead_code)]
     fn name(&self) -> &str {
         &self.metadata().name
     }
 }
 
-/// Re-export source types
-pub use nadfun::NadFunSource;
-pub use monad::MonadSource;
-pub use newsapi::NewsApiSource;
-pub use cryptopanic::CryptoPanicSource;
-pub use x_api::{XApiSource, XApiAdapter};
+pub use x_api::XApiAdapter;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_symbols_normalized() {
+        let options = FetchOptions::new().filter(CURRENCIES_FILTER, " btc, $ETH,,MON ");
+        assert_eq!(options.currency_symbols(), vec!["BTC", "ETH", "MON"]);
+        assert!(FetchOptions::new().currency_symbols().is_empty());
+    }
+
+    #[test]
+    fn test_language_filter_applied_after_fetch() {
+        let options = FetchOptions::new().filter(LANGUAGE_FILTER, " TR ");
+        assert_eq!(options.language_code().as_deref(), Some("tr"));
+        assert_eq!(FetchOptions::new().language("").language_code(), None);
+
+        let event = |language: Option<&str>| {
+            let mut payload = HashMap::new();
+            if let Some(language) = language {
+                payload.insert("language".to_string(), serde_json::json!(language));
+            }
+            IngestionEvent::new(
+                crate::schemas::IngestionSourceType::NewsApi,
+                "newsapi".to_string(),
+                "NewsAPI".to_string(),
+                crate::schemas::IngestionDataType::News,
+                payload,
+            )
+        };
+        let mut events = vec![event(Some("en")), event(Some("TR")), event(None)];
+        retain_language(&mut events, "tr");
+
+        let languages: Vec<_> = events.iter().map(|e| e.payload.get("language").cloned()).collect();
+        assert_eq!(languages, vec![Some(serde_json::json!("TR")), None]);
+    }
+
+    #[test]
+    fn test_priority_thresholds_are_configurable() {
+        let priorities = PriorityConfig {
+            social_followers_high: 5_000,
+            social_likes_medium: 20,
+            news_important_high: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(priorities.social_priority(false, 5_001, 0, 0), Severity::High);
+        assert_eq!(priorities.social_priority(false, 5_000, 0, 0), Severity::Low);
+        assert_eq!(priorities.social_priority(true, 0, 0, 0), Severity::High);
+        assert_eq!(priorities.social_priority(false, 0, 21, 0), Severity::Medium);
+        assert_eq!(priorities.social_priority(false, 0, 20, 0), Severity::Low);
+
+        assert_eq!(priorities.news_vote_priority(4, 0), Severity::High);
+        assert_eq!(priorities.news_vote_priority(3, 0), Severity::Low);
+        assert_eq!(priorities.news_vote_priority(51, 101), Severity::Critical);
+
+        // Unset config values keep the built-in defaults
+        let config: Config = serde_json::from_value(serde_json::json!({
+            "priority_social_likes_medium": 20,
+        }))
+        .unwrap();
+        let priorities = PriorityConfig::from_config(&config);
+        assert_eq!(priorities.social_likes_medium, 20);
+        assert_eq!(priorities.social_followers_high, PriorityConfig::default().social_followers_high);
+    }
+
+    #[test]
+    fn test_published_before_allows_clock_skew() {
+        let since = Utc::now();
+        let tolerance = chrono::Duration::seconds(5);
+        let stamp = |offset_secs: i64| (since + chrono::Duration::seconds(offset_secs)).to_rfc3339();
+
+        // A few seconds in the future (publisher clock ahead) is after `since`
+        assert!(!published_before(&stamp(3), since, tolerance));
+        // A publisher clock lagging within the tolerance is still kept
+        assert!(!published_before(&stamp(-3), since, tolerance));
+        assert!(published_before(&stamp(-3), since, chrono::Duration::zero()));
+        // Beyond the tolerance the item really predates `since`
+        assert!(published_before(&stamp(-10), since, tolerance));
+        assert!(!published_before("not a date", since, tolerance));
+    }
+
+    #[test]
+    fn test_source_id_round_trip() {
+        for id in SourceId::ALL {
+            let parsed: SourceId = id.as_str().parse().unwrap();
+            assert_eq!(parsed, id);
+            assert_eq!(id.to_string(), id.as_str());
+        }
+    }
+
+    #[test]
+    fn test_unknown_source_id_is_error() {
+        let err = "x-api".parse::<SourceId>().unwrap_err();
+        assert!(matches!(err, IngestionError::UnknownSource(ref s) if s == "x-api"));
+    }
+}
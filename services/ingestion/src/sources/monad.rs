@@ -1,4 +1,8 @@
 //! Monad RPC data source
+//!
+//! Supports multiple RPC endpoints: requests go to the primary and fail over
+//! to the next endpoint on transport errors. Each endpoint has its own
+//! circuit breaker so a dead endpoint is skipped until it recovers.
 
 use governor::{Quota, RateLimiter, state::NotKeyed, clock::DefaultClock, middleware::NoOpMiddleware};
 use reqwest::Client;
@@ -6,9 +10,13 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::num::NonZeroU32;
 use std::sync::Arc;
-use tracing::debug;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
 
+use crate::circuit_breaker::CircuitBreaker;
 use crate::error::{IngestionError, Result};
+use crate::metrics;
 
 /// Chain statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,17 +26,55 @@ pub struct ChainStats {
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
+/// How long fetched chain stats are reused before hitting the RPC again
+pub const CHAIN_STATS_CACHE_TTL: Duration = Duration::from_secs(2);
+
+/// A single RPC endpoint and its health
+#[allow(dead_code)]
+struct RpcEndpoint {
+    url: String,
+    /// Host (and port) of `url`, used in metrics and logs so API keys in
+    /// the path or query never leave the process
+    label: String,
+    circuit_breaker: CircuitBreaker,
+}
+
 /// Monad RPC client
+#[allow(dead_code)]
 #[derive(Clone)]
 pub struct MonadSource {
     client: Client,
+    /// Primary RPC URL
     rpc_url: String,
+    /// All endpoints in failover order, primary first
+    endpoints: Arc<Vec<RpcEndpoint>>,
     rate_limiter: Arc<RateLimiter<NotKeyed, governor::state::InMemoryState, DefaultClock, NoOpMiddleware>>,
+    /// Last chain stats and when they were fetched (shared across clones)
+    stats_cache: Arc<Mutex<Option<(Instant, ChainStats)>>>,
+    cache_ttl: Duration,
 }
 
+#[allow(dead_code)]
 impl MonadSource {
     /// Creates a new Monad RPC source
     pub fn new(rpc_url: &str, rate_limit_rpm: u32) -> Self {
+        Self::from_urls(&[rpc_url.to_string()], rate_limit_rpm)
+    }
+    
+    /// Creates a Monad RPC source with failover endpoints (primary first)
+    ///
+    /// Fails if `rpc_urls` is empty.
+    pub fn with_endpoints(rpc_urls: &[String], rate_limit_rpm: u32) -> Result<Self> {
+        if rpc_urls.is_empty() {
+            return Err(IngestionError::ValidationError(
+                "at least one Monad RPC URL is required".to_string(),
+            ));
+        }
+        Ok(Self::from_urls(rpc_urls, rate_limit_rpm))
+    }
+    
+    /// Builds the source from a non-empty endpoint list
+    fn from_urls(rpc_urls: &[String], rate_limit_rpm: u32) -> Self {
         let client = Client::builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
@@ -37,20 +83,41 @@ impl MonadSource {
         let quota = Quota::per_minute(NonZeroU32::new(rate_limit_rpm).unwrap());
         let rate_limiter = Arc::new(RateLimiter::direct(quota));
         
+        let endpoints = rpc_urls
+            .iter()
+            .map(|url| {
+                let label = endpoint_label(url);
+                RpcEndpoint {
+                    url: url.clone(),
+                    circuit_breaker: CircuitBreaker::with_defaults(format!("monad:{}", label)),
+                    label,
+                }
+            })
+            .collect();
+        
         Self {
             client,
-            rpc_url: rpc_url.to_string(),
+            rpc_url: rpc_urls[0].clone(),
+            endpoints: Arc::new(endpoints),
             rate_limiter,
+            stats_cache: Arc::new(Mutex::new(None)),
+            cache_ttl: CHAIN_STATS_CACHE_TTL,
         }
     }
     
+    /// Sets the chain stats cache TTL (zero disables caching)
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+    
     /// Waits for rate limit if necessary
     async fn wait_for_rate_limit(&self) -> Result<()> {
         self.rate_limiter.until_ready().await;
         Ok(())
     }
     
-    /// Makes a JSON-RPC call
+    /// Makes a JSON-RPC call, failing over between endpoints
     async fn rpc_call<T: for<'de> Deserialize<'de>>(
         &self,
         method: &str,
@@ -65,11 +132,48 @@ impl MonadSource {
             "id": 1
         });
         
-        debug!(method = %method, "Making RPC call");
+        let mut last_error = None;
         
+        for endpoint in self.endpoints.iter() {
+            if !endpoint.circuit_breaker.allow_request() {
+                debug!(endpoint = %endpoint.label, "Skipping RPC endpoint with open circuit");
+                continue;
+            }
+            
+            debug!(method = %method, endpoint = %endpoint.label, "Making RPC call");
+            
+            match self.send_rpc_request::<T>(&endpoint.url, &request).await {
+                Ok(rpc_response) => {
+                    endpoint.circuit_breaker.record_success();
+                    metrics::record_rpc_request(&endpoint.label, metrics::RPC_STATUS_SUCCESS);
+                    return Self::into_result(rpc_response);
+                }
+                Err(e) => {
+                    endpoint.circuit_breaker.record_failure();
+                    metrics::record_rpc_request(&endpoint.label, metrics::RPC_STATUS_ERROR);
+                    warn!(
+                        method = %method,
+                        endpoint = %endpoint.label,
+                        error = %e,
+                        "RPC endpoint failed, trying next"
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+        
+        Err(last_error.unwrap_or_else(|| IngestionError::CircuitBreakerOpen("monad".to_string())))
+    }
+    
+    /// Sends a JSON-RPC request to a single endpoint
+    async fn send_rpc_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        url: &str,
+        request: &serde_json::Value,
+    ) -> Result<RpcResponse<T>> {
         let response = self.client
-            .post(&self.rpc_url)
-            .json(&request)
+            .post(url)
+            .json(request)
             .send()
             .await?;
         
@@ -80,8 +184,11 @@ impl MonadSource {
             });
         }
         
-        let rpc_response: RpcResponse<T> = response.json().await?;
-        
+        Ok(response.json().await?)
+    }
+    
+    /// Converts a JSON-RPC response into its result or error
+    fn into_result<T>(rpc_response: RpcResponse<T>) -> Result<T> {
         match rpc_response.result {
             Some(result) => Ok(result),
             None => {
@@ -98,7 +205,37 @@ impl MonadSource {
     }
     
     /// Fetches current chain statistics
+    ///
+    /// Results are cached for `cache_ttl`, so rapid successive callers share
+    /// one set of RPC calls. The lock is held while fetching, so concurrent
+    /// callers wait for the in-flight request instead of issuing their own.
     pub async fn fetch_chain_stats(&self) -> Result<ChainStats> {
+        let mut cache = self.stats_cache.lock().await;
+        if let Some((fetched_at, ref stats)) = *cache {
+            if fetched_at.elapsed() < self.cache_ttl {
+                debug!("Using cached chain stats");
+                return Ok(stats.clone());
+            }
+        }
+        
+        let stats = self.fetch_chain_stats_uncached().await?;
+        *cache = Some((Instant::now(), stats.clone()));
+        Ok(stats)
+    }
+    
+    /// Checks RPC connectivity (reuses cached chain stats when fresh)
+    pub async fn health_check(&self) -> Result<bool> {
+        match self.fetch_chain_stats().await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                warn!(error = %e, "Monad RPC health check failed");
+                Ok(false)
+            }
+        }
+    }
+    
+    /// Fetches chain statistics directly from the RPC
+    async fn fetch_chain_stats_uncached(&self) -> Result<ChainStats> {
         // Get block number
         let block_hex: String = self.rpc_call("eth_blockNumber", json!([])).await?;
         let block_number = u64::from_str_radix(block_hex.trim_start_matches("0x"), 16)
@@ -143,7 +280,23 @@ impl MonadSource {
     }
 }
 
+/// Reduces an RPC URL to `host[:port]`
+///
+/// Provider URLs often carry the API key in the path or query, and a full
+/// URL per label would make the metric's cardinality unbounded.
+fn endpoint_label(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => match (parsed.host_str(), parsed.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => "unknown".to_string(),
+        },
+        Err(_) => "invalid".to_string(),
+    }
+}
+
 /// JSON-RPC response
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct RpcResponse<T> {
     result: Option<T>,
@@ -151,6 +304,7 @@ struct RpcResponse<T> {
 }
 
 /// JSON-RPC error
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct RpcError {
     code: i32,
@@ -160,10 +314,101 @@ struct RpcError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use wiremock::matchers::{body_partial_json, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[test]
     fn test_source_creation() {
         let source = MonadSource::new("https://rpc.monad.xyz", 300);
         assert_eq!(source.rpc_url, "https://rpc.monad.xyz");
     }
+
+    async fn mount_rpc(server: &MockServer, rpc_method: &str, result: &str, expected_calls: u64) {
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "method": rpc_method })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": result,
+            })))
+            .expect(expected_calls)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_chain_stats_cached_within_ttl() {
+        let server = MockServer::start().await;
+        mount_rpc(&server, "eth_blockNumber", "0x10", 1).await;
+        mount_rpc(&server, "eth_gasPrice", "0x3b9aca00", 1).await;
+
+        let source = MonadSource::new(&server.uri(), 600);
+        let first = source.fetch_chain_stats().await.unwrap();
+        let second = source.clone().fetch_chain_stats().await.unwrap();
+        assert!(source.health_check().await.unwrap());
+
+        assert_eq!(first.block_number, 16);
+        assert_eq!(second.block_number, 16);
+        assert_eq!(second.gas_price_gwei, 1.0);
+        assert_eq!(first.timestamp, second.timestamp);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_failover_to_next_endpoint() {
+        let failing = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&failing)
+            .await;
+
+        let healthy = MockServer::start().await;
+        mount_rpc(&healthy, "eth_blockNumber", "0x2a", 1).await;
+        mount_rpc(&healthy, "eth_gasPrice", "0x3b9aca00", 1).await;
+
+        // Mock servers are pooled, so compare against the counts before the fetch
+        let count = |uri: &str, status: &str| metrics::rpc_requests_total(&endpoint_label(uri), status);
+        let failing_errors = count(&failing.uri(), metrics::RPC_STATUS_ERROR);
+        let failing_successes = count(&failing.uri(), metrics::RPC_STATUS_SUCCESS);
+        let healthy_successes = count(&healthy.uri(), metrics::RPC_STATUS_SUCCESS);
+
+        let source = MonadSource::with_endpoints(&[failing.uri(), healthy.uri()], 600).unwrap();
+        let stats = source.fetch_chain_stats().await.unwrap();
+
+        assert_eq!(stats.block_number, 42);
+        assert_eq!(count(&failing.uri(), metrics::RPC_STATUS_ERROR), failing_errors + 2);
+        assert_eq!(count(&failing.uri(), metrics::RPC_STATUS_SUCCESS), failing_successes);
+        assert_eq!(count(&healthy.uri(), metrics::RPC_STATUS_SUCCESS), healthy_successes + 2);
+        assert_eq!(source.endpoints[0].circuit_breaker.stats().total_failures, 2);
+    }
+
+    #[test]
+    fn test_endpoint_label_hides_path_and_query() {
+        assert_eq!(
+            endpoint_label("https://monad-mainnet.g.alchemy.com/v2/secret-key"),
+            "monad-mainnet.g.alchemy.com"
+        );
+        assert_eq!(endpoint_label("http://127.0.0.1:8545/?apikey=secret"), "127.0.0.1:8545");
+        assert_eq!(endpoint_label("not a url"), "invalid");
+    }
+
+    #[test]
+    fn test_with_endpoints_rejects_empty_list() {
+        assert!(matches!(
+            MonadSource::with_endpoints(&[], 600),
+            Err(IngestionError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_chain_stats_refetched_after_ttl() {
+        let server = MockServer::start().await;
+        mount_rpc(&server, "eth_blockNumber", "0x10", 2).await;
+        mount_rpc(&server, "eth_gasPrice", "0x3b9aca00", 2).await;
+
+        let source = MonadSource::new(&server.uri(), 600)
+            .with_cache_ttl(Duration::from_millis(50));
+        source.fetch_chain_stats().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        source.fetch_chain_stats().await.unwrap();
+    }
 }
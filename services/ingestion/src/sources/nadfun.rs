@@ -33,6 +33,7 @@ pub struct TokenData {
 }
 
 /// nad.fun API client
+#[allow(dead_code)]
 #[derive(Clone)]
 pub struct NadFunSource {
     client: Client,
@@ -41,6 +42,7 @@ pub struct NadFunSource {
     rate_limiter: Arc<RateLimiter<NotKeyed, governor::state::InMemoryState, DefaultClock, NoOpMiddleware>>,
 }
 
+#[allow(dead_code)]
 impl NadFunSource {
     /// Creates a new nad.fun source
     pub fn new(base_url: &str, api_key: Option<&str>, rate_limit_rpm: u32) -> Self {
@@ -135,6 +137,7 @@ impl NadFunSource {
 }
 
 /// API response wrapper
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct ApiResponse<T> {
     data: Option<T>,
@@ -4,28 +4,40 @@
 //! https://newsapi.org/docs/endpoints/everything
 
 use async_trait::async_trait;
-use chrono::Utc;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use futures::stream::{self, StreamExt};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, warn, info};
 
-use super::{Source, SourceMetadata, FetchOptions, FetchResult};
+use super::{parse_records, published_before, Source, SourceMetadata, FetchOptions, FetchResult, DEFAULT_CLOCK_SKEW_TOLERANCE};
 use crate::circuit_breaker::CircuitBreaker;
 use crate::dedup::news_dedup_key;
 use crate::error::{IngestionError, Result};
 use crate::http_client::{ResilientHttpClient, SourceHttpClient};
-use crate::schemas::{IngestionEvent, IngestionSourceType, IngestionDataType, Status, Severity, CURRENT_SCHEMA_VERSION};
+use crate::schemas::{IngestionEvent, IngestionSourceType, IngestionDataType};
 
 const NEWSAPI_BASE_URL: &str = "https://newsapi.org/v2";
 
+/// Error code for the free tier's cap on reachable results
+const MAXIMUM_RESULTS_REACHED: &str = "maximumResultsReached";
+
+/// Search used when no queries are configured: one request covering what
+/// separate crypto, bitcoin/ethereum, blockchain and DeFi queries would
+const DEFAULT_QUERY: &str = "cryptocurrency OR bitcoin OR ethereum OR blockchain OR defi OR \"decentralized finance\"";
+
+/// Configured queries fetched at once when no query is given
+pub const DEFAULT_QUERY_CONCURRENCY: usize = 3;
+
 /// NewsAPI response structures
 #[derive(Debug, Deserialize)]
 struct NewsApiResponse {
     status: String,
+    #[allow(dead_code)]
     #[serde(rename = "totalResults")]
     total_results: Option<u32>,
-    articles: Option<Vec<NewsArticle>>,
+    /// Parsed one by one (see `parse_records`)
+    articles: Option<Vec<serde_json::Value>>,
     code: Option<String>,
     message: Option<String>,
 }
@@ -54,9 +66,16 @@ pub struct ArticleSource {
 pub struct NewsApiSource {
     client: SourceHttpClient,
     api_key: String,
+    base_url: String,
     metadata: SourceMetadata,
-    /// Default search queries for crypto news
+    /// Search queries fetched when no query is given (one request each)
     default_queries: Vec<String>,
+    /// Default queries fetched at once
+    query_concurrency: usize,
+    /// Derive event ids from dedup keys
+    deterministic_ids: bool,
+    /// Publisher clock skew allowed when comparing `publishedAt` to `since`
+    clock_skew_tolerance: chrono::Duration,
 }
 
 impl NewsApiSource {
@@ -72,7 +91,8 @@ impl NewsApiSource {
             "newsapi",
             rate_limit_rpm,
             circuit_breaker,
-        );
+        )
+        .with_soft_errors(is_results_limit_error);
 
         let metadata = SourceMetadata {
             id: "newsapi".to_string(),
@@ -81,29 +101,70 @@ impl NewsApiSource {
             default_rate_limit: rate_limit_rpm,
             supports_pagination: true,
             supports_since: true,
+            supports_language: true,
+            estimated_cost: 1,
         };
 
         Self {
             client,
             api_key,
+            base_url: NEWSAPI_BASE_URL.to_string(),
             metadata,
-            default_queries: vec![
-                "cryptocurrency".to_string(),
-                "bitcoin OR ethereum".to_string(),
-                "blockchain".to_string(),
-                "defi OR \"decentralized finance\"".to_string(),
-                "monad blockchain".to_string(),
-            ],
+            default_queries: vec![DEFAULT_QUERY.to_string()],
+            query_concurrency: DEFAULT_QUERY_CONCURRENCY,
+            deterministic_ids: false,
+            clock_skew_tolerance: DEFAULT_CLOCK_SKEW_TOLERANCE,
+        }
+    }
+
+    /// Overrides the User-Agent sent with this source's requests
+    pub fn with_user_agent(mut self, user_agent: Option<String>) -> Self {
+        self.client = self.client.with_user_agent(user_agent);
+        self
+    }
+
+    /// Overrides the API base URL (proxies, tests)
+    #[allow(dead_code)]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Replaces the default search query with several, each fetched as its
+    /// own request (an empty list keeps the built-in one)
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        if !queries.is_empty() {
+            self.default_queries = queries;
         }
+        self
+    }
+
+    /// Sets how many default queries are fetched at once (at least 1)
+    pub fn with_query_concurrency(mut self, concurrency: usize) -> Self {
+        self.query_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Derives event ids from dedup keys (stable across replays)
+    pub fn with_deterministic_ids(mut self, enabled: bool) -> Self {
+        self.deterministic_ids = enabled;
+        self
+    }
+
+    /// Overrides the clock skew allowed when filtering by `since`
+    pub fn with_clock_skew_tolerance(mut self, tolerance: chrono::Duration) -> Self {
+        self.clock_skew_tolerance = tolerance;
+        self
     }
 
     /// Fetches news for a specific query
     pub async fn fetch_query(&self, query: &str, options: &FetchOptions) -> Result<Vec<NewsArticle>> {
         let mut params: Vec<(&str, String)> = vec![
             ("q", query.to_string()),
-            ("language", "en".to_string()),
+            ("language", options.language_code().unwrap_or_else(|| "en".to_string())),
             ("sortBy", "publishedAt".to_string()),
             ("pageSize", options.limit.unwrap_or(100).to_string()),
+            ("apiKey", self.api_key.clone()),
         ];
 
         if let Some(since) = options.since {
@@ -115,16 +176,13 @@ impl NewsApiSource {
             params.push(("page", cursor.clone()));
         }
 
-        let url = format!("{}/everything", NEWSAPI_BASE_URL);
-        
-        // Note: API key should be passed via header in production
-        // For now, params include it in query string
+        let url = format!("{}/everything", self.base_url);
         let response = self.client.get_with_query(&url, &params).await?;
         let text = response.text().await
-            .map_err(|e| IngestionError::HttpError(e))?;
+            .map_err(IngestionError::HttpError)?;
 
         let api_response: NewsApiResponse = serde_json::from_str(&text)
-            .map_err(|e| IngestionError::JsonError(e))?;
+            .map_err(IngestionError::JsonError)?;
 
         if api_response.status != "ok" {
             return Err(IngestionError::ApiError {
@@ -133,7 +191,7 @@ impl NewsApiSource {
             });
         }
 
-        Ok(api_response.articles.unwrap_or_default())
+        Ok(parse_records("newsapi", api_response.articles.unwrap_or_default()))
     }
 
     /// Converts a NewsAPI article to an IngestionEvent
@@ -158,10 +216,6 @@ impl NewsApiSource {
             payload.insert("imageUrl".to_string(), serde_json::json!(image));
         }
 
-        let payload_json = serde_json::to_string(&payload).unwrap_or_default();
-        let payload_size = payload_json.len() as u64;
-        let now = Utc::now().to_rfc3339();
-
         // Create dedup key
         let dedup_key = news_dedup_key(
             "newsapi",
@@ -169,77 +223,136 @@ impl NewsApiSource {
             Some(&article.url),
             Some(&article.published_at),
         );
-        let content_hash = dedup_key.content_hash.clone();
         let combined_key = dedup_key.combined_key();
 
-        IngestionEvent {
-            schema_version: CURRENT_SCHEMA_VERSION.to_string(),
-            id: uuid::Uuid::new_v4().to_string(),
-            created_at: now.clone(),
-            updated_at: None,
-            source_type: IngestionSourceType::NewsApi,
-            source_id: "newsapi".to_string(),
-            source_name: "NewsAPI".to_string(),
-            source_url: Some(article.url.clone()),
-            data_type: IngestionDataType::News,
-            data_subtype: Some("crypto_news".to_string()),
-            payload,
-            payload_size,
-            payload_hash: Some(content_hash),
-            status: Status::Pending,
-            processing_started_at: None,
-            processing_completed_at: None,
-            processing_duration_ms: None,
-            error_message: None,
-            error_code: None,
-            retry_count: 0,
-            max_retries: 3,
-            data_quality_score: None,
-            is_valid: true,
-            validation_errors: vec![],
-            priority: Severity::Medium,
-            deduplication_key: Some(combined_key),
-            is_duplicate: false,
-            batch_id: None,
-            batch_index: None,
-            ingested_at: now,
-            data_timestamp: Some(article.published_at.clone()),
-        }
+        IngestionEvent::builder(
+            IngestionSourceType::NewsApi,
+            "newsapi",
+            "NewsAPI",
+            IngestionDataType::News,
+        )
+        .payload(payload)
+        .source_url(article.url.clone())
+        .data_subtype("crypto_news")
+        .deduplication_key(combined_key)
+        .data_timestamp(article.published_at.clone())
+        .deterministic_id(self.deterministic_ids)
+        .build()
     }
 
     /// Internal fetch with query
+    ///
+    /// Without `options.query`, every default query is fetched (up to
+    /// `query_concurrency` at once, each through the rate limiter and circuit
+    /// breaker) and the results are merged, keeping the first article per URL.
+    /// `options.limit` is split evenly between the queries and caps the merged
+    /// result. The next page only re-fetches queries that had more results; the
+    /// cursor is the page number, followed by `:` and their indices when some
+    /// queries are done. The fetch only fails if every query does.
     async fn fetch_internal(&self, options: FetchOptions) -> Result<FetchResult> {
-        let query = options.query.clone()
-            .unwrap_or_else(|| self.default_queries[0].clone());
+        let (current_page, remaining) = parse_cursor(options.cursor.as_deref());
+        let (queries, query_count): (Vec<(usize, String)>, usize) = match options.query {
+            Some(ref query) => (vec![(0, query.clone())], 1),
+            None => (
+                self.default_queries
+                    .iter()
+                    .cloned()
+                    .enumerate()
+                    .filter(|(index, _)| remaining.as_ref().is_none_or(|remaining| remaining.contains(index)))
+                    .collect(),
+                self.default_queries.len(),
+            ),
+        };
+
+        // Split by every query, not just the ones left, so page sizes (and
+        // with them NewsAPI's page offsets) stay the same across pages
+        let limit = options.limit.unwrap_or(100);
+        let query_limit = (limit / query_count as u32).max(1);
+        let query_options = FetchOptions {
+            limit: Some(query_limit),
+            cursor: options.cursor.as_ref().map(|_| current_page.to_string()),
+            ..options.clone()
+        };
 
         debug!(
             source = "newsapi",
-            query = %query,
+            queries = ?queries,
             since = ?options.since,
             "Fetching news"
         );
 
-        let articles = self.fetch_query(&query, &options).await?;
-        let article_count = articles.len();
+        let pages: Vec<(usize, String, Result<Vec<NewsArticle>>)> = stream::iter(queries.clone())
+            .map(|(index, query)| {
+                let query_options = &query_options;
+                async move {
+                    let articles = self.fetch_query(&query, query_options).await;
+                    (index, query, articles)
+                }
+            })
+            .buffered(self.query_concurrency)
+            .collect()
+            .await;
+
+        let mut more_queries = Vec::new();
+        let mut seen_urls = HashSet::new();
+        let mut events = Vec::new();
+        let mut first_error = None;
+        let mut succeeded = 0;
+
+        for (index, query, articles) in pages {
+            // The free tier caps how deep we can page; treat hitting it as the
+            // end of results rather than a source failure
+            let (articles, results_limited) = match articles {
+                Ok(articles) => (articles, false),
+                Err(e) if is_results_limit_error(&e) => {
+                    info!(source = "newsapi", query = %query, error = %e, "NewsAPI result limit reached");
+                    (Vec::new(), true)
+                }
+                Err(e) => {
+                    warn!(source = "newsapi", query = %query, error = %e, "NewsAPI query failed");
+                    first_error.get_or_insert(e);
+                    continue;
+                }
+            };
+            succeeded += 1;
+            let page_size = articles.len();
+
+            // Results are sorted by publishedAt desc, so once the oldest article
+            // in a page predates `since` the following pages are all older
+            let reached_since = match (options.since, articles.last()) {
+                (Some(since), Some(last)) => published_before(&last.published_at, since, self.clock_skew_tolerance),
+                _ => false,
+            };
+            if !results_limited && !reached_since && page_size as u32 >= query_limit {
+                more_queries.push(index);
+            }
 
-        let events: Vec<IngestionEvent> = articles
-            .iter()
-            .map(|a| self.article_to_event(a, &query))
-            .collect();
+            for article in articles {
+                let before_since = options.since
+                    .is_some_and(|since| published_before(&article.published_at, since, self.clock_skew_tolerance));
+                if !before_since && seen_urls.insert(article.url.clone()) {
+                    events.push(self.article_to_event(&article, &query));
+                }
+            }
+        }
 
-        // Calculate next cursor (page number)
-        let current_page: u32 = options.cursor
-            .as_ref()
-            .and_then(|c| c.parse().ok())
-            .unwrap_or(1);
-        
-        let limit = options.limit.unwrap_or(100);
-        let has_more = article_count as u32 >= limit;
-        let next_cursor = if has_more {
+        if let (0, Some(e)) = (succeeded, first_error) {
+            return Err(e);
+        }
+        events.truncate(limit as usize);
+        let article_count = events.len();
+
+        // Calculate next cursor (page number, plus the queries still going)
+        let has_more = !more_queries.is_empty();
+        let next_cursor = if !has_more {
+            None
+        } else if more_queries.len() == queries.len() {
             Some((current_page + 1).to_string())
         } else {
-            None
+            let indices: Vec<String> = more_queries.iter().map(usize::to_string).collect();
+            Some(format!("{}:{}", current_page + 1, indices.join(",")))
         };
+        let queries: Vec<String> = queries.into_iter().map(|(_, query)| query).collect();
 
         info!(
             source = "newsapi",
@@ -253,19 +366,53 @@ impl NewsApiSource {
             next_cursor,
             has_more,
             raw_payload: Some(serde_json::json!({
-                "query": query,
+                "queries": queries,
                 "count": article_count,
             })),
         })
     }
 }
 
+/// Splits a cursor into its page number (1 without a cursor) and, if only
+/// some default queries have more results, their indices
+fn parse_cursor(cursor: Option<&str>) -> (u32, Option<HashSet<usize>>) {
+    let Some(cursor) = cursor else {
+        return (1, None);
+    };
+    let (page, indices) = match cursor.split_once(':') {
+        Some((page, indices)) => (page, Some(indices)),
+        None => (cursor, None),
+    };
+    let remaining = indices.map(|indices| indices.split(',').filter_map(|index| index.parse().ok()).collect());
+    (page.parse().unwrap_or(1), remaining)
+}
+
+/// Whether an error is NewsAPI's free-tier result cap
+/// (`maximumResultsReached`, or 426 Upgrade Required)
+fn is_results_limit_error(error: &IngestionError) -> bool {
+    let IngestionError::ApiError { code, message } = error else {
+        return false;
+    };
+
+    code == MAXIMUM_RESULTS_REACHED
+        || code.starts_with("426")
+        || serde_json::from_str::<NewsApiResponse>(message)
+            .ok()
+            .and_then(|response| response.code)
+            .is_some_and(|code| code == MAXIMUM_RESULTS_REACHED)
+}
+
 #[async_trait]
 impl Source for NewsApiSource {
     fn metadata(&self) -> &SourceMetadata {
         &self.metadata
     }
 
+    /// One request per default query, since scheduled fetches fan out over them
+    fn estimated_cost(&self) -> u32 {
+        self.default_queries.len() as u32
+    }
+
     async fn fetch(&self, options: FetchOptions) -> Result<FetchResult> {
         self.fetch_internal(options).await
     }
@@ -282,11 +429,16 @@ impl Source for NewsApiSource {
             }
         }
     }
+
+    fn set_rate_limit(&self, rate_limit_rpm: u32) {
+        self.client.set_rate_limit(rate_limit_rpm);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::{DateTime, Utc};
 
     #[test]
     fn test_article_parsing() {
@@ -305,4 +457,364 @@ mod tests {
         assert_eq!(article.title, "Bitcoin Hits New High");
         assert_eq!(article.source.name, "CoinDesk");
     }
+
+    /// NewsAPI article JSON whose URL is derived from its title
+    fn article(title: &str, published_at: &str) -> serde_json::Value {
+        serde_json::json!({
+            "source": {"id": null, "name": "CoinDesk"},
+            "author": null,
+            "title": title,
+            "description": null,
+            "url": format!("https://coindesk.com/{}", title),
+            "urlToImage": null,
+            "publishedAt": published_at,
+            "content": null
+        })
+    }
+
+    fn test_source() -> NewsApiSource {
+        test_source_with_breaker(Arc::new(CircuitBreaker::with_defaults("newsapi")))
+    }
+
+    fn test_source_with_breaker(circuit_breaker: Arc<CircuitBreaker>) -> NewsApiSource {
+        NewsApiSource::new(
+            Arc::new(ResilientHttpClient::with_defaults().unwrap()),
+            "test-key".to_string(),
+            100,
+            circuit_breaker,
+        )
+    }
+
+    #[test]
+    fn test_deterministic_ids_stable_across_constructions() {
+        let json = r#"{
+            "source": {"id": null, "name": "CoinDesk"},
+            "author": null,
+            "title": "Monad Mainnet Goes Live",
+            "description": null,
+            "url": "https://coindesk.com/monad-mainnet",
+            "urlToImage": null,
+            "publishedAt": "2024-01-15T10:00:00Z",
+            "content": null
+        }"#;
+        let article: NewsArticle = serde_json::from_str(json).unwrap();
+
+        let first = test_source().with_deterministic_ids(true).article_to_event(&article, "monad");
+        let second = test_source().with_deterministic_ids(true).article_to_event(&article, "monad");
+        assert_eq!(first.id, second.id);
+
+        let random = test_source().article_to_event(&article, "monad");
+        assert_ne!(random.id, first.id);
+    }
+
+    #[tokio::test]
+    async fn test_pagination_stops_at_since() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/everything"))
+            .and(query_param("apiKey", "test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "totalResults": 30,
+                "articles": [
+                    article("newest", "2024-01-15T12:00:00Z"),
+                    article("newer", "2024-01-15T11:00:00Z"),
+                    article("older", "2024-01-15T09:00:00Z"),
+                ],
+            })))
+            .mount(&server)
+            .await;
+
+        let source = test_source().with_base_url(server.uri());
+        let since = DateTime::parse_from_rfc3339("2024-01-15T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let result = source
+            .fetch(FetchOptions::new().since(since).limit(3))
+            .await
+            .unwrap();
+
+        assert!(!result.has_more);
+        assert!(result.next_cursor.is_none());
+        let titles: Vec<_> = result.events.iter().map(|e| e.payload["title"].clone()).collect();
+        assert_eq!(titles, vec![serde_json::json!("newest"), serde_json::json!("newer")]);
+
+        // A full page entirely after `since` keeps paginating
+        let since = DateTime::parse_from_rfc3339("2024-01-15T08:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let result = source
+            .fetch(FetchOptions::new().since(since).limit(3))
+            .await
+            .unwrap();
+        assert!(result.has_more);
+        assert_eq!(result.next_cursor.as_deref(), Some("2"));
+    }
+
+    #[tokio::test]
+    async fn test_default_queries_fetched_concurrently_and_url_deduped() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for (query, slugs) in [("bitcoin", ["btc-etf", "shared"]), ("monad", ["monad-mainnet", "shared"]), ("defi", ["defi-hack", "defi-tvl"])] {
+            Mock::given(method("GET"))
+                .and(path("/everything"))
+                .and(query_param("q", query))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "status": "ok",
+                    "totalResults": 2,
+                    "articles": slugs.map(|slug| article(slug, "2024-01-15T12:00:00Z")),
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let source = test_source()
+            .with_base_url(server.uri())
+            .with_queries(vec!["bitcoin".to_string(), "monad".to_string(), "defi".to_string()])
+            .with_query_concurrency(2);
+        let result = source.fetch(FetchOptions::new().limit(10)).await.unwrap();
+
+        let urls: Vec<_> = result.events.iter().filter_map(|e| e.source_url.clone()).collect();
+        assert_eq!(urls, vec![
+            "https://coindesk.com/btc-etf",
+            "https://coindesk.com/shared",
+            "https://coindesk.com/monad-mainnet",
+            "https://coindesk.com/defi-hack",
+            "https://coindesk.com/defi-tvl",
+        ]);
+        assert_eq!(result.events[1].payload["query"], "bitcoin");
+        assert!(!result.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_default_queries_share_limit_and_page_only_unfinished() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let full_page = |prefix: &str| -> Vec<serde_json::Value> {
+            (0..3).map(|i| article(&format!("{}-{}", prefix, i), "2024-01-15T12:00:00Z")).collect()
+        };
+        Mock::given(method("GET"))
+            .and(path("/everything"))
+            .and(query_param("q", "bitcoin"))
+            .and(query_param("pageSize", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "totalResults": 30,
+                "articles": full_page("btc"),
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/everything"))
+            .and(query_param("q", "monad"))
+            .and(query_param("pageSize", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "totalResults": 1,
+                "articles": [article("monad-mainnet", "2024-01-15T12:00:00Z")],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let source = test_source()
+            .with_base_url(server.uri())
+            .with_queries(vec!["bitcoin".to_string(), "monad".to_string()]);
+        let first = source.fetch(FetchOptions::new().limit(7)).await.unwrap();
+        assert_eq!(first.events.len(), 4);
+        assert_eq!(first.next_cursor.as_deref(), Some("2:0"));
+
+        // Only the query that filled its page is fetched again
+        let second = source
+            .fetch(FetchOptions::new().limit(7).cursor(first.next_cursor.unwrap()))
+            .await
+            .unwrap();
+        assert_eq!(second.events.len(), 3);
+        assert!(second.events.iter().all(|e| e.payload["query"] == "bitcoin"));
+    }
+
+    #[tokio::test]
+    async fn test_merged_result_capped_at_limit() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for query in ["bitcoin", "monad"] {
+            Mock::given(method("GET"))
+                .and(path("/everything"))
+                .and(query_param("q", query))
+                .and(query_param("pageSize", "1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "status": "ok",
+                    "totalResults": 1,
+                    "articles": [article(query, "2024-01-15T12:00:00Z")],
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let source = test_source()
+            .with_base_url(server.uri())
+            .with_queries(vec!["bitcoin".to_string(), "monad".to_string()]);
+        let result = source.fetch(FetchOptions::new().limit(1)).await.unwrap();
+        assert_eq!(result.events.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_cursor() {
+        assert_eq!(parse_cursor(None), (1, None));
+        assert_eq!(parse_cursor(Some("3")), (3, None));
+        assert_eq!(parse_cursor(Some("2:0,2")), (2, Some(HashSet::from([0, 2]))));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_article_skipped() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/everything"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "totalResults": 3,
+                "articles": [
+                    article("first", "2024-01-15T12:00:00Z"),
+                    {"source": {"name": "CoinDesk"}, "title": 42, "url": null},
+                    article("third", "2024-01-15T12:00:00Z"),
+                ],
+            })))
+            .mount(&server)
+            .await;
+
+        let before = crate::metrics::parse_errors_total("newsapi");
+        let source = test_source().with_base_url(server.uri());
+        let result = source.fetch(FetchOptions::new().query("bitcoin").limit(3)).await.unwrap();
+
+        let titles: Vec<_> = result.events.iter().map(|e| e.payload["title"].clone()).collect();
+        assert_eq!(titles, vec![serde_json::json!("first"), serde_json::json!("third")]);
+        assert_eq!(crate::metrics::parse_errors_total("newsapi"), before + 1);
+    }
+
+    #[tokio::test]
+    async fn test_since_filter_tolerates_clock_skew() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let since = Utc::now();
+        let published = |offset_secs: i64| (since + chrono::Duration::seconds(offset_secs)).to_rfc3339();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/everything"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "totalResults": 3,
+                "articles": [
+                    article("future", &published(4)),
+                    article("skewed", &published(-4)),
+                    article("stale", &published(-120)),
+                ],
+            })))
+            .mount(&server)
+            .await;
+
+        let titles = |result: FetchResult| -> Vec<serde_json::Value> {
+            result.events.iter().map(|e| e.payload["title"].clone()).collect()
+        };
+
+        let source = test_source()
+            .with_base_url(server.uri())
+            .with_clock_skew_tolerance(chrono::Duration::seconds(10));
+        let result = source.fetch(FetchOptions::new().since(since)).await.unwrap();
+        assert_eq!(titles(result), vec![serde_json::json!("future"), serde_json::json!("skewed")]);
+
+        let strict = test_source()
+            .with_base_url(server.uri())
+            .with_clock_skew_tolerance(chrono::Duration::zero());
+        let result = strict.fetch(FetchOptions::new().since(since)).await.unwrap();
+        assert_eq!(titles(result), vec![serde_json::json!("future")]);
+    }
+
+    #[tokio::test]
+    async fn test_language_filter_maps_to_param() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for language in ["en", "tr"] {
+            Mock::given(method("GET"))
+                .and(path("/everything"))
+                .and(query_param("language", language))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "status": "ok",
+                    "totalResults": 0,
+                    "articles": [],
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let source = test_source().with_base_url(server.uri());
+        source.fetch_query("bitcoin", &FetchOptions::new().language("TR")).await.unwrap();
+        // English stays the default
+        source.fetch_query("bitcoin", &FetchOptions::new()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_maximum_results_reached_ends_pagination() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/everything"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(426).set_body_json(serde_json::json!({
+                "status": "error",
+                "code": "maximumResultsReached",
+                "message": "You have requested too many results.",
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/everything"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "totalResults": 500,
+                "articles": [article("monad-mainnet", "2024-01-15T10:00:00Z")],
+            })))
+            .mount(&server)
+            .await;
+
+        let breaker = Arc::new(CircuitBreaker::with_defaults("newsapi"));
+        let source = test_source_with_breaker(breaker.clone()).with_base_url(server.uri());
+
+        let first = source.fetch(FetchOptions::new().limit(1)).await.unwrap();
+        assert_eq!(first.events.len(), 1);
+        assert_eq!(first.next_cursor.as_deref(), Some("2"));
+
+        let second = source
+            .fetch(FetchOptions::new().limit(1).cursor(first.next_cursor.unwrap()))
+            .await
+            .unwrap();
+        assert!(second.events.is_empty());
+        assert!(!second.has_more);
+        assert!(second.next_cursor.is_none());
+
+        let stats = breaker.stats();
+        assert_eq!(stats.failure_count, 0);
+        assert_eq!(stats.total_failures, 0);
+    }
 }
@@ -0,0 +1,118 @@
+//! Reconnecting streams for long-lived sources
+//!
+//! Streaming sources (WebSocket feeds, bus consumers) wrap their connect
+//! function with [`with_reconnect`], which re-runs it with jittered
+//! exponential backoff whenever connecting fails or the stream ends.
+
+use futures::future::Future;
+use futures::stream::{self, BoxStream, Stream, StreamExt};
+use tracing::{info, warn};
+
+use crate::metrics;
+use crate::retry::Backoff;
+
+#[allow(dead_code)]
+struct ReconnectState<F, S> {
+    source: String,
+    connect: F,
+    stream: Option<S>,
+    backoff: Backoff,
+    connected_once: bool,
+}
+
+/// Wraps a fallible stream factory in a stream that never ends on its own
+///
+/// `connect` is called to open the stream, and again (after a backoff delay)
+/// each time it fails or the opened stream ends. Every reconnect attempt is
+/// counted in `ingestion_stream_reconnects_total{source}`; the backoff resets
+/// once a reconnected stream yields an item. Drop the stream to stop it.
+#[allow(dead_code)]
+pub fn with_reconnect<F, Fut, S, T>(
+    source: impl Into<String>,
+    backoff: Backoff,
+    connect: F,
+) -> BoxStream<'static, T>
+where
+    F: FnMut() -> Fut + Send + 'static,
+    Fut: Future<Output = anyhow::Result<S>> + Send,
+    S: Stream<Item = T> + Send + Unpin + 'static,
+    T: Send + 'static,
+{
+    let state: ReconnectState<F, S> = ReconnectState {
+        source: source.into(),
+        connect,
+        stream: None,
+        backoff,
+        connected_once: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(stream) = state.stream.as_mut() {
+                match stream.next().await {
+                    Some(item) => {
+                        state.backoff.reset();
+                        return Some((item, state));
+                    }
+                    None => {
+                        warn!(source = %state.source, "Source stream ended, reconnecting");
+                        state.stream = None;
+                    }
+                }
+            }
+
+            if state.connected_once {
+                let delay = state.backoff.next_delay();
+                metrics::record_stream_reconnect(&state.source);
+                tokio::time::sleep(delay).await;
+            }
+            state.connected_once = true;
+
+            match (state.connect)().await {
+                Ok(stream) => {
+                    info!(source = %state.source, "Source stream connected");
+                    state.stream = Some(stream);
+                }
+                Err(e) => {
+                    warn!(source = %state.source, error = %e, "Failed to connect source stream");
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_reconnects_until_factory_succeeds() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = attempts.clone();
+        let backoff = Backoff::new(Duration::from_millis(1), Duration::from_millis(5));
+
+        let stream = with_reconnect("reconnect-test", backoff, move || {
+            let attempt = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if attempt < 2 {
+                    anyhow::bail!("connection refused");
+                }
+                Ok(stream::iter(vec![attempt * 10, attempt * 10 + 1]))
+            }
+        });
+
+        let items: Vec<u32> = tokio::time::timeout(Duration::from_secs(5), stream.take(4).collect())
+            .await
+            .unwrap();
+
+        // Two failed attempts, then the third stream's items; once it ends the
+        // fourth connection picks up
+        assert_eq!(items, vec![20, 21, 30, 31]);
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+        assert_eq!(metrics::stream_reconnects_total("reconnect-test"), 3);
+    }
+}
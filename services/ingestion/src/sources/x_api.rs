@@ -13,14 +13,15 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
-use super::{Source, SourceMetadata, FetchOptions, FetchResult};
+use super::{PriorityConfig, Source, SourceMetadata, FetchOptions, FetchResult};
 use crate::circuit_breaker::CircuitBreaker;
 use crate::dedup::social_dedup_key;
 use crate::error::{IngestionError, Result};
 use crate::http_client::{ResilientHttpClient, SourceHttpClient};
-use crate::schemas::{IngestionEvent, IngestionSourceType, IngestionDataType, Status, Severity, CURRENT_SCHEMA_VERSION};
+use crate::metrics;
+use crate::schemas::{IngestionEvent, IngestionSourceType, IngestionDataType};
 
 /// Normalized tweet/post structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -120,16 +121,20 @@ pub trait XApiAdapter: Send + Sync {
     async fn search(&self, params: SocialSearchParams) -> Result<SocialSearchResult>;
 
     /// Gets posts from a specific user
+    #[allow(dead_code)]
     async fn user_timeline(&self, user_id: &str, params: SocialSearchParams) -> Result<SocialSearchResult>;
 
     /// Checks if the adapter is healthy
     async fn health_check(&self) -> Result<bool>;
+
+    /// Applies a new requests-per-minute limit (adapters without a rate
+    /// limiter ignore it)
+    fn set_rate_limit(&self, _rate_limit_rpm: u32) {}
 }
 
 /// Official X API v2 adapter
 pub struct OfficialXApiAdapter {
     client: SourceHttpClient,
-    bearer_token: String,
 }
 
 impl OfficialXApiAdapter {
@@ -146,17 +151,21 @@ impl OfficialXApiAdapter {
             "x_api",
             rate_limit_rpm,
             circuit_breaker,
-        );
+        )
+        .with_bearer_auth(&bearer_token);
 
-        Self {
-            client,
-            bearer_token,
-        }
+        Self { client }
+    }
+
+    /// Overrides the User-Agent sent with this source's requests
+    pub fn with_user_agent(mut self, user_agent: Option<String>) -> Self {
+        self.client = self.client.with_user_agent(user_agent);
+        self
     }
 
     /// Parses X API v2 response into normalized posts
     fn parse_response(&self, data: serde_json::Value) -> Result<SocialSearchResult> {
-        let posts: Vec<SocialPost> = vec![]; // TODO: Parse actual X API response structure
+        let _posts: Vec<SocialPost> = vec![]; // TODO: Parse actual X API response structure
         let next_token = data.get("meta")
             .and_then(|m| m.get("next_token"))
             .and_then(|t| t.as_str())
@@ -174,7 +183,14 @@ impl OfficialXApiAdapter {
             .unwrap_or_default();
 
         let posts: Vec<SocialPost> = tweets.iter()
-            .filter_map(|tweet| self.parse_tweet(tweet))
+            .filter_map(|tweet| {
+                let post = self.parse_tweet(tweet);
+                if post.is_none() {
+                    metrics::record_parse_error("x_api");
+                    warn!(source = "x_api", "Skipping malformed tweet");
+                }
+                post
+            })
             .collect();
 
         Ok(SocialSearchResult {
@@ -249,7 +265,7 @@ impl XApiAdapter for OfficialXApiAdapter {
         // Note: In production, you'd add the Bearer token header
         let response = self.client.get_with_query(&url, &query_params).await?;
         let data: serde_json::Value = response.json().await
-            .map_err(|e| IngestionError::HttpError(e))?;
+            .map_err(IngestionError::HttpError)?;
 
         self.parse_response(data)
     }
@@ -270,7 +286,7 @@ impl XApiAdapter for OfficialXApiAdapter {
         let url = format!("{}/users/{}/tweets", Self::BASE_URL, user_id);
         let response = self.client.get_with_query(&url, &query_params).await?;
         let data: serde_json::Value = response.json().await
-            .map_err(|e| IngestionError::HttpError(e))?;
+            .map_err(IngestionError::HttpError)?;
 
         self.parse_response(data)
     }
@@ -280,13 +296,19 @@ impl XApiAdapter for OfficialXApiAdapter {
         // We could check rate limit status or do a minimal search
         Ok(true)
     }
+
+    fn set_rate_limit(&self, rate_limit_rpm: u32) {
+        self.client.set_rate_limit(rate_limit_rpm);
+    }
 }
 
 /// Mock adapter for testing
+#[allow(dead_code)]
 pub struct MockXApiAdapter {
     posts: Vec<SocialPost>,
 }
 
+#[allow(dead_code)]
 impl MockXApiAdapter {
     pub fn new() -> Self {
         Self { posts: vec![] }
@@ -330,6 +352,10 @@ pub struct XApiSource {
     metadata: SourceMetadata,
     /// Default search queries for crypto
     default_queries: Vec<String>,
+    /// Derive event ids from dedup keys
+    deterministic_ids: bool,
+    /// Engagement thresholds for post priority
+    priorities: PriorityConfig,
 }
 
 impl XApiSource {
@@ -341,6 +367,10 @@ impl XApiSource {
             default_rate_limit: rate_limit_rpm,
             supports_pagination: true,
             supports_since: true,
+            supports_language: true,
+            // Paid tiers meter every post read, so a fetch costs far more
+            // quota than a news request
+            estimated_cost: 10,
         };
 
         Self {
@@ -353,6 +383,49 @@ impl XApiSource {
                 "$ETH crypto -is:retweet".to_string(),
                 "nad.fun OR nadfun".to_string(),
             ],
+            deterministic_ids: false,
+            priorities: PriorityConfig::default(),
+        }
+    }
+
+    /// Derives event ids from dedup keys (stable across replays)
+    pub fn with_deterministic_ids(mut self, enabled: bool) -> Self {
+        self.deterministic_ids = enabled;
+        self
+    }
+
+    /// Overrides the engagement thresholds used for post priority
+    pub fn with_priorities(mut self, priorities: PriorityConfig) -> Self {
+        self.priorities = priorities;
+        self
+    }
+
+    /// Builds the search query, narrowing it to cashtags for the requested
+    /// currencies and to the requested language
+    fn build_query(&self, options: &FetchOptions) -> String {
+        let currencies = options.currency_symbols();
+        let query = if currencies.is_empty() {
+            options.query.clone()
+                .unwrap_or_else(|| self.default_queries[0].clone())
+        } else {
+            let cashtags = currencies
+                .iter()
+                .map(|symbol| format!("${}", symbol))
+                .collect::<Vec<_>>()
+                .join(" OR ");
+            match options.query {
+                Some(ref query) => format!("{} ({})", query, cashtags),
+                None => format!("({})", cashtags),
+            }
+        };
+
+        match options.language_code() {
+            // Grouped so `lang:` applies to every OR branch
+            Some(language) if query.contains(" OR ") && !is_grouped(&query) => {
+                format!("({}) lang:{}", query, language)
+            }
+            Some(language) => format!("{} lang:{}", query, language),
+            None => query,
         }
     }
 
@@ -392,10 +465,6 @@ impl XApiSource {
             payload.insert("authorFollowers".to_string(), serde_json::json!(followers));
         }
 
-        let payload_json = serde_json::to_string(&payload).unwrap_or_default();
-        let payload_size = payload_json.len() as u64;
-        let now = Utc::now().to_rfc3339();
-
         // Create dedup key
         let dedup_key = social_dedup_key(
             "x_api",
@@ -403,52 +472,48 @@ impl XApiSource {
             &post.text,
             Some(&post.id),
         );
-        let content_hash = dedup_key.content_hash.clone();
         let combined_key = dedup_key.combined_key();
 
         // Determine priority based on engagement and author
-        let priority = if post.author.verified || post.author.followers_count.unwrap_or(0) > 100_000 {
-            Severity::High
-        } else if post.metrics.likes > 100 || post.metrics.reposts > 50 {
-            Severity::Medium
-        } else {
-            Severity::Low
-        };
+        let priority = self.priorities.social_priority(
+            post.author.verified,
+            post.author.followers_count.unwrap_or(0),
+            post.metrics.likes,
+            post.metrics.reposts,
+        );
+
+        IngestionEvent::builder(
+            IngestionSourceType::SocialApi,
+            "x_api",
+            "X/Twitter",
+            IngestionDataType::Social,
+        )
+        .payload(payload)
+        .source_url(post.url.clone())
+        .data_subtype("tweet")
+        .priority(priority)
+        .deduplication_key(combined_key)
+        .data_timestamp(post.created_at.to_rfc3339())
+        .deterministic_id(self.deterministic_ids)
+        .build()
+    }
+}
 
-        IngestionEvent {
-            schema_version: CURRENT_SCHEMA_VERSION.to_string(),
-            id: uuid::Uuid::new_v4().to_string(),
-            created_at: now.clone(),
-            updated_at: None,
-            source_type: IngestionSourceType::SocialApi,
-            source_id: "x_api".to_string(),
-            source_name: "X/Twitter".to_string(),
-            source_url: Some(post.url.clone()),
-            data_type: IngestionDataType::Social,
-            data_subtype: Some("tweet".to_string()),
-            payload,
-            payload_size,
-            payload_hash: Some(content_hash),
-            status: Status::Pending,
-            processing_started_at: None,
-            processing_completed_at: None,
-            processing_duration_ms: None,
-            error_message: None,
-            error_code: None,
-            retry_count: 0,
-            max_retries: 3,
-            data_quality_score: None,
-            is_valid: true,
-            validation_errors: vec![],
-            priority,
-            deduplication_key: Some(combined_key),
-            is_duplicate: false,
-            batch_id: None,
-            batch_index: None,
-            ingested_at: now,
-            data_timestamp: Some(post.created_at.to_rfc3339()),
+/// Checks whether `query` is one parenthesized group, like `($BTC OR $ETH)`
+fn is_grouped(query: &str) -> bool {
+    let mut depth = 0;
+    for (i, c) in query.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ if depth == 0 => return false,
+            _ => {}
+        }
+        if depth == 0 {
+            return i == query.len() - 1;
         }
     }
+    false
 }
 
 #[async_trait]
@@ -458,8 +523,7 @@ impl Source for XApiSource {
     }
 
     async fn fetch(&self, options: FetchOptions) -> Result<FetchResult> {
-        let query = options.query.clone()
-            .unwrap_or_else(|| self.default_queries[0].clone());
+        let query = self.build_query(&options);
 
         debug!(
             source = "x_api",
@@ -506,11 +570,16 @@ impl Source for XApiSource {
     async fn health_check(&self) -> Result<bool> {
         self.adapter.health_check().await
     }
+
+    fn set_rate_limit(&self, rate_limit_rpm: u32) {
+        self.adapter.set_rate_limit(rate_limit_rpm);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::schemas::Severity;
 
     #[tokio::test]
     async fn test_mock_adapter() {
@@ -552,4 +621,31 @@ mod tests {
         assert_eq!(result.events.len(), 1);
         assert_eq!(result.events[0].priority, Severity::High); // Verified author
     }
+
+    #[test]
+    fn test_language_filter_adds_lang_operator() {
+        let source = XApiSource::new(Arc::new(MockXApiAdapter::new()), 60);
+
+        let options = FetchOptions::new().language("en");
+        assert_eq!(source.build_query(&options), "($MON OR #Monad) lang:en");
+
+        let options = options.currencies(["BTC", "ETH"]);
+        assert_eq!(source.build_query(&options), "($BTC OR $ETH) lang:en");
+
+        let options = FetchOptions::new().query("monad").language("tr");
+        assert_eq!(source.build_query(&options), "monad lang:tr");
+    }
+
+    #[test]
+    fn test_currencies_filter_builds_cashtag_query() {
+        let source = XApiSource::new(Arc::new(MockXApiAdapter::new()), 60);
+
+        let options = FetchOptions::new().currencies(["BTC", "ETH", "MON"]);
+        assert_eq!(source.build_query(&options), "($BTC OR $ETH OR $MON)");
+
+        let options = options.query("-is:retweet");
+        assert_eq!(source.build_query(&options), "-is:retweet ($BTC OR $ETH OR $MON)");
+
+        assert_eq!(source.build_query(&FetchOptions::new()), "$MON OR #Monad");
+    }
 }
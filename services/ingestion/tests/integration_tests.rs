@@ -3,7 +3,6 @@
 //! Uses wiremock for mocking HTTP endpoints.
 //! Tests pipeline throughput and backpressure behavior.
 
-use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
@@ -172,7 +171,7 @@ async fn test_worker_pool_scaling() {
     let mut handles = Vec::new();
     let rx = Arc::new(tokio::sync::Mutex::new(rx));
     
-    for worker_id in 0..num_workers {
+    for _worker_id in 0..num_workers {
         let rx = rx.clone();
         let out_tx = out_tx.clone();
         let mut shutdown_rx = shutdown_tx.subscribe();
@@ -331,7 +330,7 @@ async fn test_high_throughput() {
     
     // Fast consumer
     let consumer = tokio::spawn(async move {
-        while let Some(_) = rx.recv().await {
+        while rx.recv().await.is_some() {
             processed_clone.fetch_add(1, Ordering::Relaxed);
         }
     });